@@ -54,6 +54,27 @@ pub fn build_cli() -> App<'static, 'static> {
                         .help("Generate entity file of compact format")
                         .takes_value(false)
                         .conflicts_with("EXPANDED_FORMAT"),
+                )
+                .arg(
+                    Arg::with_name("SINGULARIZE")
+                        .long("singularize")
+                        .help("Name entities by the singular of their table name, e.g. `Order` for `orders`")
+                        .takes_value(false),
+                )
+                .arg(
+                    Arg::with_name("IRREGULAR_PLURALS")
+                        .long("irregular-plurals")
+                        .help("Also singularize irregular plurals, e.g. `Person` for `people`")
+                        .takes_value(false)
+                        .requires("SINGULARIZE"),
+                )
+                .arg(
+                    Arg::with_name("TYPE_OVERRIDE")
+                        .long("type-override")
+                        .help("Generate columns of a database type as a column type, e.g. `citext=text`")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
                 ),
         )
         .setting(AppSettings::SubcommandRequiredElseHelp);
@@ -1,7 +1,7 @@
 use clap::ArgMatches;
 use dotenv::dotenv;
 use log::LevelFilter;
-use sea_orm_codegen::{EntityTransformer, OutputFile};
+use sea_orm_codegen::{ColumnTypeOverrides, EntityNaming, EntityTransformer, OutputFile};
 use std::{error::Error, fmt::Display, fs, io::Write, path::Path, process::Command};
 
 mod cli;
@@ -27,6 +27,13 @@ async fn run_generate_command(matches: &ArgMatches<'_>) -> Result<(), Box<dyn Er
             let output_dir = args.value_of("OUTPUT_DIR").unwrap();
             let include_hidden_tables = args.is_present("INCLUDE_HIDDEN_TABLES");
             let expanded_format = args.is_present("EXPANDED_FORMAT");
+            let naming = EntityNaming::new()
+                .singularize(args.is_present("SINGULARIZE"))
+                .irregular_plurals(args.is_present("IRREGULAR_PLURALS"));
+            let mut type_overrides = ColumnTypeOverrides::new();
+            for spec in args.values_of("TYPE_OVERRIDE").into_iter().flatten() {
+                type_overrides = type_overrides.parse_override(spec)?;
+            }
             let filter_hidden_tables = |table: &str| -> bool {
                 if include_hidden_tables {
                     true
@@ -74,7 +81,9 @@ async fn run_generate_command(matches: &ArgMatches<'_>) -> Result<(), Box<dyn Er
                 panic!("This database is not supported ({})", url)
             };
 
-            let output = EntityTransformer::transform(table_stmts)?.generate(expanded_format);
+            let output = EntityTransformer::transform_with_naming(table_stmts, &naming)?
+                .override_column_types(&type_overrides)
+                .generate(expanded_format);
 
             let dir = Path::new(output_dir);
             fs::create_dir_all(dir)?;
@@ -6,6 +6,7 @@ use quote::format_ident;
 #[derive(Clone, Debug)]
 pub struct Entity {
     pub(crate) table_name: String,
+    pub(crate) entity_name: Option<String>,
     pub(crate) columns: Vec<Column>,
     pub(crate) relations: Vec<Relation>,
     pub(crate) conjunct_relations: Vec<ConjunctRelation>,
@@ -13,12 +14,18 @@ pub struct Entity {
 }
 
 impl Entity {
+    /// The name the module and type names are derived from, the table name
+    /// unless an [`EntityNaming`](crate::EntityNaming) renamed the entity
+    pub fn get_entity_name(&self) -> &str {
+        self.entity_name.as_deref().unwrap_or(&self.table_name)
+    }
+
     pub fn get_table_name_snake_case(&self) -> String {
-        self.table_name.to_snake_case()
+        self.get_entity_name().to_snake_case()
     }
 
     pub fn get_table_name_camel_case(&self) -> String {
-        self.table_name.to_camel_case()
+        self.get_entity_name().to_camel_case()
     }
 
     pub fn get_table_name_snake_case_ident(&self) -> Ident {
@@ -177,6 +184,7 @@ mod tests {
     fn setup() -> Entity {
         Entity {
             table_name: "special_cake".to_owned(),
+            entity_name: None,
             columns: vec![
                 Column {
                     name: "id".to_owned(),
@@ -184,6 +192,7 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    default_value: None,
                 },
                 Column {
                     name: "name".to_owned(),
@@ -191,6 +200,7 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    default_value: None,
                 },
             ],
             relations: vec![
@@ -1,7 +1,7 @@
 use heck::{CamelCase, SnakeCase};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use sea_query::{ColumnDef, ColumnSpec, ColumnType};
+use sea_query::{ColumnDef, ColumnSpec, ColumnType, Value};
 
 #[derive(Clone, Debug)]
 pub struct Column {
@@ -10,6 +10,7 @@ pub struct Column {
     pub(crate) auto_increment: bool,
     pub(crate) not_null: bool,
     pub(crate) unique: bool,
+    pub(crate) default_value: Option<Value>,
 }
 
 impl Column {
@@ -27,8 +28,6 @@ impl Column {
             ColumnType::Char(_)
             | ColumnType::String(_)
             | ColumnType::Text
-            | ColumnType::Time(_)
-            | ColumnType::Date
             | ColumnType::Custom(_) => "String",
             ColumnType::TinyInteger(_) => "i8",
             ColumnType::SmallInteger(_) => "i16",
@@ -37,6 +36,8 @@ impl Column {
             ColumnType::Float(_) => "f32",
             ColumnType::Double(_) => "f64",
             ColumnType::Json | ColumnType::JsonBinary => "Json",
+            ColumnType::Date => "Date",
+            ColumnType::Time(_) => "Time",
             ColumnType::DateTime(_) | ColumnType::Timestamp(_) => "DateTime",
             ColumnType::TimestampWithTimeZone(_) => "DateTimeWithTimeZone",
             ColumnType::Decimal(_) | ColumnType::Money(_) => "Decimal",
@@ -55,11 +56,16 @@ impl Column {
 
     pub fn get_col_type_attrs(&self) -> Option<TokenStream> {
         let col_type = match &self.col_type {
+            ColumnType::Char(Some(l)) => Some(format!("Char(Some({}))", l)),
+            ColumnType::Char(None) => Some("Char(None)".to_owned()),
             ColumnType::Float(Some(l)) => Some(format!("Float(Some({}))", l)),
             ColumnType::Double(Some(l)) => Some(format!("Double(Some({}))", l)),
             ColumnType::Decimal(Some((p, s))) => Some(format!("Decimal(Some(({}, {})))", p, s)),
-            ColumnType::Money(Some((p, s))) => Some(format!("Money(Some({}, {}))", p, s)),
+            ColumnType::Money(Some((p, s))) => Some(format!("Money(Some(({}, {})))", p, s)),
+            ColumnType::Money(None) => Some("Money(None)".to_owned()),
             ColumnType::Text => Some("Text".to_owned()),
+            ColumnType::Timestamp(_) => Some("Timestamp".to_owned()),
+            ColumnType::JsonBinary => Some("JsonBinary".to_owned()),
             ColumnType::Custom(iden) => {
                 Some(format!("Custom(\"{}\".to_owned())", iden.to_string()))
             }
@@ -122,8 +128,35 @@ impl Column {
                 .unique()
             });
         }
+        if let Some(default_value) = self.get_default_value() {
+            col_def.extend(quote! {
+                .default_value(#default_value)
+            });
+        }
         col_def
     }
+
+    /// The literal of the column default, if it is a value that can be written as one
+    pub fn get_default_value(&self) -> Option<TokenStream> {
+        match self.default_value.as_ref()? {
+            Value::Bool(Some(b)) => Some(quote! { #b }),
+            Value::TinyInt(Some(i)) => Some(quote! { #i }),
+            Value::SmallInt(Some(i)) => Some(quote! { #i }),
+            Value::Int(Some(i)) => Some(quote! { #i }),
+            Value::BigInt(Some(i)) => Some(quote! { #i }),
+            Value::TinyUnsigned(Some(i)) => Some(quote! { #i }),
+            Value::SmallUnsigned(Some(i)) => Some(quote! { #i }),
+            Value::Unsigned(Some(i)) => Some(quote! { #i }),
+            Value::BigUnsigned(Some(i)) => Some(quote! { #i }),
+            Value::Float(Some(f)) => Some(quote! { #f }),
+            Value::Double(Some(f)) => Some(quote! { #f }),
+            Value::String(Some(s)) => {
+                let s = s.as_str();
+                Some(quote! { #s })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<ColumnDef> for Column {
@@ -151,12 +184,20 @@ impl From<&ColumnDef> for Column {
             .get_column_spec()
             .iter()
             .any(|spec| matches!(spec, ColumnSpec::UniqueKey));
+        let default_value = col_def
+            .get_column_spec()
+            .iter()
+            .find_map(|spec| match spec {
+                ColumnSpec::Default(value) => Some(value.clone()),
+                _ => None,
+            });
         Self {
             name,
             col_type,
             auto_increment,
             not_null,
             unique,
+            default_value,
         }
     }
 }
@@ -177,6 +218,7 @@ mod tests {
                     auto_increment: false,
                     not_null: false,
                     unique: false,
+                    default_value: None,
                 }
             };
         }
@@ -194,6 +236,14 @@ mod tests {
             make_col!("CAKE_FILLING_ID", ColumnType::Double(None)),
             make_col!("CAKE-FILLING-ID", ColumnType::Binary(None)),
             make_col!("CAKE", ColumnType::Boolean),
+            make_col!("code", ColumnType::Char(Some(4))),
+            make_col!("description", ColumnType::Text),
+            make_col!("baked_on", ColumnType::Date),
+            make_col!("baked_at", ColumnType::Time(None)),
+            make_col!("created_at", ColumnType::DateTime(None)),
+            make_col!("updated_at", ColumnType::Timestamp(None)),
+            make_col!("expires_at", ColumnType::TimestampWithTimeZone(None)),
+            make_col!("uuid", ColumnType::Uuid),
         ]
     }
 
@@ -211,6 +261,14 @@ mod tests {
             "cake_filling_id",
             "cake_filling_id",
             "cake",
+            "code",
+            "description",
+            "baked_on",
+            "baked_at",
+            "created_at",
+            "updated_at",
+            "expires_at",
+            "uuid",
         ];
         for (col, snack_case) in columns.into_iter().zip(snack_cases) {
             assert_eq!(col.get_name_snake_case().to_string(), snack_case);
@@ -231,6 +289,14 @@ mod tests {
             "CakeFillingId",
             "CakeFillingId",
             "Cake",
+            "Code",
+            "Description",
+            "BakedOn",
+            "BakedAt",
+            "CreatedAt",
+            "UpdatedAt",
+            "ExpiresAt",
+            "Uuid",
         ];
         for (col, camel_case) in columns.into_iter().zip(camel_cases) {
             assert_eq!(col.get_name_camel_case().to_string(), camel_case);
@@ -241,7 +307,24 @@ mod tests {
     fn test_get_rs_type() {
         let columns = setup();
         let rs_types = vec![
-            "String", "String", "i8", "i16", "i32", "i64", "f32", "f64", "Vec<u8>", "bool",
+            "String",
+            "String",
+            "i8",
+            "i16",
+            "i32",
+            "i64",
+            "f32",
+            "f64",
+            "Vec<u8>",
+            "bool",
+            "String",
+            "String",
+            "Date",
+            "Time",
+            "DateTime",
+            "DateTime",
+            "DateTimeWithTimeZone",
+            "Uuid",
         ];
         for (mut col, rs_type) in columns.into_iter().zip(rs_types) {
             let rs_type: TokenStream = rs_type.parse().unwrap();
@@ -271,6 +354,14 @@ mod tests {
             "ColumnType::Double.def()",
             "ColumnType::Binary.def()",
             "ColumnType::Boolean.def()",
+            "ColumnType::Char(Some(4u32)).def()",
+            "ColumnType::Text.def()",
+            "ColumnType::Date.def()",
+            "ColumnType::Time.def()",
+            "ColumnType::DateTime.def()",
+            "ColumnType::Timestamp.def()",
+            "ColumnType::TimestampWithTimeZone.def()",
+            "ColumnType::Uuid.def()",
         ];
         for (mut col, col_def) in columns.into_iter().zip(col_defs) {
             let mut col_def: TokenStream = col_def.parse().unwrap();
@@ -288,6 +379,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_col_type_attrs() {
+        let columns = setup();
+        let col_type_attrs = vec![
+            None,
+            Some("Custom(\"cus_col\".to_owned())"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("Char(Some(4))"),
+            Some("Text"),
+            None,
+            None,
+            None,
+            Some("Timestamp"),
+            None,
+            None,
+        ];
+        for (col, col_type_attr) in columns.into_iter().zip(col_type_attrs) {
+            assert_eq!(
+                col.get_col_type_attrs().map(|attr| attr.to_string()),
+                col_type_attr.map(|ty| quote!(column_type = #ty).to_string())
+            );
+        }
+    }
+
     #[test]
     fn test_from_column_def() {
         let column: Column = ColumnDef::new(Alias::new("id")).string().to_owned().into();
@@ -326,4 +448,43 @@ mod tests {
         assert!(column.unique);
         assert!(column.not_null);
     }
+
+    #[test]
+    fn test_default_value() {
+        let column: Column = ColumnDef::new(Alias::new("qty"))
+            .integer()
+            .not_null()
+            .default(0)
+            .to_owned()
+            .into();
+        assert_eq!(
+            column.get_def().to_string(),
+            quote! {
+                ColumnType::Integer.def().default_value(0i32)
+            }
+            .to_string()
+        );
+
+        let column: Column = ColumnDef::new(Alias::new("status"))
+            .string()
+            .not_null()
+            .unique_key()
+            .default("pending")
+            .to_owned()
+            .into();
+        assert_eq!(
+            column.get_def().to_string(),
+            quote! {
+                ColumnType::String(None).def().unique().default_value("pending")
+            }
+            .to_string()
+        );
+
+        let column: Column = ColumnDef::new(Alias::new("note"))
+            .string()
+            .default(Option::<String>::None)
+            .to_owned()
+            .into();
+        assert!(column.get_default_value().is_none());
+    }
 }
@@ -1,15 +1,19 @@
 mod base_entity;
 mod column;
 mod conjunct_relation;
+mod naming;
 mod primary_key;
 mod relation;
 mod transformer;
+mod type_override;
 mod writer;
 
 pub use base_entity::*;
 pub use column::*;
 pub use conjunct_relation::*;
+pub use naming::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use transformer::*;
+pub use type_override::*;
 pub use writer::*;
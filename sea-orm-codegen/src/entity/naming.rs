@@ -0,0 +1,154 @@
+use heck::SnakeCase;
+
+/// Plurals that no suffix rule recovers, and words that are the same in both forms
+const IRREGULAR_PLURALS: [(&str, &str); 26] = [
+    ("people", "person"),
+    ("men", "man"),
+    ("women", "woman"),
+    ("children", "child"),
+    ("mice", "mouse"),
+    ("geese", "goose"),
+    ("teeth", "tooth"),
+    ("feet", "foot"),
+    ("oxen", "ox"),
+    ("indices", "index"),
+    ("matrices", "matrix"),
+    ("vertices", "vertex"),
+    ("analyses", "analysis"),
+    ("statuses", "status"),
+    ("criteria", "criterion"),
+    ("phenomena", "phenomenon"),
+    ("leaves", "leaf"),
+    ("knives", "knife"),
+    ("lives", "life"),
+    ("wives", "wife"),
+    ("series", "series"),
+    ("species", "species"),
+    ("news", "news"),
+    ("sheep", "sheep"),
+    ("fish", "fish"),
+    ("movies", "movie"),
+];
+
+/// How the Rust module and type names of an entity are derived from its table name.
+/// The `table_name` of the generated entity is always the exact name in the database.
+#[derive(Clone, Debug, Default)]
+pub struct EntityNaming {
+    singularize: bool,
+    irregular_plurals: bool,
+}
+
+impl EntityNaming {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Singularize the last word of the table name with the regular English
+    /// suffix rules, e.g. the entity of `orders` becomes `Order`
+    pub fn singularize(mut self, singularize: bool) -> Self {
+        self.singularize = singularize;
+        self
+    }
+
+    /// Also look up irregular plurals such as `people` and uncountable words
+    /// such as `series` before applying the suffix rules
+    pub fn irregular_plurals(mut self, irregular_plurals: bool) -> Self {
+        self.irregular_plurals = irregular_plurals;
+        self
+    }
+
+    /// The snake case name of the entity generated for the table
+    pub fn entity_name(&self, table_name: &str) -> String {
+        let name = table_name.to_snake_case();
+        if !self.singularize {
+            return name;
+        }
+        let (prefix, last) = match name.rfind('_') {
+            Some(i) => name.split_at(i + 1),
+            None => ("", name.as_str()),
+        };
+        format!("{}{}", prefix, self.singularize_word(last))
+    }
+
+    fn singularize_word(&self, word: &str) -> String {
+        if self.irregular_plurals {
+            if let Some((_, singular)) =
+                IRREGULAR_PLURALS.iter().find(|(plural, _)| *plural == word)
+            {
+                return singular.to_string();
+            }
+        }
+        let strip = |suffix: &str, replace: &str| {
+            word.strip_suffix(suffix)
+                .filter(|stem| !stem.is_empty())
+                .map(|stem| format!("{}{}", stem, replace))
+        };
+        if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") {
+            return word.to_owned();
+        }
+        strip("ies", "y")
+            .or_else(|| strip("sses", "ss"))
+            .or_else(|| strip("shes", "sh"))
+            .or_else(|| strip("ches", "ch"))
+            .or_else(|| strip("xes", "x"))
+            .or_else(|| strip("s", ""))
+            .unwrap_or_else(|| word.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EntityNaming;
+
+    #[test]
+    fn test_entity_name_verbatim() {
+        let naming = EntityNaming::new();
+        assert_eq!(naming.entity_name("orders"), "orders");
+        assert_eq!(naming.entity_name("CakeFillings"), "cake_fillings");
+    }
+
+    #[test]
+    fn test_entity_name_singularize() {
+        let naming = EntityNaming::new().singularize(true);
+        let cases = [
+            ("orders", "order"),
+            ("cakes", "cake"),
+            ("order_items", "order_item"),
+            ("LineItems", "line_item"),
+            ("categories", "category"),
+            ("addresses", "address"),
+            ("dishes", "dish"),
+            ("batches", "batch"),
+            ("boxes", "box"),
+            ("address", "address"),
+            ("status", "status"),
+            ("analysis", "analysis"),
+            ("lineitem", "lineitem"),
+            ("s", "s"),
+        ];
+        for (table_name, entity_name) in cases {
+            assert_eq!(naming.entity_name(table_name), entity_name);
+        }
+    }
+
+    #[test]
+    fn test_entity_name_irregular_plurals_opt_in() {
+        let regular = EntityNaming::new().singularize(true);
+        assert_eq!(regular.entity_name("people"), "people");
+        assert_eq!(regular.entity_name("children"), "children");
+
+        let irregular = regular.irregular_plurals(true);
+        assert_eq!(irregular.entity_name("people"), "person");
+        assert_eq!(irregular.entity_name("vip_children"), "vip_child");
+        assert_eq!(irregular.entity_name("statuses"), "status");
+        assert_eq!(irregular.entity_name("series"), "series");
+        assert_eq!(irregular.entity_name("movies"), "movie");
+        assert_eq!(irregular.entity_name("orders"), "order");
+    }
+
+    #[test]
+    fn test_entity_name_irregular_plurals_without_singularize() {
+        let naming = EntityNaming::new().irregular_plurals(true);
+        assert_eq!(naming.entity_name("people"), "people");
+    }
+}
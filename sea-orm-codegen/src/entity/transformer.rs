@@ -1,5 +1,6 @@
 use crate::{
-    Column, ConjunctRelation, Entity, EntityWriter, Error, PrimaryKey, Relation, RelationType,
+    Column, ConjunctRelation, Entity, EntityNaming, EntityWriter, Error, PrimaryKey, Relation,
+    RelationType,
 };
 use sea_query::TableStatement;
 use std::collections::HashMap;
@@ -54,7 +55,7 @@ impl EntityTransformer {
                 .get_indexes()
                 .iter()
                 .filter(|index| index.is_primary_key())
-                .map(|index| {
+                .flat_map(|index| {
                     index
                         .get_index_spec()
                         .get_column_names()
@@ -62,10 +63,10 @@ impl EntityTransformer {
                         .map(|name| PrimaryKey { name })
                         .collect::<Vec<_>>()
                 })
-                .flatten()
                 .collect();
             let entity = Entity {
                 table_name: table_name.clone(),
+                entity_name: None,
                 columns,
                 relations: relations.clone().collect(),
                 conjunct_relations: vec![],
@@ -133,7 +134,172 @@ impl EntityTransformer {
             }
         }
         Ok(EntityWriter {
-            entities: entities.into_iter().map(|(_, v)| v).collect(),
+            entities: entities.into_values().collect(),
         })
     }
+
+    /// Transform the tables like [`transform`](Self::transform), naming the
+    /// entities and the relations between them with `naming`
+    pub fn transform_with_naming(
+        table_stmts: Vec<TableStatement>,
+        naming: &EntityNaming,
+    ) -> Result<EntityWriter, Error> {
+        let mut writer = Self::transform(table_stmts)?;
+        let mut names: HashMap<String, String> = HashMap::new();
+        for entity in writer.entities.iter_mut() {
+            let entity_name = naming.entity_name(&entity.table_name);
+            if let Some(table_name) = names.insert(entity_name.clone(), entity.table_name.clone()) {
+                return Err(Error::TransformError(format!(
+                    "Tables `{}` and `{}` are both named `{}`",
+                    table_name, entity.table_name, entity_name
+                )));
+            }
+            for rel in entity.relations.iter_mut() {
+                rel.ref_table = naming.entity_name(&rel.ref_table);
+            }
+            for conjunct_rel in entity.conjunct_relations.iter_mut() {
+                conjunct_rel.via = naming.entity_name(&conjunct_rel.via);
+                conjunct_rel.to = naming.entity_name(&conjunct_rel.to);
+            }
+            entity.entity_name = Some(entity_name);
+        }
+        Ok(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EntityNaming, EntityTransformer, EntityWriter, Error};
+    use sea_query::{Alias, ColumnDef, ForeignKey, Table, TableStatement};
+
+    fn setup() -> Vec<TableStatement> {
+        vec![
+            TableStatement::Create(
+                Table::create()
+                    .table(Alias::new("orders"))
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .to_owned(),
+            ),
+            TableStatement::Create(
+                Table::create()
+                    .table(Alias::new("order_items"))
+                    .col(
+                        ColumnDef::new(Alias::new("id"))
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Alias::new("order_id")).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-order_items-orders")
+                            .from(Alias::new("order_items"), Alias::new("order_id"))
+                            .to(Alias::new("orders"), Alias::new("id")),
+                    )
+                    .to_owned(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_transform_related_both_directions() -> Result<(), Error> {
+        let mut entities = EntityTransformer::transform(setup())?.entities;
+        entities.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        let related: Vec<Vec<String>> = entities
+            .iter()
+            .map(|entity| {
+                std::iter::once(EntityWriter::gen_compact_relation_enum(entity))
+                    .chain(EntityWriter::gen_impl_related(entity))
+                    .map(|tokens| tokens.to_string())
+                    .collect()
+            })
+            .collect();
+        // The foreign key of `order_items` also becomes a `has_many` on `orders`
+        assert_eq!(
+            related,
+            vec![
+                vec![
+                    [
+                        "# [derive (Copy , Clone , Debug , EnumIter , DeriveRelation)] pub enum Relation {",
+                        r#"# [sea_orm (belongs_to = "super::orders::Entity" , from = "Column::OrderId" , to = "super::orders::Column::Id" ,)] Orders , }"#,
+                    ]
+                    .join(" "),
+                    "impl Related < super :: orders :: Entity > for Entity { fn to () -> RelationDef { Relation :: Orders . def () } }".to_owned(),
+                ],
+                vec![
+                    [
+                        "# [derive (Copy , Clone , Debug , EnumIter , DeriveRelation)] pub enum Relation {",
+                        r#"# [sea_orm (has_many = "super::order_items::Entity")] OrderItems , }"#,
+                    ]
+                    .join(" "),
+                    "impl Related < super :: order_items :: Entity > for Entity { fn to () -> RelationDef { Relation :: OrderItems . def () } }".to_owned(),
+                ],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_with_naming() -> Result<(), Error> {
+        let naming = EntityNaming::new().singularize(true);
+        let mut entities = EntityTransformer::transform_with_naming(setup(), &naming)?.entities;
+        entities.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+        let names: Vec<_> = entities
+            .iter()
+            .map(|entity| {
+                (
+                    entity.table_name.as_str(),
+                    entity.get_table_name_snake_case(),
+                    entity.get_table_name_camel_case(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (
+                    "order_items",
+                    "order_item".to_owned(),
+                    "OrderItem".to_owned()
+                ),
+                ("orders", "order".to_owned(), "Order".to_owned()),
+            ]
+        );
+        assert_eq!(
+            entities[0].get_relation_ref_tables_snake_case(),
+            vec!["order"]
+        );
+        assert_eq!(
+            entities[1].get_relation_ref_tables_camel_case(),
+            vec!["OrderItem"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_with_naming_conflict() {
+        let mut table_stmts = setup();
+        table_stmts.push(TableStatement::Create(
+            Table::create()
+                .table(Alias::new("order"))
+                .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+                .to_owned(),
+        ));
+        let naming = EntityNaming::new().singularize(true);
+        assert!(matches!(
+            EntityTransformer::transform_with_naming(table_stmts, &naming),
+            Err(Error::TransformError(_))
+        ));
+    }
 }
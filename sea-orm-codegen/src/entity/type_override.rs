@@ -0,0 +1,161 @@
+use crate::Error;
+use sea_query::ColumnType;
+use std::collections::HashMap;
+
+/// Column types to generate for database types that introspection does not know, such as
+/// Postgres domains or extension types like `citext`. These are discovered as
+/// `ColumnType::Custom` with the name of the type, which is looked up case-insensitively.
+#[derive(Clone, Debug, Default)]
+pub struct ColumnTypeOverrides {
+    overrides: HashMap<String, ColumnType>,
+}
+
+impl ColumnTypeOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate columns of the database type `db_type` as `col_type`
+    pub fn override_type(mut self, db_type: &str, col_type: ColumnType) -> Self {
+        self.overrides.insert(db_type.to_lowercase(), col_type);
+        self
+    }
+
+    /// Add an override written as `db_type=col_type`, e.g. `citext=text`,
+    /// with one of the column types named by [`parse_column_type`](Self::parse_column_type)
+    pub fn parse_override(self, spec: &str) -> Result<Self, Error> {
+        let (db_type, col_type) = spec.split_once('=').ok_or_else(|| {
+            Error::TransformError(format!(
+                "Type override `{}` should be written as `db_type=col_type`",
+                spec
+            ))
+        })?;
+        let col_type = Self::parse_column_type(col_type.trim()).ok_or_else(|| {
+            Error::TransformError(format!("Unknown column type `{}`", col_type.trim()))
+        })?;
+        Ok(self.override_type(db_type.trim(), col_type))
+    }
+
+    /// The column type named in snake case, e.g. `text`, `big_integer` or `json_binary`
+    pub fn parse_column_type(name: &str) -> Option<ColumnType> {
+        let col_type = match name.to_lowercase().as_str() {
+            "char" => ColumnType::Char(None),
+            "string" => ColumnType::String(None),
+            "text" => ColumnType::Text,
+            "tiny_integer" => ColumnType::TinyInteger(None),
+            "small_integer" => ColumnType::SmallInteger(None),
+            "integer" => ColumnType::Integer(None),
+            "big_integer" => ColumnType::BigInteger(None),
+            "float" => ColumnType::Float(None),
+            "double" => ColumnType::Double(None),
+            "decimal" => ColumnType::Decimal(None),
+            "date_time" => ColumnType::DateTime(None),
+            "timestamp" => ColumnType::Timestamp(None),
+            "timestamp_with_time_zone" => ColumnType::TimestampWithTimeZone(None),
+            "time" => ColumnType::Time(None),
+            "date" => ColumnType::Date,
+            "binary" => ColumnType::Binary(None),
+            "boolean" => ColumnType::Boolean,
+            "money" => ColumnType::Money(None),
+            "json" => ColumnType::Json,
+            "json_binary" => ColumnType::JsonBinary,
+            "uuid" => ColumnType::Uuid,
+            _ => return None,
+        };
+        Some(col_type)
+    }
+
+    /// The column type to generate instead of `col_type`, if it is overridden
+    pub fn get(&self, col_type: &ColumnType) -> Option<&ColumnType> {
+        match col_type {
+            ColumnType::Custom(iden) => self.overrides.get(&iden.to_string().to_lowercase()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColumnTypeOverrides, EntityTransformer, Error};
+    use sea_query::{Alias, ColumnDef, Table, TableStatement};
+
+    #[test]
+    fn test_override_column_types() -> Result<(), Error> {
+        let table_stmts = vec![TableStatement::Create(
+            Table::create()
+                .table(Alias::new("account"))
+                .col(
+                    ColumnDef::new(Alias::new("id"))
+                        .integer()
+                        .not_null()
+                        .auto_increment()
+                        .primary_key(),
+                )
+                .col(
+                    ColumnDef::new(Alias::new("email"))
+                        .custom(Alias::new("citext"))
+                        .not_null(),
+                )
+                .col(ColumnDef::new(Alias::new("age")).custom(Alias::new("positive_int")))
+                .col(ColumnDef::new(Alias::new("shape")).custom(Alias::new("geometry")))
+                .to_owned(),
+        )];
+        let overrides = ColumnTypeOverrides::new()
+            .parse_override("CITEXT=text")?
+            .parse_override("positive_int = integer")?;
+
+        let writer = EntityTransformer::transform(table_stmts)?.override_column_types(&overrides);
+        let columns = &writer.entities[0].columns;
+        let generated: Vec<_> = columns
+            .iter()
+            .map(|col| {
+                (
+                    col.get_rs_type().to_string(),
+                    col.get_col_type_attrs().map(|attrs| attrs.to_string()),
+                    col.get_def().to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            generated,
+            vec![
+                (
+                    "i32".to_owned(),
+                    None,
+                    "ColumnType :: Integer . def ()".to_owned()
+                ),
+                (
+                    "String".to_owned(),
+                    Some("column_type = \"Text\"".to_owned()),
+                    "ColumnType :: Text . def ()".to_owned()
+                ),
+                (
+                    "Option < i32 >".to_owned(),
+                    None,
+                    "ColumnType :: Integer . def () . null ()".to_owned()
+                ),
+                // Types without an override are kept
+                (
+                    "Option < String >".to_owned(),
+                    Some("column_type = \"Custom(\\\"geometry\\\".to_owned())\"".to_owned()),
+                    "ColumnType :: Custom (\"geometry\" . to_owned ()) . def () . null ()"
+                        .to_owned()
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_override_err() {
+        assert!(matches!(
+            ColumnTypeOverrides::new().parse_override("citext"),
+            Err(Error::TransformError(_))
+        ));
+        assert!(matches!(
+            ColumnTypeOverrides::new().parse_override("citext=varchar2"),
+            Err(Error::TransformError(_))
+        ));
+    }
+}
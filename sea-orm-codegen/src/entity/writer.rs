@@ -1,4 +1,4 @@
-use crate::Entity;
+use crate::{ColumnTypeOverrides, Entity};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{punctuated::Punctuated, token::Comma};
@@ -26,6 +26,20 @@ impl EntityWriter {
         WriterOutput { files }
     }
 
+    /// Generate the columns of the types in `overrides` with the column type given there
+    pub fn override_column_types(mut self, overrides: &ColumnTypeOverrides) -> Self {
+        for col in self
+            .entities
+            .iter_mut()
+            .flat_map(|entity| entity.columns.iter_mut())
+        {
+            if let Some(col_type) = overrides.get(&col.col_type) {
+                col.col_type = col_type.clone();
+            }
+        }
+        self
+    }
+
     pub fn write_entities(&self, expanded_format: bool) -> Vec<OutputFile> {
         self.entities
             .iter()
@@ -52,7 +66,7 @@ impl EntityWriter {
         let code_blocks: Vec<TokenStream> = self
             .entities
             .iter()
-            .map(|entity| Self::gen_mod(entity))
+            .map(Self::gen_mod)
             .collect();
         Self::write(
             &mut lines,
@@ -74,7 +88,7 @@ impl EntityWriter {
         let code_blocks = self
             .entities
             .iter()
-            .map(|entity| Self::gen_prelude_use(entity))
+            .map(Self::gen_prelude_use)
             .collect();
         Self::write(&mut lines, code_blocks);
         OutputFile {
@@ -349,6 +363,9 @@ impl EntityWriter {
                 if col.unique {
                     attrs.push(quote! { unique });
                 }
+                if let Some(default_value) = col.get_default_value() {
+                    attrs.push(quote! { default_value = #default_value });
+                }
                 if !attrs.is_empty() {
                     let mut ts = TokenStream::new();
                     for (i, attr) in attrs.into_iter().enumerate() {
@@ -406,6 +423,7 @@ mod tests {
         vec![
             Entity {
                 table_name: "cake".to_owned(),
+                entity_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -413,6 +431,7 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -420,6 +439,7 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        default_value: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -440,6 +460,7 @@ mod tests {
             },
             Entity {
                 table_name: "_cake_filling_".to_owned(),
+                entity_name: None,
                 columns: vec![
                     Column {
                         name: "cake_id".to_owned(),
@@ -447,6 +468,7 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "filling_id".to_owned(),
@@ -454,6 +476,7 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                 ],
                 relations: vec![
@@ -486,6 +509,7 @@ mod tests {
             },
             Entity {
                 table_name: "filling".to_owned(),
+                entity_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -493,6 +517,7 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -500,6 +525,7 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                 ],
                 relations: vec![],
@@ -513,6 +539,7 @@ mod tests {
             },
             Entity {
                 table_name: "fruit".to_owned(),
+                entity_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -520,6 +547,7 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -527,6 +555,7 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "cake_id".to_owned(),
@@ -534,6 +563,7 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        default_value: None,
                     },
                 ],
                 relations: vec![
@@ -561,6 +591,7 @@ mod tests {
             },
             Entity {
                 table_name: "vendor".to_owned(),
+                entity_name: None,
                 columns: vec![
                     Column {
                         name: "id".to_owned(),
@@ -568,6 +599,7 @@ mod tests {
                         auto_increment: true,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "name".to_owned(),
@@ -575,6 +607,7 @@ mod tests {
                         auto_increment: false,
                         not_null: true,
                         unique: false,
+                        default_value: None,
                     },
                     Column {
                         name: "fruit_id".to_owned(),
@@ -582,6 +615,7 @@ mod tests {
                         auto_increment: false,
                         not_null: false,
                         unique: false,
+                        default_value: None,
                     },
                 ],
                 relations: vec![Relation {
@@ -3,13 +3,22 @@ pub mod derive_attr {
 
     #[derive(Default, FromAttributes)]
     pub struct SeaOrm {
+        pub active_model: Option<syn::Lit>,
         pub column: Option<syn::Ident>,
+        pub db_type: Option<syn::Lit>,
         pub entity: Option<syn::Ident>,
+        pub enum_name: Option<syn::Lit>,
+        pub from: Option<syn::Lit>,
         pub model: Option<syn::Ident>,
         pub primary_key: Option<syn::Ident>,
+        pub quote_identifiers: Option<syn::Lit>,
         pub relation: Option<syn::Ident>,
+        pub rs_type: Option<syn::Lit>,
         pub schema_name: Option<syn::Lit>,
         pub table_name: Option<syn::Lit>,
+        pub to: Option<syn::Lit>,
+        pub via_from: Option<syn::Lit>,
+        pub via_to: Option<syn::Lit>,
     }
 }
 
@@ -21,9 +30,12 @@ pub mod field_attr {
         pub belongs_to: Option<syn::Lit>,
         pub has_one: Option<syn::Lit>,
         pub has_many: Option<syn::Lit>,
+        pub num_value: Option<syn::Lit>,
         pub on_update: Option<syn::Lit>,
         pub on_delete: Option<syn::Lit>,
+        pub string_value: Option<syn::Lit>,
         pub from: Option<syn::Lit>,
+        pub from_expr: Option<syn::Lit>,
         pub to: Option<syn::Lit>,
     }
 }
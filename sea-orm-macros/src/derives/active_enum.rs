@@ -0,0 +1,240 @@
+use heck::SnakeCase;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::attributes::{derive_attr, field_attr};
+
+enum Error {
+    InputNotEnum,
+    Syn(syn::Error),
+}
+
+enum ActiveEnumValue {
+    String(syn::LitStr),
+    Num(syn::Lit),
+}
+
+struct DeriveActiveEnum {
+    ident: syn::Ident,
+    rs_type: TokenStream,
+    db_type: TokenStream,
+    variants: Vec<(syn::Ident, ActiveEnumValue)>,
+}
+
+impl DeriveActiveEnum {
+    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
+        let ident_span = input.ident.span();
+        let variants = match input.data {
+            syn::Data::Enum(syn::DataEnum { variants, .. }) => variants,
+            _ => return Err(Error::InputNotEnum),
+        };
+
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
+            .map_err(Error::Syn)?
+            .unwrap_or_default();
+
+        let ident = input.ident;
+
+        let rs_type = match &sea_attr.rs_type {
+            Some(lit) => Self::parse_lit_string(lit).map_err(Error::Syn)?,
+            None => {
+                return Err(Error::Syn(syn::Error::new(
+                    ident_span,
+                    "Missing attribute 'rs_type'",
+                )))
+            }
+        };
+
+        let mut enum_variants = Vec::new();
+        for variant in variants.iter() {
+            if !matches!(variant.fields, syn::Fields::Unit) {
+                return Err(Error::Syn(syn::Error::new_spanned(
+                    variant,
+                    "DeriveActiveEnum only supports unit variants",
+                )));
+            }
+            let attr = field_attr::SeaOrm::from_attributes(&variant.attrs).map_err(Error::Syn)?;
+            let value = match (attr.string_value, attr.num_value) {
+                (Some(syn::Lit::Str(lit_str)), None) => ActiveEnumValue::String(lit_str),
+                (None, Some(lit @ syn::Lit::Int(_))) => ActiveEnumValue::Num(lit),
+                (Some(lit), None) => {
+                    return Err(Error::Syn(syn::Error::new_spanned(
+                        lit,
+                        "'string_value' must be a string",
+                    )))
+                }
+                (None, Some(lit)) => {
+                    return Err(Error::Syn(syn::Error::new_spanned(
+                        lit,
+                        "'num_value' must be an integer",
+                    )))
+                }
+                _ => {
+                    return Err(Error::Syn(syn::Error::new_spanned(
+                        variant,
+                        "Missing exactly one of 'string_value' or 'num_value'",
+                    )))
+                }
+            };
+            enum_variants.push((variant.ident.clone(), value));
+        }
+
+        let db_type = match &sea_attr.db_type {
+            Some(syn::Lit::Str(lit_str)) if lit_str.value() == "Enum" => {
+                let enum_name = match &sea_attr.enum_name {
+                    Some(syn::Lit::Str(lit_str)) => lit_str.value(),
+                    Some(lit) => {
+                        return Err(Error::Syn(syn::Error::new_spanned(
+                            lit,
+                            "attribute must be a string",
+                        )))
+                    }
+                    None => ident.to_string().to_snake_case(),
+                };
+                let mut string_values = Vec::new();
+                for (variant_ident, value) in enum_variants.iter() {
+                    match value {
+                        ActiveEnumValue::String(lit_str) => string_values.push(lit_str.clone()),
+                        ActiveEnumValue::Num(_) => {
+                            return Err(Error::Syn(syn::Error::new_spanned(
+                                variant_ident,
+                                "db_type 'Enum' requires 'string_value' on every variant",
+                            )))
+                        }
+                    }
+                }
+                quote! {
+                    Enum(#enum_name.to_owned(), vec![#( #string_values.to_owned() ),*])
+                }
+            }
+            Some(lit) => Self::parse_lit_string(lit).map_err(Error::Syn)?,
+            None => {
+                return Err(Error::Syn(syn::Error::new(
+                    ident_span,
+                    "Missing attribute 'db_type'",
+                )))
+            }
+        };
+
+        Ok(DeriveActiveEnum {
+            ident,
+            rs_type,
+            db_type,
+            variants: enum_variants,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let Self {
+            ident,
+            rs_type,
+            db_type,
+            variants,
+        } = self;
+
+        let variant_idents: Vec<&syn::Ident> = variants.iter().map(|(ident, _)| ident).collect();
+        let to_values: Vec<TokenStream> = variants
+            .iter()
+            .map(|(_, value)| match value {
+                ActiveEnumValue::String(lit_str) => quote! { #lit_str.to_owned() },
+                ActiveEnumValue::Num(lit) => quote! { #lit },
+            })
+            .collect();
+        let variant_values: Vec<TokenStream> = variants
+            .iter()
+            .map(|(_, value)| match value {
+                ActiveEnumValue::String(lit_str) => quote! { #lit_str },
+                ActiveEnumValue::Num(lit) => quote! { #lit },
+            })
+            .collect();
+
+        Ok(quote!(
+            #[automatically_derived]
+            impl sea_orm::ActiveEnum for #ident {
+                type Value = #rs_type;
+
+                fn to_value(&self) -> Self::Value {
+                    match self {
+                        #( Self::#variant_idents => #to_values, )*
+                    }
+                }
+
+                fn try_from_value(v: &Self::Value) -> Result<Self, sea_orm::DbErr> {
+                    #( if *v == #variant_values {
+                        return Ok(Self::#variant_idents);
+                    } )*
+                    Err(sea_orm::DbErr::Query(format!(
+                        "unexpected value for {} enum: {:?}",
+                        stringify!(#ident),
+                        v
+                    )))
+                }
+
+                fn db_type() -> sea_orm::ColumnDef {
+                    sea_orm::prelude::ColumnType::#db_type.def()
+                }
+            }
+
+            #[automatically_derived]
+            impl From<#ident> for sea_orm::sea_query::Value {
+                fn from(source: #ident) -> Self {
+                    <#ident as sea_orm::ActiveEnum>::to_value(&source).into()
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::TryGetable for #ident {
+                fn try_get(res: &sea_orm::QueryResult, pre: &str, col: &str) -> Result<Self, sea_orm::TryGetError> {
+                    let value = <<Self as sea_orm::ActiveEnum>::Value as sea_orm::TryGetable>::try_get(res, pre, col)?;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+                }
+
+                fn try_get_by_index(res: &sea_orm::QueryResult, idx: usize) -> Result<Self, sea_orm::TryGetError> {
+                    let value = <<Self as sea_orm::ActiveEnum>::Value as sea_orm::TryGetable>::try_get_by_index(res, idx)?;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(sea_orm::TryGetError::DbErr)
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::ValueType for #ident {
+                fn try_from(v: sea_orm::sea_query::Value) -> Result<Self, sea_orm::sea_query::ValueTypeErr> {
+                    let value = <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::ValueType>::try_from(v)?;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(|_| sea_orm::sea_query::ValueTypeErr)
+                }
+
+                fn type_name() -> String {
+                    stringify!(#ident).to_owned()
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::Nullable for #ident {
+                fn null() -> sea_orm::sea_query::Value {
+                    <<Self as sea_orm::ActiveEnum>::Value as sea_orm::sea_query::Nullable>::null()
+                }
+            }
+        ))
+    }
+
+    fn parse_lit_string(lit: &syn::Lit) -> syn::Result<TokenStream> {
+        match lit {
+            syn::Lit::Str(lit_str) => lit_str
+                .value()
+                .parse()
+                .map_err(|_| syn::Error::new_spanned(lit, "attribute not valid")),
+            _ => Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+        }
+    }
+}
+
+pub fn expand_derive_active_enum(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident_span = input.ident.span();
+
+    match DeriveActiveEnum::new(input) {
+        Ok(model) => model.expand(),
+        Err(Error::InputNotEnum) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive DeriveActiveEnum on enums");
+        }),
+        Err(Error::Syn(err)) => Err(err),
+    }
+}
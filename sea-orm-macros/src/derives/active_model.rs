@@ -21,13 +21,11 @@ pub fn expand_derive_active_model(ident: Ident, data: Data) -> syn::Result<Token
 
     let field: Vec<Ident> = fields
         .clone()
-        .into_iter()
         .map(|Field { ident, .. }| format_ident!("{}", ident.unwrap().to_string()))
         .collect();
 
     let name: Vec<Ident> = fields
         .clone()
-        .into_iter()
         .map(|Field { ident, .. }| format_ident!("{}", ident.unwrap().to_string().to_camel_case()))
         .collect();
 
@@ -87,6 +85,18 @@ pub fn expand_derive_active_model(ident: Ident, data: Data) -> syn::Result<Token
                 }
             }
 
+            fn try_set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: sea_orm::Value) -> Result<(), sea_orm::DbErr> {
+                match c {
+                    #(<Self::Entity as EntityTrait>::Column::#name => {
+                        self.#field = sea_orm::ActiveValue::set(<#ty as sea_orm::sea_query::ValueType>::try_from(v).map_err(|_| {
+                            sea_orm::DbErr::Type(format!("expected {} for `{}`", <#ty as sea_orm::sea_query::ValueType>::type_name(), sea_orm::IdenStatic::as_str(&c)))
+                        })?);
+                        Ok(())
+                    },)*
+                    _ => panic!("This ActiveModel does not have this field"),
+                }
+            }
+
             fn unset(&mut self, c: <Self::Entity as EntityTrait>::Column) {
                 match c {
                     #(<Self::Entity as EntityTrait>::Column::#name => self.#field = sea_orm::ActiveValue::unset(),)*
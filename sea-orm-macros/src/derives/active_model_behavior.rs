@@ -1,9 +1,93 @@
+use heck::CamelCase;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::Data;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{punctuated::Punctuated, token::Comma, Data, DataStruct, Field, Fields, Meta, Type};
+
+pub fn expand_derive_active_model_behavior(_ident: Ident, data: Data) -> syn::Result<TokenStream> {
+    // search for #[sea_orm(created_at)] and #[sea_orm(updated_at)] on the fields of Model
+    let mut created_at: Option<(Ident, Type)> = None;
+    let mut updated_at: Option<(Ident, Type)> = None;
+    if let Data::Struct(DataStruct {
+        fields: Fields::Named(named),
+        ..
+    }) = data
+    {
+        for Field {
+            ident, ty, attrs, ..
+        } in named.named
+        {
+            let ident = match ident {
+                Some(ident) => ident,
+                None => continue,
+            };
+            for attr in attrs.iter() {
+                if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
+                    continue;
+                }
+                if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                {
+                    for meta in list.iter() {
+                        if let Meta::Path(path) = meta {
+                            if let Some(name) = path.get_ident() {
+                                let column = format_ident!("{}", ident.to_string().to_camel_case());
+                                if name == "created_at" {
+                                    if created_at.is_some() {
+                                        return Ok(quote_spanned! {
+                                            ident.span() => compile_error!("only one field can be marked as `created_at`");
+                                        });
+                                    }
+                                    created_at = Some((column, ty.clone()));
+                                } else if name == "updated_at" {
+                                    if updated_at.is_some() {
+                                        return Ok(quote_spanned! {
+                                            ident.span() => compile_error!("only one field can be marked as `updated_at`");
+                                        });
+                                    }
+                                    updated_at = Some((column, ty.clone()));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if created_at.is_none() && updated_at.is_none() {
+        return Ok(quote!(
+            impl sea_orm::ActiveModelBehavior for ActiveModel {}
+        ));
+    }
+
+    let set_created_at = created_at.map(|(column, ty)| {
+        quote! {
+            if insert {
+                sea_orm::ActiveModelTrait::set(
+                    &mut self,
+                    Column::#column,
+                    <#ty as sea_orm::CurrentTimestamp>::current_timestamp().into(),
+                );
+            }
+        }
+    });
+    let set_updated_at = updated_at.map(|(column, ty)| {
+        quote! {
+            sea_orm::ActiveModelTrait::set(
+                &mut self,
+                Column::#column,
+                <#ty as sea_orm::CurrentTimestamp>::current_timestamp().into(),
+            );
+        }
+    });
 
-pub fn expand_derive_active_model_behavior(_ident: Ident, _data: Data) -> syn::Result<TokenStream> {
     Ok(quote!(
-        impl sea_orm::ActiveModelBehavior for ActiveModel {}
+        impl sea_orm::ActiveModelBehavior for ActiveModel {
+            #[allow(unused_variables)]
+            fn before_save(mut self, insert: bool) -> Self {
+                #set_created_at
+                #set_updated_at
+                self
+            }
+        }
     ))
 }
@@ -1,7 +1,35 @@
 use heck::{MixedCase, SnakeCase};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{Data, DataEnum, Fields, Variant};
+use syn::{
+    punctuated::Punctuated, token::Comma, Attribute, Data, DataEnum, Fields, Lit, Meta, Variant,
+};
+
+use crate::{attributes::derive_attr, util::expand_impl_iden};
+
+/// Find the column name overridden by `#[sea_orm(column_name = "name")]`, if any
+fn column_name_override(variant: &Variant) -> syn::Result<Option<String>> {
+    for attr in variant.attrs.iter() {
+        if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
+            continue;
+        }
+        let list = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+        for meta in list.iter() {
+            if let Meta::NameValue(nv) = meta {
+                if nv.path.get_ident().map(|i| i == "column_name") == Some(true) {
+                    return match &nv.lit {
+                        Lit::Str(litstr) => Ok(Some(litstr.value())),
+                        _ => Err(syn::Error::new_spanned(
+                            &nv.lit,
+                            "column_name must be a string",
+                        )),
+                    };
+                }
+            }
+        }
+    }
+    Ok(None)
+}
 
 pub fn impl_default_as_str(ident: &Ident, data: &Data) -> syn::Result<TokenStream> {
     let variants = match data {
@@ -25,10 +53,13 @@ pub fn impl_default_as_str(ident: &Ident, data: &Data) -> syn::Result<TokenStrea
     let name: Vec<TokenStream> = variants
         .iter()
         .map(|v| {
-            let ident = v.ident.to_string().to_snake_case();
-            quote! { #ident }
+            let ident = match column_name_override(v)? {
+                Some(column_name) => column_name,
+                None => v.ident.to_string().to_snake_case(),
+            };
+            Ok(quote! { #ident })
         })
-        .collect();
+        .collect::<syn::Result<_>>()?;
 
     Ok(quote!(
         impl #ident {
@@ -51,14 +82,28 @@ pub fn impl_col_from_str(ident: &Ident, data: &Data) -> syn::Result<TokenStream>
         }
     };
 
-    let columns = data_enum.variants.iter().map(|column| {
-        let column_iden = column.ident.clone();
-        let column_str_snake = column_iden.to_string().to_snake_case();
-        let column_str_mixed = column_iden.to_string().to_mixed_case();
-        quote!(
-            #column_str_snake | #column_str_mixed => Ok(#ident::#column_iden)
-        )
-    });
+    let columns = data_enum
+        .variants
+        .iter()
+        .map(|column| {
+            let column_iden = column.ident.clone();
+            let mut column_strs = Vec::new();
+            if let Some(column_name) = column_name_override(column)? {
+                column_strs.push(column_name);
+            }
+            for column_str in [
+                column_iden.to_string().to_snake_case(),
+                column_iden.to_string().to_mixed_case(),
+            ] {
+                if !column_strs.contains(&column_str) {
+                    column_strs.push(column_str);
+                }
+            }
+            Ok(quote!(
+                #(#column_strs)|* => Ok(#ident::#column_iden)
+            ))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     Ok(quote!(
         impl std::str::FromStr for #ident {
@@ -74,8 +119,12 @@ pub fn impl_col_from_str(ident: &Ident, data: &Data) -> syn::Result<TokenStream>
     ))
 }
 
-pub fn expand_derive_column(ident: &Ident, data: &Data) -> syn::Result<TokenStream> {
-    let impl_iden = expand_derive_custom_column(ident, data)?;
+pub fn expand_derive_column(
+    ident: &Ident,
+    data: &Data,
+    attrs: &[Attribute],
+) -> syn::Result<TokenStream> {
+    let impl_iden = expand_derive_custom_column(ident, data, attrs)?;
 
     Ok(quote!(
         #impl_iden
@@ -88,19 +137,21 @@ pub fn expand_derive_column(ident: &Ident, data: &Data) -> syn::Result<TokenStre
     ))
 }
 
-pub fn expand_derive_custom_column(ident: &Ident, data: &Data) -> syn::Result<TokenStream> {
+pub fn expand_derive_custom_column(
+    ident: &Ident,
+    data: &Data,
+    attrs: &[Attribute],
+) -> syn::Result<TokenStream> {
+    let sea_attr = derive_attr::SeaOrm::try_from_attributes(attrs)?.unwrap_or_default();
     let impl_default_as_str = impl_default_as_str(ident, data)?;
     let impl_col_from_str = impl_col_from_str(ident, data)?;
+    let impl_iden = expand_impl_iden(ident, sea_attr.quote_identifiers.as_ref())?;
 
     Ok(quote!(
         #impl_default_as_str
 
         #impl_col_from_str
 
-        impl sea_orm::Iden for #ident {
-            fn unquoted(&self, s: &mut dyn std::fmt::Write) {
-                write!(s, "{}", self.as_str()).unwrap();
-            }
-        }
+        #impl_iden
     ))
 }
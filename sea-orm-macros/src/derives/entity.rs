@@ -3,13 +3,14 @@ use std::iter::FromIterator;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::attributes::derive_attr;
+use crate::{attributes::derive_attr, util::expand_impl_iden};
 
 struct DeriveEntity {
     column_ident: syn::Ident,
     ident: syn::Ident,
     model_ident: syn::Ident,
     primary_key_ident: syn::Ident,
+    quote_identifiers: Option<syn::Lit>,
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
@@ -29,6 +30,7 @@ impl DeriveEntity {
             .relation
             .unwrap_or_else(|| format_ident!("Relation"));
 
+        let quote_identifiers = sea_attr.quote_identifiers;
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
 
@@ -37,24 +39,25 @@ impl DeriveEntity {
             ident,
             model_ident,
             primary_key_ident,
+            quote_identifiers,
             relation_ident,
             schema_name,
             table_name,
         })
     }
 
-    fn expand(&self) -> TokenStream {
+    fn expand(&self) -> syn::Result<TokenStream> {
         let expanded_impl_entity_name = self.impl_entity_name();
         let expanded_impl_entity_trait = self.impl_entity_trait();
-        let expanded_impl_iden = self.impl_iden();
+        let expanded_impl_iden = self.impl_iden()?;
         let expanded_impl_iden_static = self.impl_iden_static();
 
-        TokenStream::from_iter([
+        Ok(TokenStream::from_iter([
             expanded_impl_entity_name,
             expanded_impl_entity_trait,
             expanded_impl_iden,
             expanded_impl_iden_static,
-        ])
+        ]))
     }
 
     fn impl_entity_name(&self) -> TokenStream {
@@ -105,16 +108,8 @@ impl DeriveEntity {
         )
     }
 
-    fn impl_iden(&self) -> TokenStream {
-        let ident = &self.ident;
-
-        quote!(
-            impl sea_orm::Iden for #ident {
-                fn unquoted(&self, s: &mut dyn std::fmt::Write) {
-                    write!(s, "{}", self.as_str()).unwrap();
-                }
-            }
-        )
+    fn impl_iden(&self) -> syn::Result<TokenStream> {
+        expand_impl_iden(&self.ident, self.quote_identifiers.as_ref())
     }
 
     fn impl_iden_static(&self) -> TokenStream {
@@ -131,5 +126,5 @@ impl DeriveEntity {
 }
 
 pub fn expand_derive_entity(input: syn::DeriveInput) -> syn::Result<TokenStream> {
-    Ok(DeriveEntity::new(input)?.expand())
+    DeriveEntity::new(input)?.expand()
 }
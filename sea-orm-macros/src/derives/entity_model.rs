@@ -11,6 +11,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     // if #[sea_orm(table_name = "foo", schema_name = "bar")] specified, create Entity struct
     let mut table_name = None;
     let mut schema_name = quote! { None };
+    // #[sea_orm(quote_identifiers = false)] is passed on to the Entity and Column
+    let mut quote_identifiers = quote! {};
     attrs.iter().for_each(|attr| {
         if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
             return;
@@ -25,6 +27,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         } else if ident == "schema_name" {
                             let name = &nv.lit;
                             schema_name = quote! { Some(#name) };
+                        } else if ident == "quote_identifiers" {
+                            let lit = &nv.lit;
+                            quote_identifiers = quote! { #[sea_orm(quote_identifiers = #lit)] };
                         }
                     }
                 }
@@ -35,6 +40,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         .map(|table_name| {
             quote! {
                 #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
+                #quote_identifiers
                 pub struct Entity;
 
                 impl sea_orm::prelude::EntityName for Entity {
@@ -62,7 +68,6 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                 if let Some(ident) = &field.ident {
                     let field_name =
                         Ident::new(&ident.to_string().to_case(Case::Pascal), Span::call_site());
-                    columns_enum.push(quote! { #field_name });
 
                     let mut nullable = false;
                     let mut default_value = None;
@@ -70,8 +75,10 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
+                    let mut sensitive = false;
                     let mut sql_type = None;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", nullable, indexed, unique)]
+                    let mut column_name = None;
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", nullable, indexed, unique, sensitive)]
                     for attr in field.attrs.iter() {
                         if let Some(ident) = attr.path.get_ident() {
                             if ident != "sea_orm" {
@@ -116,6 +123,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 default_value = Some(nv.lit.to_owned());
                                             } else if name == "default_expr" {
                                                 default_expr = Some(nv.lit.to_owned());
+                                            } else if name == "column_name" {
+                                                if let Lit::Str(litstr) = &nv.lit {
+                                                    column_name = Some(litstr.clone());
+                                                } else {
+                                                    return Err(Error::new(
+                                                        field.span(),
+                                                        format!("Invalid column_name {:?}", nv.lit),
+                                                    ));
+                                                }
                                             }
                                         }
                                     }
@@ -133,6 +149,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 indexed = true;
                                             } else if name == "unique" {
                                                 unique = true;
+                                            } else if name == "sensitive" {
+                                                sensitive = true;
                                             }
                                         }
                                     }
@@ -143,10 +161,18 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     }
 
                     if ignore {
-                        columns_enum.pop();
                         continue;
                     }
 
+                    match column_name {
+                        Some(column_name) => columns_enum.push(quote! {
+                            #[sea_orm(column_name = #column_name)]
+                            #field_name
+                        }),
+                        None => columns_enum.push(quote! { #field_name }),
+                    }
+
+                    let mut col_def = None;
                     let field_type = match sql_type {
                         Some(t) => t,
                         None => {
@@ -165,13 +191,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 "String" | "&str" => quote! { String(None) },
                                 "u8" | "i8" => quote! { TinyInteger },
                                 "u16" | "i16" => quote! { SmallInteger },
-                                "u32" | "i32" => quote! { Integer },
-                                "u64" | "i64" => quote! { BigInteger },
+                                "i32" => quote! { Integer },
+                                "u32" => quote! { Unsigned },
+                                "i64" => quote! { BigInteger },
+                                "u64" => quote! { BigUnsigned },
                                 "f32" => quote! { Float },
                                 "f64" => quote! { Double },
                                 "bool" => quote! { Boolean },
-                                "NaiveDate" => quote! { Date },
-                                "NaiveTime" => quote! { Time },
+                                "Date" | "NaiveDate" => quote! { Date },
+                                "Time" | "NaiveTime" => quote! { Time },
                                 "DateTime" | "NaiveDateTime" => {
                                     quote! { DateTime }
                                 }
@@ -183,16 +211,25 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 "Decimal" => quote! { Decimal(None) },
                                 "Vec<u8>" => quote! { Binary },
                                 _ => {
-                                    return Err(Error::new(
-                                        field.span(),
-                                        format!("unrecognized type {}", temp),
-                                    ))
+                                    // assume any other type is an ActiveEnum
+                                    let ty: TokenStream = temp.parse().map_err(|_| {
+                                        Error::new(
+                                            field.span(),
+                                            format!("unrecognized type {}", temp),
+                                        )
+                                    })?;
+                                    col_def =
+                                        Some(quote! { <#ty as sea_orm::ActiveEnum>::db_type() });
+                                    quote! {}
                                 }
                             }
                         }
                     };
 
-                    let mut match_row = quote! { Self::#field_name => sea_orm::prelude::ColumnType::#field_type.def() };
+                    let col_def = col_def.unwrap_or_else(
+                        || quote! { sea_orm::prelude::ColumnType::#field_type.def() },
+                    );
+                    let mut match_row = quote! { Self::#field_name => #col_def };
                     if nullable {
                         match_row = quote! { #match_row.nullable() };
                     }
@@ -202,6 +239,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    if sensitive {
+                        match_row = quote! { #match_row.sensitive() };
+                    }
                     if let Some(default_value) = default_value {
                         match_row = quote! { #match_row.default_value(#default_value) };
                     }
@@ -214,34 +254,35 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         }
     }
 
-    let primary_key = (!primary_keys.is_empty())
-        .then(|| {
-            let auto_increment = auto_increment && primary_keys.len() == 1;
-            let primary_key_types = if primary_key_types.len() == 1 {
-                let first = primary_key_types.first();
-                quote! { #first }
-            } else {
-                quote! { (#primary_key_types) }
-            };
-            quote! {
-            #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
-            pub enum PrimaryKey {
-                #primary_keys
-            }
+    let primary_key = if !primary_keys.is_empty() {
+        let auto_increment = auto_increment && primary_keys.len() == 1;
+        let primary_key_types = if primary_key_types.len() == 1 {
+            let first = primary_key_types.first();
+            quote! { #first }
+        } else {
+            quote! { (#primary_key_types) }
+        };
+        quote! {
+        #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+        pub enum PrimaryKey {
+            #primary_keys
+        }
 
-            impl PrimaryKeyTrait for PrimaryKey {
-                type ValueType = #primary_key_types;
+        impl PrimaryKeyTrait for PrimaryKey {
+            type ValueType = #primary_key_types;
 
-                fn auto_increment() -> bool {
-                    #auto_increment
-                }
+            fn auto_increment() -> bool {
+                #auto_increment
             }
-                    }
-        })
-        .unwrap_or_default();
+        }
+                }
+    } else {
+        quote! {}
+    };
 
     Ok(quote! {
         #[derive(Copy, Clone, Debug, sea_orm::prelude::EnumIter, sea_orm::prelude::DeriveColumn)]
+        #quote_identifiers
         pub enum Column {
             #columns_enum
         }
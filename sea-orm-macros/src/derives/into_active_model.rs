@@ -0,0 +1,87 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::attributes::derive_attr;
+
+enum Error {
+    InputNotStruct,
+    Syn(syn::Error),
+}
+
+struct DeriveIntoActiveModel {
+    ident: syn::Ident,
+    active_model: TokenStream,
+    fields: Vec<syn::Ident>,
+}
+
+impl DeriveIntoActiveModel {
+    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
+        let fields = match input.data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
+                ..
+            }) => named,
+            _ => return Err(Error::InputNotStruct),
+        };
+
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
+            .map_err(Error::Syn)?
+            .unwrap_or_default();
+
+        let active_model = match sea_attr.active_model {
+            Some(lit) => Self::parse_lit_string(&lit).map_err(Error::Syn)?,
+            None => quote!(ActiveModel),
+        };
+
+        Ok(DeriveIntoActiveModel {
+            ident: input.ident,
+            active_model,
+            fields: fields
+                .into_iter()
+                .map(|field| field.ident.expect("named field"))
+                .collect(),
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let DeriveIntoActiveModel {
+            ident,
+            active_model,
+            fields,
+        } = self;
+
+        Ok(quote!(
+            impl sea_orm::entity::IntoActiveModel<#active_model> for #ident {
+                #[allow(clippy::needless_update)]
+                fn into_active_model(self) -> #active_model {
+                    #active_model {
+                        #( #fields: sea_orm::entity::IntoActiveValue::into_active_value(self.#fields), )*
+                        ..::std::default::Default::default()
+                    }
+                }
+            }
+        ))
+    }
+
+    fn parse_lit_string(lit: &syn::Lit) -> syn::Result<TokenStream> {
+        match lit {
+            syn::Lit::Str(lit_str) => lit_str
+                .value()
+                .parse()
+                .map_err(|_| syn::Error::new_spanned(lit, "attribute not valid")),
+            _ => Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+        }
+    }
+}
+
+pub fn expand_derive_into_active_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident_span = input.ident.span();
+
+    match DeriveIntoActiveModel::new(input) {
+        Ok(model) => model.expand(),
+        Err(Error::InputNotStruct) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive DeriveIntoActiveModel on structs with named fields");
+        }),
+        Err(Error::Syn(err)) => Err(err),
+    }
+}
@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::attributes::derive_attr;
+
+enum Error {
+    InputNotStruct,
+    Syn(syn::Error),
+}
+
+struct DeriveLinked {
+    ident: syn::Ident,
+    from: TokenStream,
+    to: TokenStream,
+    via_from: TokenStream,
+    via_to: TokenStream,
+}
+
+impl DeriveLinked {
+    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
+        if !matches!(input.data, syn::Data::Struct(_)) {
+            return Err(Error::InputNotStruct);
+        }
+
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
+            .map_err(Error::Syn)?
+            .unwrap_or_default();
+
+        let ident = input.ident;
+        let attr = |lit: Option<syn::Lit>, name: &str| match lit {
+            Some(lit) => Self::parse_lit_string(&lit).map_err(Error::Syn),
+            None => Err(Error::Syn(syn::Error::new_spanned(
+                &ident,
+                format!("Missing attribute '{}'", name),
+            ))),
+        };
+
+        Ok(DeriveLinked {
+            from: attr(sea_attr.from, "from")?,
+            to: attr(sea_attr.to, "to")?,
+            via_from: attr(sea_attr.via_from, "via_from")?,
+            via_to: attr(sea_attr.via_to, "via_to")?,
+            ident,
+        })
+    }
+
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let DeriveLinked {
+            ident,
+            from,
+            to,
+            via_from,
+            via_to,
+        } = self;
+
+        Ok(quote!(
+            impl sea_orm::entity::Linked for #ident {
+                type FromEntity = #from;
+
+                type ToEntity = #to;
+
+                fn link(&self) -> Vec<sea_orm::entity::RelationDef> {
+                    vec![
+                        sea_orm::entity::RelationTrait::def(&#via_from).rev(),
+                        sea_orm::entity::RelationTrait::def(&#via_to),
+                    ]
+                }
+            }
+        ))
+    }
+
+    fn parse_lit_string(lit: &syn::Lit) -> syn::Result<TokenStream> {
+        match lit {
+            syn::Lit::Str(lit_str) => lit_str
+                .value()
+                .parse()
+                .map_err(|_| syn::Error::new_spanned(lit, "attribute not valid")),
+            _ => Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+        }
+    }
+}
+
+pub fn expand_derive_linked(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident_span = input.ident.span();
+
+    match DeriveLinked::new(input) {
+        Ok(linked) => linked.expand(),
+        Err(Error::InputNotStruct) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive DeriveLinked on structs");
+        }),
+        Err(Error::Syn(err)) => Err(err),
+    }
+}
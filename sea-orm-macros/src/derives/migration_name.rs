@@ -0,0 +1,19 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+/// Name the migration after the module it is defined in, e.g. `m20220101_000001_create_cake`
+pub fn expand_derive_migration_name(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics sea_orm::MigrationName for #ident #ty_generics #where_clause {
+            fn name(&self) -> &str {
+                let path = std::module_path!();
+                path.rsplit("::").next().unwrap_or(path)
+            }
+        }
+    ))
+}
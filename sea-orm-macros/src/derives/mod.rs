@@ -1,19 +1,29 @@
+mod active_enum;
 mod active_model;
 mod active_model_behavior;
 mod column;
 mod entity;
 mod entity_model;
 mod from_query_result;
+mod into_active_model;
+mod linked;
+mod migration_name;
 mod model;
+mod partial_model;
 mod primary_key;
 mod relation;
 
+pub use active_enum::*;
 pub use active_model::*;
 pub use active_model_behavior::*;
 pub use column::*;
 pub use entity::*;
 pub use entity_model::*;
 pub use from_query_result::*;
+pub use into_active_model::*;
+pub use linked::*;
+pub use migration_name::*;
 pub use model::*;
+pub use partial_model::*;
 pub use primary_key::*;
 pub use relation::*;
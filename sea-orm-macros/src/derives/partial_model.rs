@@ -0,0 +1,104 @@
+use heck::CamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+
+use crate::attributes::{derive_attr, field_attr};
+
+use super::expand_derive_from_query_result;
+
+enum Error {
+    InputNotStruct,
+    Syn(syn::Error),
+}
+
+struct DerivePartialModel {
+    ident: syn::Ident,
+    data: syn::Data,
+    columns: Vec<(String, TokenStream)>,
+}
+
+impl DerivePartialModel {
+    fn new(input: syn::DeriveInput) -> Result<Self, Error> {
+        let fields = match &input.data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
+                ..
+            }) => named,
+            _ => return Err(Error::InputNotStruct),
+        };
+
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
+            .map_err(Error::Syn)?
+            .unwrap_or_default();
+
+        let mut columns = Vec::new();
+        for field in fields.iter() {
+            let field_ident = field.ident.as_ref().expect("named field");
+            let attr = field_attr::SeaOrm::try_from_attributes(&field.attrs)
+                .map_err(Error::Syn)?
+                .unwrap_or_default();
+            let expr = match (&attr.from_expr, &sea_attr.entity) {
+                (Some(lit), _) => Self::parse_lit_string(lit).map_err(Error::Syn)?,
+                (None, Some(entity)) => {
+                    let column = format_ident!("{}", field_ident.to_string().to_camel_case());
+                    quote!(<#entity as sea_orm::EntityTrait>::Column::#column)
+                }
+                (None, None) => {
+                    return Err(Error::Syn(syn::Error::new_spanned(
+                        field_ident,
+                        "Missing attribute 'from_expr', or 'entity' on the struct to select the column of the same name",
+                    )))
+                }
+            };
+            columns.push((field_ident.to_string(), expr));
+        }
+
+        Ok(DerivePartialModel {
+            ident: input.ident,
+            data: input.data,
+            columns,
+        })
+    }
+
+    fn expand(self) -> syn::Result<TokenStream> {
+        let DerivePartialModel {
+            ident,
+            data,
+            columns,
+        } = self;
+        let (names, exprs): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        let from_query_result = expand_derive_from_query_result(ident.clone(), data)?;
+
+        Ok(quote!(
+            impl sea_orm::PartialModelTrait for #ident {
+                fn select_cols<S: sea_orm::QuerySelect>(select: S) -> S {
+                    select #( .column_as(#exprs, #names) )*
+                }
+            }
+
+            #from_query_result
+        ))
+    }
+
+    fn parse_lit_string(lit: &syn::Lit) -> syn::Result<TokenStream> {
+        match lit {
+            syn::Lit::Str(lit_str) => lit_str
+                .value()
+                .parse()
+                .map_err(|_| syn::Error::new_spanned(lit, "attribute not valid")),
+            _ => Err(syn::Error::new_spanned(lit, "attribute must be a string")),
+        }
+    }
+}
+
+pub fn expand_derive_partial_model(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident_span = input.ident.span();
+
+    match DerivePartialModel::new(input) {
+        Ok(model) => model.expand(),
+        Err(Error::InputNotStruct) => Ok(quote_spanned! {
+            ident_span => compile_error!("you can only derive DerivePartialModel on structs with named fields");
+        }),
+        Err(Error::Syn(err)) => Err(err),
+    }
+}
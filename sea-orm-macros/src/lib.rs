@@ -46,21 +46,25 @@ pub fn derive_primary_key(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(DeriveColumn)]
+#[proc_macro_derive(DeriveColumn, attributes(sea_orm))]
 pub fn derive_column(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
 
-    match derives::expand_derive_column(&ident, &data) {
+    match derives::expand_derive_column(&ident, &data, &attrs) {
         Ok(ts) => ts.into(),
         Err(e) => e.to_compile_error().into(),
     }
 }
 
-#[proc_macro_derive(DeriveCustomColumn)]
+#[proc_macro_derive(DeriveCustomColumn, attributes(sea_orm))]
 pub fn derive_custom_column(input: TokenStream) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(input);
+    let DeriveInput {
+        ident, data, attrs, ..
+    } = parse_macro_input!(input);
 
-    match derives::expand_derive_custom_column(&ident, &data) {
+    match derives::expand_derive_custom_column(&ident, &data, &attrs) {
         Ok(ts) => ts.into(),
         Err(e) => e.to_compile_error().into(),
     }
@@ -84,7 +88,7 @@ pub fn derive_active_model(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(DeriveActiveModelBehavior)]
+#[proc_macro_derive(DeriveActiveModelBehavior, attributes(sea_orm))]
 pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, data, .. } = parse_macro_input!(input);
 
@@ -94,6 +98,14 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(DeriveActiveEnum, attributes(sea_orm))]
+pub fn derive_active_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_active_enum(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro_derive(FromQueryResult)]
 pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, data, .. } = parse_macro_input!(input);
@@ -104,6 +116,22 @@ pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(DeriveIntoActiveModel, attributes(sea_orm))]
+pub fn derive_into_active_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_into_active_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(DerivePartialModel, attributes(sea_orm))]
+pub fn derive_partial_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_partial_model(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro_derive(DeriveRelation, attributes(sea_orm))]
 pub fn derive_relation(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -112,6 +140,22 @@ pub fn derive_relation(input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[proc_macro_derive(DeriveLinked, attributes(sea_orm))]
+pub fn derive_linked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_linked(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(DeriveMigrationName)]
+pub fn derive_migration_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_migration_name(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn test(_: TokenStream, input: TokenStream) -> TokenStream {
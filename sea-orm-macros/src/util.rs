@@ -1,4 +1,6 @@
-use syn::{punctuated::Punctuated, token::Comma, Field, Meta};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, token::Comma, Field, Lit, Meta};
 
 pub(crate) fn field_not_ignored(field: &Field) -> bool {
     for attr in field.attrs.iter() {
@@ -24,3 +26,40 @@ pub(crate) fn field_not_ignored(field: &Field) -> bool {
     }
     true
 }
+
+/// The `Iden` impl of an entity or column enum. Identifiers are quoted unless
+/// `#[sea_orm(quote_identifiers = false)]` asks to leave out quotes where they are not needed.
+pub(crate) fn expand_impl_iden(
+    ident: &Ident,
+    quote_identifiers: Option<&Lit>,
+) -> syn::Result<TokenStream> {
+    let quote_identifiers = match quote_identifiers {
+        Some(Lit::Bool(litbool)) => litbool.value(),
+        Some(lit) => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "quote_identifiers must be a bool",
+            ))
+        }
+        None => true,
+    };
+    let expanded_prepare = if quote_identifiers {
+        TokenStream::new()
+    } else {
+        quote!(
+            fn prepare(&self, s: &mut dyn std::fmt::Write, q: char) {
+                sea_orm::prepare_iden_quoted_if_needed(self.as_str(), s, q);
+            }
+        )
+    };
+
+    Ok(quote!(
+        impl sea_orm::Iden for #ident {
+            #expanded_prepare
+
+            fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+                write!(s, "{}", self.as_str()).unwrap();
+            }
+        }
+    ))
+}
@@ -0,0 +1,195 @@
+use crate::{ConnectionTrait, DatabaseConnection, DbBackend, DbErr, PooledConnection, Statement};
+
+/// A Postgres session level advisory lock, held on a connection taken out of the pool.
+///
+/// Obtained from [`DatabaseConnection::advisory_lock`] or [`DatabaseConnection::try_advisory_lock`].
+/// Release it with [`unlock`](Self::unlock), which hands the connection back. A lock that is
+/// dropped instead closes its connection, and Postgres releases the lock with the session.
+#[derive(Debug)]
+pub struct AdvisoryLock {
+    conn: Option<PooledConnection>,
+    key: i64,
+}
+
+impl AdvisoryLock {
+    /// The key the lock was taken on
+    pub fn key(&self) -> i64 {
+        self.key
+    }
+
+    /// The connection holding the lock, to run statements while the lock is held
+    pub fn connection(&self) -> &PooledConnection {
+        self.conn.as_ref().expect("connection of a held lock")
+    }
+
+    /// Release the lock and get back the connection it was held on
+    pub async fn unlock(mut self) -> Result<PooledConnection, DbErr> {
+        let conn = self.conn.take().expect("connection of a held lock");
+        match conn.pg_advisory_unlock(self.key).await {
+            Ok(_) => Ok(conn),
+            Err(e) => {
+                // The lock may still be held, do not return the session to the pool
+                conn.discard();
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Drop for AdvisoryLock {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            conn.discard();
+        }
+    }
+}
+
+impl DatabaseConnection {
+    /// Take a connection out of the pool and wait until it holds the advisory lock on `key`.
+    /// Only supported on Postgres.
+    pub async fn advisory_lock(&self, key: i64) -> Result<AdvisoryLock, DbErr> {
+        advisory_lock_supported(self.get_database_backend())?;
+        let conn = self.acquire().await?;
+        conn.pg_advisory_lock(key).await?;
+        Ok(AdvisoryLock {
+            conn: Some(conn),
+            key,
+        })
+    }
+
+    /// Take the advisory lock on `key` if no other session holds it, without waiting.
+    /// Returns `None` if the lock is taken. Only supported on Postgres.
+    pub async fn try_advisory_lock(&self, key: i64) -> Result<Option<AdvisoryLock>, DbErr> {
+        advisory_lock_supported(self.get_database_backend())?;
+        let conn = self.acquire().await?;
+        if conn.pg_try_advisory_lock(key).await? {
+            Ok(Some(AdvisoryLock {
+                conn: Some(conn),
+                key,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl PooledConnection {
+    /// Wait until this session holds the advisory lock on `key`, with `pg_advisory_lock`.
+    /// Only supported on Postgres.
+    pub async fn pg_advisory_lock(&self, key: i64) -> Result<(), DbErr> {
+        advisory_lock_supported(self.get_database_backend())?;
+        self.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_lock($1)",
+            vec![key.into()],
+        ))
+        .await?;
+        Ok(())
+    }
+
+    /// Take the advisory lock on `key` with `pg_try_advisory_lock`, returning
+    /// whether it was taken. Only supported on Postgres.
+    pub async fn pg_try_advisory_lock(&self, key: i64) -> Result<bool, DbErr> {
+        advisory_lock_supported(self.get_database_backend())?;
+        self.query_one_or_err(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_try_advisory_lock($1) AS locked",
+            vec![key.into()],
+        ))
+        .await?
+        .try_get("", "locked")
+    }
+
+    /// Release the advisory lock on `key` held by this session, with `pg_advisory_unlock`.
+    /// Returns `false` if the session did not hold it. Only supported on Postgres.
+    pub async fn pg_advisory_unlock(&self, key: i64) -> Result<bool, DbErr> {
+        advisory_lock_supported(self.get_database_backend())?;
+        self.query_one_or_err(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_unlock($1) AS unlocked",
+            vec![key.into()],
+        ))
+        .await?
+        .try_get("", "unlocked")
+    }
+}
+
+fn advisory_lock_supported(backend: DbBackend) -> Result<(), DbErr> {
+    match backend {
+        DbBackend::Postgres => Ok(()),
+        _ => Err(DbErr::Query(format!(
+            "advisory locks are only supported on Postgres, not {:?}",
+            backend
+        ))),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{DbBackend, DbErr, MockDatabase, MockExecResult, Transaction};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn advisory_lock() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![
+                // Exec and query results are counted together
+                vec![],
+                vec![maplit::btreemap! { "unlocked" => true.into() }],
+                vec![maplit::btreemap! { "locked" => false.into() }],
+            ])
+            .into_connection();
+
+        let lock = db.advisory_lock(42).await?;
+        assert_eq!(lock.key(), 42);
+        lock.unlock().await?;
+        assert!(db.try_advisory_lock(7).await?.is_none());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "SELECT pg_advisory_lock($1)",
+                    vec![42i64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "SELECT pg_advisory_unlock($1) AS unlocked",
+                    vec![42i64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    "SELECT pg_try_advisory_lock($1) AS locked",
+                    vec![7i64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn advisory_lock_unsupported() {
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            db.advisory_lock(42).await.unwrap_err(),
+            DbErr::Query("advisory locks are only supported on Postgres, not MySql".to_owned())
+        );
+        assert_eq!(
+            db.acquire()
+                .await
+                .unwrap()
+                .pg_advisory_unlock(42)
+                .await
+                .unwrap_err(),
+            DbErr::Query("advisory locks are only supported on Postgres, not MySql".to_owned())
+        );
+    }
+}
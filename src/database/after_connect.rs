@@ -0,0 +1,123 @@
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+use crate::{DbBackend, DbErr, ExecResult, QueryResult, Statement, debug_print, sqlx_error_to_exec_err, sqlx_error_to_query_err};
+
+/// A callback run on every connection the pool opens, before it is handed out,
+/// see [`ConnectOptions::after_connect`](crate::ConnectOptions::after_connect)
+pub type AfterConnectFn = dyn for<'c> Fn(NewConnection<'c>) -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'c>> + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct AfterConnect(Arc<AfterConnectFn>);
+
+impl AfterConnect {
+    pub(crate) fn new(hook: Arc<AfterConnectFn>) -> Self {
+        Self(hook)
+    }
+
+    pub(crate) async fn run(&self, conn: NewConnection<'_>) -> Result<(), DbErr> {
+        (self.0)(conn).await
+    }
+}
+
+impl fmt::Debug for AfterConnect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AfterConnect")
+    }
+}
+
+/// A physical connection the pool has just opened, to set up its session in the
+/// [`ConnectOptions::after_connect`](crate::ConnectOptions::after_connect) hook
+#[derive(Debug)]
+pub enum NewConnection<'c> {
+    #[cfg(feature = "sqlx-mysql")]
+    SqlxMySql(&'c mut sqlx::MySqlConnection),
+    #[cfg(feature = "sqlx-postgres")]
+    SqlxPostgres(&'c mut sqlx::PgConnection),
+    #[cfg(feature = "sqlx-sqlite")]
+    SqlxSqlite(&'c mut sqlx::SqliteConnection),
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl<'c> From<&'c mut sqlx::MySqlConnection> for NewConnection<'c> {
+    fn from(conn: &'c mut sqlx::MySqlConnection) -> Self {
+        NewConnection::SqlxMySql(conn)
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<'c> From<&'c mut sqlx::PgConnection> for NewConnection<'c> {
+    fn from(conn: &'c mut sqlx::PgConnection) -> Self {
+        NewConnection::SqlxPostgres(conn)
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<'c> From<&'c mut sqlx::SqliteConnection> for NewConnection<'c> {
+    fn from(conn: &'c mut sqlx::SqliteConnection) -> Self {
+        NewConnection::SqlxSqlite(conn)
+    }
+}
+
+impl NewConnection<'_> {
+    pub fn get_database_backend(&self) -> DbBackend {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            NewConnection::SqlxMySql(_) => DbBackend::MySql,
+            #[cfg(feature = "sqlx-postgres")]
+            NewConnection::SqlxPostgres(_) => DbBackend::Postgres,
+            #[cfg(feature = "sqlx-sqlite")]
+            NewConnection::SqlxSqlite(_) => DbBackend::Sqlite,
+        }
+    }
+
+    pub async fn execute(&mut self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            NewConnection::SqlxMySql(conn) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                query.execute(&mut **conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            NewConnection::SqlxPostgres(conn) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                query.execute(&mut **conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            NewConnection::SqlxSqlite(conn) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                query.execute(&mut **conn).await
+                    .map(Into::into)
+            },
+        };
+        res.map_err(sqlx_error_to_exec_err)
+    }
+
+    pub async fn query_one(&mut self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            NewConnection::SqlxMySql(conn) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                query.fetch_optional(&mut **conn).await
+                    .map(|row| row.map(Into::into))
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            NewConnection::SqlxPostgres(conn) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                query.fetch_optional(&mut **conn).await
+                    .map(|row| row.map(Into::into))
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            NewConnection::SqlxSqlite(conn) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                query.fetch_optional(&mut **conn).await
+                    .map(|row| row.map(Into::into))
+            },
+        };
+        res.map_err(sqlx_error_to_query_err)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{DatabaseConnection, DbBackend, DbErr};
+use sea_query::Alias;
+
+/// The format of the data streamed by [`DatabaseConnection::copy_in`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// Tab separated columns, one row per line, `\N` for NULL
+    Text,
+    /// Comma separated values, an unquoted empty value for NULL
+    Csv,
+    /// The binary format of Postgres, starting with the `PGCOPY` signature
+    Binary,
+}
+
+#[allow(dead_code)]
+impl CopyFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CopyFormat::Text => "text",
+            CopyFormat::Csv => "csv",
+            CopyFormat::Binary => "binary",
+        }
+    }
+}
+
+/// The `COPY .. FROM STDIN` statement loading `columns` of `table`, run by the Postgres driver
+#[allow(dead_code)]
+pub(crate) fn copy_in_sql(table: &str, columns: &[&str], format: CopyFormat) -> String {
+    let backend = DbBackend::Postgres;
+    let mut sql = format!("COPY {}", backend.quote_iden(&Alias::new(table)));
+    if !columns.is_empty() {
+        let columns: Vec<_> = columns
+            .iter()
+            .map(|column| backend.quote_iden(&Alias::new(column)))
+            .collect();
+        sql.push_str(&format!(" ({})", columns.join(", ")));
+    }
+    sql.push_str(&format!(" FROM STDIN WITH (FORMAT {})", format.as_str()));
+    sql
+}
+
+impl DatabaseConnection {
+    /// Bulk load rows into `table` with `COPY .. FROM STDIN`, streaming them from `source`
+    /// in `format`, which is much faster than inserting them. `columns` are the columns
+    /// of each row in order; leave it empty to copy into every column of the table.
+    /// Returns the number of rows copied. Only supported on Postgres.
+    ///
+    /// The copy runs as a single statement: if any row is rejected, none are loaded.
+    pub async fn copy_in<R>(
+        &self,
+        table: &str,
+        columns: &[&str],
+        format: CopyFormat,
+        source: R,
+    ) -> Result<u64, DbErr>
+    where
+        R: futures::AsyncRead + Unpin,
+    {
+        match self {
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.copy_in(table, columns, format, source).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(_) => Err(DbErr::Exec(
+                "COPY FROM is not supported on a mock connection".to_owned(),
+            )),
+            DatabaseConnection::Disconnected => Err(DbErr::Conn("Disconnected".to_owned())),
+            #[allow(unreachable_patterns)]
+            _ => {
+                let _ = (table, columns, format, source);
+                Err(DbErr::Exec(format!(
+                    "COPY FROM is only supported on Postgres, not {:?}",
+                    crate::ConnectionTrait::get_database_backend(self)
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copy_in_sql, CopyFormat};
+
+    #[test]
+    fn copy_in_statement() {
+        assert_eq!(
+            copy_in_sql("cake", &["id", "name"], CopyFormat::Csv),
+            r#"COPY "cake" ("id", "name") FROM STDIN WITH (FORMAT csv)"#
+        );
+        assert_eq!(
+            copy_in_sql("cake", &[], CopyFormat::Binary),
+            r#"COPY "cake" FROM STDIN WITH (FORMAT binary)"#
+        );
+    }
+}
@@ -1,21 +1,176 @@
-use std::{pin::Pin, future::Future};
-use crate::{DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError};
+use std::{pin::Pin, future::Future, time::Duration};
+use crate::{database_name_stmt, server_version_stmt, DatabaseTransaction, DbBackend, ServerVersion, DbErr, ExecResult, QueryResult, Statement, TransactionError, with_timeout};
 
+/// Anything statements can be run on: a pool, a single pooled connection, a transaction
+/// or a mock. It can be used as `&dyn ConnectionTrait` or `Box<dyn ConnectionTrait>`,
+/// e.g. to keep a connection in a struct, and entity queries accept those as well.
 #[async_trait::async_trait]
 pub trait ConnectionTrait: Sync {
     fn get_database_backend(&self) -> DbBackend;
 
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr>;
 
+    /// Fetch the first row returned by the statement, or `Ok(None)` if it returns no row
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr>;
 
+    /// Fetch the first row returned by the statement, failing with
+    /// [`DbErr::RecordNotFound`] if it returns no row
+    async fn query_one_or_err(&self, stmt: Statement) -> Result<QueryResult, DbErr> {
+        let sql = stmt.sql.clone();
+        self.query_one(stmt)
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(format!("no row returned by: {}", sql)))
+    }
+
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
+    /// Execute raw SQL without bound values, e.g. DDL in a script or migration.
+    /// Build a [`Statement`] with values instead for anything carrying user input.
+    async fn execute_sql(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.execute((sql, self.get_database_backend()).into()).await
+    }
+
+    /// Fetch the first row returned by raw SQL without bound values, see [`execute_sql`](Self::execute_sql)
+    async fn query_one_sql(&self, sql: &str) -> Result<Option<QueryResult>, DbErr> {
+        self.query_one((sql, self.get_database_backend()).into()).await
+    }
+
+    /// Fetch all rows returned by raw SQL without bound values, see [`execute_sql`](Self::execute_sql)
+    async fn query_all_sql(&self, sql: &str) -> Result<Vec<QueryResult>, DbErr> {
+        self.query_all((sql, self.get_database_backend()).into()).await
+    }
+
+    /// Execute the statements in order, stopping at the first error.
+    /// On sqlx connections, statements without values are sent as one batch,
+    /// saving a round trip per statement; otherwise they run one at a time.
+    async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        execute_sequentially(self, stmts).await
+    }
+
+    /// Execute the statement, failing with [`DbErr::Timeout`] if it has not completed
+    /// within `timeout`. On a Postgres pool the statement is also cancelled on the server;
+    /// elsewhere it is only abandoned, and may still run to completion.
+    async fn execute_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<ExecResult, DbErr> {
+        with_timeout(timeout, self.execute(stmt)).await
+    }
+
+    /// [`query_one`](Self::query_one) bounded by a timeout, see [`execute_with_timeout`](Self::execute_with_timeout)
+    async fn query_one_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Option<QueryResult>, DbErr> {
+        with_timeout(timeout, self.query_one(stmt)).await
+    }
+
+    /// [`query_all`](Self::query_all) bounded by a timeout, see [`execute_with_timeout`](Self::execute_with_timeout)
+    async fn query_all_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Vec<QueryResult>, DbErr> {
+        with_timeout(timeout, self.query_all(stmt)).await
+    }
+
+    /// Whether statements run through this connection are part of an open
+    /// transaction; `false` for a pool connection
+    fn is_in_transaction(&self) -> bool {
+        false
+    }
+
+    /// The name of the database connected to: the current database on MySQL and
+    /// Postgres, and `main` on SQLite
+    async fn get_database_name(&self) -> Result<String, DbErr> {
+        self.query_one_or_err(database_name_stmt(self.get_database_backend()))
+            .await?
+            .try_get("", "name")
+    }
+
+    /// The version of the database server, with `SELECT version()` on MySQL and Postgres
+    /// and `SELECT sqlite_version()` on SQLite
+    async fn get_server_version(&self) -> Result<ServerVersion, DbErr> {
+        let version: String = self
+            .query_one_or_err(server_version_stmt(self.get_database_backend()))
+            .await?
+            .try_get("", "version")?;
+        ServerVersion::parse(&version)
+    }
+
+}
+
+/// Running a function inside a transaction, for a connection that can open one.
+///
+/// Kept apart from [`ConnectionTrait`] because its callback makes it generic,
+/// which would keep `ConnectionTrait` from being used as `dyn ConnectionTrait`.
+/// A transaction to be finished by hand is begun with [`DatabaseConnection::begin`](crate::DatabaseConnection::begin).
+#[async_trait::async_trait]
+pub trait TransactionTrait: ConnectionTrait {
     /// Execute the function inside a transaction.
     /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    /// If the function panics, the transaction is rolled back before the panic is resumed.
+    ///
+    /// The function may return a [`DbErr`] as its error, which can be propagated
+    /// with `?` from the [`TransactionError`] as it converts into a [`DbErr`].
     async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
     where
         F: for<'c> FnOnce(&'c DatabaseTransaction<'_>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>> + Send + Sync,
         T: Send,
         E: std::error::Error + Send;
 }
+
+// Pass everything through a reference or box, so that code generic over `C: ConnectionTrait`
+// also takes a `&dyn ConnectionTrait` or a `Box<dyn ConnectionTrait>`
+macro_rules! impl_connection_trait_through {
+    ( $ty: ty ) => {
+        #[async_trait::async_trait]
+        impl<C> ConnectionTrait for $ty
+        where
+            C: ConnectionTrait + ?Sized,
+        {
+            fn get_database_backend(&self) -> DbBackend {
+                (**self).get_database_backend()
+            }
+
+            async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+                (**self).execute(stmt).await
+            }
+
+            async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+                (**self).query_one(stmt).await
+            }
+
+            async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+                (**self).query_all(stmt).await
+            }
+
+            async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+                (**self).execute_many(stmts).await
+            }
+
+            async fn execute_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<ExecResult, DbErr> {
+                (**self).execute_with_timeout(stmt, timeout).await
+            }
+
+            async fn query_one_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Option<QueryResult>, DbErr> {
+                (**self).query_one_with_timeout(stmt, timeout).await
+            }
+
+            async fn query_all_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Vec<QueryResult>, DbErr> {
+                (**self).query_all_with_timeout(stmt, timeout).await
+            }
+
+            fn is_in_transaction(&self) -> bool {
+                (**self).is_in_transaction()
+            }
+        }
+    };
+}
+
+impl_connection_trait_through!(&C);
+impl_connection_trait_through!(Box<C>);
+
+pub(crate) async fn execute_sequentially<C>(
+    conn: &C,
+    stmts: Vec<Statement>,
+) -> Result<Vec<ExecResult>, DbErr>
+where
+    C: ConnectionTrait + ?Sized,
+{
+    let mut results = Vec::with_capacity(stmts.len());
+    for stmt in stmts {
+        results.push(conn.execute(stmt).await?);
+    }
+    Ok(results)
+}
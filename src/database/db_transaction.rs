@@ -1,5 +1,6 @@
-use std::{pin::Pin, future::Future};
-use crate::{DbBackend, ConnectionTrait, DbErr, ExecResult, QueryResult, Statement, debug_print};
+use std::{fmt, pin::Pin, future::Future, panic::{self, AssertUnwindSafe}, sync::atomic::{AtomicU64, Ordering}};
+use futures::FutureExt;
+use crate::{DbBackend, ConnectionTrait, DbErr, ExecResult, QueryResult, Statement, TransactionTrait, debug_print};
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
 #[cfg(feature = "sqlx-dep")]
@@ -8,36 +9,86 @@ use sqlx::Connection;
 #[cfg(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
 use futures::lock::Mutex;
 
+/// A transaction opened on a database connection.
+///
+/// Obtained from [`DatabaseConnection::begin`](crate::DatabaseConnection::begin) and
+/// finished with [`commit`](Self::commit) or [`rollback`](Self::rollback). A transaction
+/// that is dropped without either is rolled back.
+///
+/// [`TransactionTrait::transaction`] on a transaction opens a nested one on a savepoint.
+/// The outer transaction is locked until the nested one is committed or rolled back, so
+/// savepoints are always released in the reverse order they were opened, and rolling one
+/// back only discards the changes made since it was opened, including those of the
+/// savepoints committed into it. Do not use the outer transaction inside the callback,
+/// it waits for the nested one to finish.
 #[derive(Debug)]
 pub enum DatabaseTransaction<'a>  {
     #[cfg(feature = "sqlx-mysql")]
-    SqlxMySqlTransaction(Mutex<sqlx::Transaction<'a, sqlx::MySql>>),
+    SqlxMySqlTransaction(Box<Mutex<sqlx::Transaction<'a, sqlx::MySql>>>, TransactionState),
     #[cfg(feature = "sqlx-postgres")]
-    SqlxPostgresTransaction(Mutex<sqlx::Transaction<'a, sqlx::Postgres>>),
+    SqlxPostgresTransaction(Box<Mutex<sqlx::Transaction<'a, sqlx::Postgres>>>, TransactionState),
     #[cfg(feature = "sqlx-sqlite")]
-    SqlxSqliteTransaction(Mutex<sqlx::Transaction<'a, sqlx::Sqlite>>),
+    SqlxSqliteTransaction(Box<Mutex<sqlx::Transaction<'a, sqlx::Sqlite>>>, TransactionState),
+    #[cfg(feature = "mock")]
+    MockDatabaseTransaction(crate::MockDatabaseTransaction, TransactionState),
     #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
     None(&'a ()),
 }
 
+/// What every kind of [`DatabaseTransaction`] keeps track of besides its connection
+#[derive(Debug)]
+pub struct TransactionState {
+    depth: u32,
+    rows_affected: AtomicU64,
+    id: u64,
+}
+
+static NEXT_TRANSACTION_ID: AtomicU64 = AtomicU64::new(1);
+
+impl TransactionState {
+    fn new(depth: u32) -> Self {
+        Self {
+            depth,
+            rows_affected: AtomicU64::new(0),
+            id: NEXT_TRANSACTION_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+/// Log a statement, tagged with `transaction_id=..` when it runs in a transaction
+#[allow(unused_variables)]
+pub(crate) fn debug_print_statement(transaction_id: Option<u64>, stmt: &dyn fmt::Display) {
+    match transaction_id {
+        Some(id) => { debug_print!("transaction_id={} {}", id, stmt); },
+        None => { debug_print!("{}", stmt); },
+    }
+}
+
 #[cfg(feature = "sqlx-mysql")]
 impl<'a> From<sqlx::Transaction<'a, sqlx::MySql>> for DatabaseTransaction<'a> {
     fn from(inner: sqlx::Transaction<'a, sqlx::MySql>) -> Self {
-        DatabaseTransaction::SqlxMySqlTransaction(Mutex::new(inner))
+        DatabaseTransaction::SqlxMySqlTransaction(Box::new(Mutex::new(inner)), TransactionState::new(1))
     }
 }
 
 #[cfg(feature = "sqlx-postgres")]
 impl<'a> From<sqlx::Transaction<'a, sqlx::Postgres>> for DatabaseTransaction<'a> {
     fn from(inner: sqlx::Transaction<'a, sqlx::Postgres>) -> Self {
-        DatabaseTransaction::SqlxPostgresTransaction(Mutex::new(inner))
+        DatabaseTransaction::SqlxPostgresTransaction(Box::new(Mutex::new(inner)), TransactionState::new(1))
     }
 }
 
 #[cfg(feature = "sqlx-sqlite")]
 impl<'a> From<sqlx::Transaction<'a, sqlx::Sqlite>> for DatabaseTransaction<'a> {
     fn from(inner: sqlx::Transaction<'a, sqlx::Sqlite>) -> Self {
-        DatabaseTransaction::SqlxSqliteTransaction(Mutex::new(inner))
+        DatabaseTransaction::SqlxSqliteTransaction(Box::new(Mutex::new(inner)), TransactionState::new(1))
+    }
+}
+
+#[cfg(feature = "mock")]
+impl<'a> From<crate::MockDatabaseTransaction> for DatabaseTransaction<'a> {
+    fn from(inner: crate::MockDatabaseTransaction) -> Self {
+        DatabaseTransaction::MockDatabaseTransaction(inner, TransactionState::new(1))
     }
 }
 
@@ -49,56 +100,183 @@ impl<'a> DatabaseTransaction<'a> {
         T: Send,
         E: std::error::Error + Send,
     {
-        let res = callback(&self).await.map_err(|e| TransactionError::Transaction(e));
+        // Roll back before letting a panic in the callback propagate,
+        // so that a half-applied transaction is never left behind
+        let res = match AssertUnwindSafe(callback(&self)).catch_unwind().await {
+            Ok(res) => res.map_err(TransactionError::Transaction),
+            Err(panic) => {
+                let _ = self.rollback().await;
+                panic::resume_unwind(panic);
+            }
+        };
         if res.is_ok() {
-            self.commit().await?;
+            self.commit().await.map_err(TransactionError::Connection)?;
         }
         else {
-            self.rollback().await?;
+            self.rollback().await.map_err(TransactionError::Connection)?;
         }
         res
     }
 
-    async fn commit<E>(self) -> Result<(), TransactionError<E>>
-    where E: std::error::Error {
+    /// The total number of rows affected by the statements executed on this
+    /// transaction so far. Statements run in a nested transaction are counted
+    /// by that transaction, which starts again from zero
+    pub fn rows_affected(&self) -> u64 {
+        self.state().rows_affected.load(Ordering::Relaxed)
+    }
+
+    fn add_rows_affected(&self, rows_affected: u64) {
+        self.state().rows_affected.fetch_add(rows_affected, Ordering::Relaxed);
+    }
+
+    fn state(&self) -> &TransactionState {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseTransaction::SqlxMySqlTransaction(_, state) => state,
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseTransaction::SqlxPostgresTransaction(_, state) => state,
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseTransaction::SqlxSqliteTransaction(_, state) => state,
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(_, state) => state,
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        }
+    }
+
+    #[allow(clippy::let_unit_value)]
+    async fn execute_statement(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print_statement(Some(self.id()), &stmt);
+
+        let _res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseTransaction::SqlxMySqlTransaction(conn, _) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseTransaction::SqlxPostgresTransaction(conn, _) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseTransaction::SqlxSqliteTransaction(conn, _) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                return inner.get_connection().execute(stmt).await;
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        };
+        #[cfg(feature = "sqlx-dep")]
+        _res.map_err(sqlx_error_to_exec_err)
+    }
+
+    async fn execute_many_statements(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseTransaction::SqlxMySqlTransaction(conn, _) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_mysql::sqlx_execute_many(&mut conn, stmts, Some(self.id())).await
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseTransaction::SqlxPostgresTransaction(conn, _) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_postgres::sqlx_execute_many(&mut conn, stmts, Some(self.id())).await
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseTransaction::SqlxSqliteTransaction(conn, _) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_sqlite::sqlx_execute_many(&mut conn, stmts, Some(self.id())).await
+            },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                let mut results = Vec::with_capacity(stmts.len());
+                for stmt in stmts {
+                    debug_print_statement(Some(self.id()), &stmt);
+                    results.push(inner.get_connection().execute(stmt).await?);
+                }
+                Ok(results)
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        }
+    }
+
+    /// An id unique to this transaction within the process, which stays the same for its
+    /// lifetime. Every statement it runs is logged with `transaction_id=<id>` in front, so
+    /// the statements of one transaction can be told apart in the logs. A nested transaction
+    /// gets an id of its own
+    pub fn id(&self) -> u64 {
+        self.state().id
+    }
+
+    /// The nesting level of this transaction: `1` for a transaction begun on a
+    /// connection, incremented for every savepoint opened with
+    /// [`TransactionTrait::transaction`] inside it
+    pub fn depth(&self) -> u32 {
+        self.state().depth
+    }
+
+    /// Commit the transaction, making its changes visible to other connections
+    pub async fn commit(self) -> Result<(), DbErr> {
         match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(inner) => {
+            DatabaseTransaction::SqlxMySqlTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.commit().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.commit().await.map_err(|e| DbErr::Query(e.to_string()))
             },
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(inner) => {
+            DatabaseTransaction::SqlxPostgresTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.commit().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.commit().await.map_err(|e| DbErr::Query(e.to_string()))
             },
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(inner) => {
+            DatabaseTransaction::SqlxSqliteTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.commit().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.commit().await.map_err(|e| DbErr::Query(e.to_string()))
+            },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                inner.commit();
+                Ok(())
             },
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
             _ => unimplemented!(),
         }
     }
 
-    async fn rollback<E>(self) -> Result<(), TransactionError<E>>
-    where E: std::error::Error {
+    /// Roll back the transaction, discarding all of its changes
+    pub async fn rollback(self) -> Result<(), DbErr> {
         match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(inner) => {
+            DatabaseTransaction::SqlxMySqlTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.rollback().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.rollback().await.map_err(|e| DbErr::Query(e.to_string()))
             },
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(inner) => {
+            DatabaseTransaction::SqlxPostgresTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.rollback().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.rollback().await.map_err(|e| DbErr::Query(e.to_string()))
             },
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(inner) => {
+            DatabaseTransaction::SqlxSqliteTransaction(inner, _) => {
                 let transaction = inner.into_inner();
-                transaction.rollback().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))
+                transaction.rollback().await.map_err(|e| DbErr::Query(e.to_string()))
+            },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                inner.rollback();
+                Ok(())
             },
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
             _ => unimplemented!(),
@@ -108,76 +286,66 @@ impl<'a> DatabaseTransaction<'a> {
 
 #[async_trait::async_trait]
 impl<'a> ConnectionTrait for DatabaseTransaction<'a> {
+    fn is_in_transaction(&self) -> bool {
+        true
+    }
+
     fn get_database_backend(&self) -> DbBackend {
         match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(_) => DbBackend::MySql,
+            DatabaseTransaction::SqlxMySqlTransaction(_, _) => DbBackend::MySql,
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(_) => DbBackend::Postgres,
+            DatabaseTransaction::SqlxPostgresTransaction(_, _) => DbBackend::Postgres,
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(_) => DbBackend::Sqlite,
+            DatabaseTransaction::SqlxSqliteTransaction(_, _) => DbBackend::Sqlite,
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => inner.get_connection().get_database_backend(),
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
             _ => unimplemented!(),
         }
     }
 
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
-        debug_print!("{}", stmt);
+        let res = self.execute_statement(stmt).await?;
+        self.add_rows_affected(res.rows_affected());
+        Ok(res)
+    }
 
-        let _res = match self {
-            #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
-                let mut conn = conn.lock().await;
-                query.execute(&mut *conn).await
-                    .map(Into::into)
-            },
-            #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
-                let mut conn = conn.lock().await;
-                query.execute(&mut *conn).await
-                    .map(Into::into)
-            },
-            #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
-                let mut conn = conn.lock().await;
-                query.execute(&mut *conn).await
-                    .map(Into::into)
-            },
-            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
-            _ => unimplemented!(),
-        };
-        #[cfg(feature = "sqlx-dep")]
-        _res.map_err(sqlx_error_to_exec_err)
+    async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let res = self.execute_many_statements(stmts).await?;
+        self.add_rows_affected(res.iter().map(ExecResult::rows_affected).sum());
+        Ok(res)
     }
 
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
+        debug_print_statement(Some(self.id()), &stmt);
 
         let _res = match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(conn) => {
+            DatabaseTransaction::SqlxMySqlTransaction(conn, _) => {
                 let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                 let mut conn = conn.lock().await;
                 query.fetch_one(&mut *conn).await
                     .map(|row| Some(row.into()))
             },
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+            DatabaseTransaction::SqlxPostgresTransaction(conn, _) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
                 let mut conn = conn.lock().await;
                 query.fetch_one(&mut *conn).await
                     .map(|row| Some(row.into()))
             },
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+            DatabaseTransaction::SqlxSqliteTransaction(conn, _) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
                 let mut conn = conn.lock().await;
                 query.fetch_one(&mut *conn).await
                     .map(|row| Some(row.into()))
             },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                return inner.get_connection().query_one(stmt).await;
+            },
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
             _ => unimplemented!(),
         };
@@ -191,37 +359,44 @@ impl<'a> ConnectionTrait for DatabaseTransaction<'a> {
     }
 
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        debug_print!("{}", stmt);
+        debug_print_statement(Some(self.id()), &stmt);
 
         let _res = match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(conn) => {
+            DatabaseTransaction::SqlxMySqlTransaction(conn, _) => {
                 let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
                 let mut conn = conn.lock().await;
                 query.fetch_all(&mut *conn).await
                     .map(|rows| rows.into_iter().map(|r| r.into()).collect())
             },
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+            DatabaseTransaction::SqlxPostgresTransaction(conn, _) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
                 let mut conn = conn.lock().await;
                 query.fetch_all(&mut *conn).await
                     .map(|rows| rows.into_iter().map(|r| r.into()).collect())
             },
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+            DatabaseTransaction::SqlxSqliteTransaction(conn, _) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
                 let mut conn = conn.lock().await;
                 query.fetch_all(&mut *conn).await
                     .map(|rows| rows.into_iter().map(|r| r.into()).collect())
             },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, _) => {
+                return inner.get_connection().query_all(stmt).await;
+            },
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
             _ => unimplemented!(),
         };
         #[cfg(feature = "sqlx-dep")]
         _res.map_err(sqlx_error_to_query_err)
     }
+}
 
+#[async_trait::async_trait]
+impl<'a> TransactionTrait for DatabaseTransaction<'a> {
     /// Execute the function inside a transaction.
     /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
     async fn transaction<F, T, E>(&self, _callback: F) -> Result<T, TransactionError<E>>
@@ -232,21 +407,32 @@ impl<'a> ConnectionTrait for DatabaseTransaction<'a> {
     {
         match self {
             #[cfg(feature = "sqlx-mysql")]
-            DatabaseTransaction::SqlxMySqlTransaction(conn) => {
+            DatabaseTransaction::SqlxMySqlTransaction(conn, state) => {
                 let mut conn = conn.lock().await;
-                let transaction = DatabaseTransaction::from(conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?);
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                let transaction = DatabaseTransaction::SqlxMySqlTransaction(Box::new(Mutex::new(transaction)), TransactionState::new(state.depth + 1));
                 transaction.run(_callback).await
             },
             #[cfg(feature = "sqlx-postgres")]
-            DatabaseTransaction::SqlxPostgresTransaction(conn) => {
+            DatabaseTransaction::SqlxPostgresTransaction(conn, state) => {
                 let mut conn = conn.lock().await;
-                let transaction = DatabaseTransaction::from(conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?);
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                let transaction = DatabaseTransaction::SqlxPostgresTransaction(Box::new(Mutex::new(transaction)), TransactionState::new(state.depth + 1));
                 transaction.run(_callback).await
             },
             #[cfg(feature = "sqlx-sqlite")]
-            DatabaseTransaction::SqlxSqliteTransaction(conn) => {
+            DatabaseTransaction::SqlxSqliteTransaction(conn, state) => {
                 let mut conn = conn.lock().await;
-                let transaction = DatabaseTransaction::from(conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?);
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                let transaction = DatabaseTransaction::SqlxSqliteTransaction(Box::new(Mutex::new(transaction)), TransactionState::new(state.depth + 1));
+                transaction.run(_callback).await
+            },
+            #[cfg(feature = "mock")]
+            DatabaseTransaction::MockDatabaseTransaction(inner, state) => {
+                let transaction = DatabaseTransaction::MockDatabaseTransaction(
+                    crate::MockDatabaseTransaction::new(std::sync::Arc::clone(inner.get_connection())),
+                    TransactionState::new(state.depth + 1),
+                );
                 transaction.run(_callback).await
             },
             #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
@@ -274,3 +460,95 @@ where E: std::error::Error {
 
 impl<E> std::error::Error for TransactionError<E>
 where E: std::error::Error {}
+
+/// Lets `?` propagate the error of a transaction whose callback returns a [`DbErr`],
+/// or an error convertible into one, from a function returning a [`DbErr`]
+impl<E> From<TransactionError<E>> for DbErr
+where E: std::error::Error + Into<DbErr> {
+    fn from(err: TransactionError<E>) -> Self {
+        match err {
+            TransactionError::Connection(e) => e,
+            TransactionError::Transaction(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "mock", feature = "debug-print"))]
+mod tests {
+    use crate::{tests_cfg::cake, EntityTrait, DbBackend, DbErr, MockDatabase, MockExecResult};
+    use pretty_assertions::assert_eq;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CAPTURED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // Tests run on threads of their own, each one only sees the records it logged
+    struct CaptureLogger;
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            CAPTURED.with(|captured| captured.borrow_mut().push(record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CaptureLogger = CaptureLogger;
+
+    fn transaction_ids() -> Vec<u64> {
+        CAPTURED.with(|captured| {
+            captured
+                .borrow()
+                .iter()
+                .filter_map(|line| line.strip_prefix("transaction_id="))
+                .map(|line| line.split(' ').next().unwrap().parse().unwrap())
+                .collect()
+        })
+    }
+
+    #[smol_potat::test]
+    async fn transaction_id_logged() -> Result<(), DbErr> {
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(log::LevelFilter::Debug);
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_query_results(vec![
+                // Exec and query results are counted together
+                vec![],
+                Vec::<cake::Model>::new(),
+                Vec::<cake::Model>::new(),
+                Vec::<cake::Model>::new(),
+            ])
+            .into_connection();
+
+        let txn = db.begin().await?;
+        let first = txn.id();
+        cake::Entity::delete_many().exec(&txn).await?;
+        cake::Entity::find().all(&txn).await?;
+        txn.commit().await?;
+
+        let txn = db.begin().await?;
+        let second = txn.id();
+        cake::Entity::find().all(&txn).await?;
+        txn.rollback().await?;
+
+        // Outside of a transaction, statements are logged without an id
+        cake::Entity::find().all(&db).await?;
+
+        assert_ne!(first, second);
+        assert_eq!(transaction_ids(), vec![first, first, second]);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,17 @@
 use crate::{
     error::*, DatabaseConnection, DbBackend, EntityTrait, ExecResult, ExecResultHolder, Iden,
     Iterable, MockDatabaseConnection, MockDatabaseTrait, ModelTrait, QueryResult, QueryResultRow,
-    Statement, Transaction,
+    Statement, Transaction, TryGetError,
 };
+use crate::{column_index_out_of_bounds, is_null_value};
 use sea_query::{Value, ValueType};
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[derive(Debug)]
 pub struct MockDatabase {
     db_backend: DbBackend,
     transaction_log: Vec<Transaction>,
+    transaction: Option<OpenTransaction>,
     exec_results: Vec<MockExecResult>,
     query_results: Vec<Vec<MockRow>>,
 }
@@ -25,6 +27,13 @@ pub struct MockRow {
     values: BTreeMap<String, Value>,
 }
 
+/// The statements of a transaction that has not been committed or rolled back yet
+#[derive(Debug)]
+struct OpenTransaction {
+    stmts: Vec<Statement>,
+    transaction_depth: usize,
+}
+
 pub trait IntoMockRow {
     fn into_mock_row(self) -> MockRow;
 }
@@ -34,13 +43,14 @@ impl MockDatabase {
         Self {
             db_backend,
             transaction_log: Vec::new(),
+            transaction: None,
             exec_results: Vec::new(),
             query_results: Vec::new(),
         }
     }
 
     pub fn into_connection(self) -> DatabaseConnection {
-        DatabaseConnection::MockDatabaseConnection(MockDatabaseConnection::new(self))
+        DatabaseConnection::MockDatabaseConnection(Arc::new(MockDatabaseConnection::new(self)))
     }
 
     pub fn append_exec_results(mut self, mut vec: Vec<MockExecResult>) -> Self {
@@ -62,7 +72,7 @@ impl MockDatabase {
 
 impl MockDatabaseTrait for MockDatabase {
     fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
-        self.transaction_log.push(Transaction::one(statement));
+        self.log(statement);
         if counter < self.exec_results.len() {
             Ok(ExecResult {
                 result: ExecResultHolder::Mock(std::mem::take(&mut self.exec_results[counter])),
@@ -73,12 +83,13 @@ impl MockDatabaseTrait for MockDatabase {
     }
 
     fn query(&mut self, counter: usize, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        self.transaction_log.push(Transaction::one(statement));
+        self.log(statement);
         if counter < self.query_results.len() {
             Ok(std::mem::take(&mut self.query_results[counter])
                 .into_iter()
                 .map(|row| QueryResult {
                     row: QueryResultRow::Mock(row),
+                    sensitive: Vec::new(),
                 })
                 .collect())
         } else {
@@ -86,6 +97,29 @@ impl MockDatabaseTrait for MockDatabase {
         }
     }
 
+    fn begin(&mut self) {
+        match &mut self.transaction {
+            Some(transaction) => {
+                transaction.transaction_depth += 1;
+                let sql = format!("SAVEPOINT savepoint_{}", transaction.transaction_depth);
+                transaction.push(self.db_backend, sql);
+            }
+            None => {
+                let mut transaction = OpenTransaction::new();
+                transaction.push(self.db_backend, "BEGIN".to_owned());
+                self.transaction = Some(transaction);
+            }
+        }
+    }
+
+    fn commit(&mut self) {
+        self.close_transaction("COMMIT", "RELEASE SAVEPOINT");
+    }
+
+    fn rollback(&mut self) {
+        self.close_transaction("ROLLBACK", "ROLLBACK TO SAVEPOINT");
+    }
+
     fn drain_transaction_log(&mut self) -> Vec<Transaction> {
         std::mem::take(&mut self.transaction_log)
     }
@@ -95,13 +129,81 @@ impl MockDatabaseTrait for MockDatabase {
     }
 }
 
+impl MockDatabase {
+    fn log(&mut self, statement: Statement) {
+        match &mut self.transaction {
+            Some(transaction) => transaction.stmts.push(statement),
+            None => self.transaction_log.push(Transaction::one(statement)),
+        }
+    }
+
+    fn close_transaction(&mut self, end: &str, end_savepoint: &str) {
+        let mut transaction = self
+            .transaction
+            .take()
+            .expect("there is no open transaction");
+        if transaction.transaction_depth == 0 {
+            transaction.push(self.db_backend, end.to_owned());
+            self.transaction_log.push(Transaction::many(transaction.stmts));
+        } else {
+            let sql = format!("{} savepoint_{}", end_savepoint, transaction.transaction_depth);
+            transaction.push(self.db_backend, sql);
+            transaction.transaction_depth -= 1;
+            self.transaction = Some(transaction);
+        }
+    }
+}
+
+impl OpenTransaction {
+    fn new() -> Self {
+        Self {
+            stmts: Vec::new(),
+            transaction_depth: 0,
+        }
+    }
+
+    fn push(&mut self, db_backend: DbBackend, sql: String) {
+        self.stmts.push(Statement::from_string(db_backend, sql));
+    }
+}
+
 impl MockRow {
     pub fn try_get<T>(&self, col: &str) -> Result<T, DbErr>
     where
         T: ValueType,
     {
-        T::try_from(self.values.get(col).unwrap().clone())
-            .map_err(|e| DbErr::Query(e.to_string()))
+        T::try_from(self.value(col)?.clone()).map_err(|e| DbErr::Query(e.to_string()))
+    }
+
+    /// Decode a column for [`TryGetable`](crate::TryGetable), telling a NULL apart from
+    /// a value of the wrong type
+    pub(crate) fn try_get_column<T>(&self, col: &str) -> Result<T, TryGetError>
+    where
+        T: ValueType,
+    {
+        decode_value(self.value(col).map_err(TryGetError::DbErr)?, col)
+    }
+
+    fn value(&self, col: &str) -> Result<&Value, DbErr> {
+        self.values
+            .get(col)
+            .ok_or_else(|| DbErr::Type(format!("no column {}", col)))
+    }
+
+    /// Mock rows keep their columns in lexical order, so `idx` counts in that order
+    pub(crate) fn try_get_by_index<T>(&self, idx: usize) -> Result<T, TryGetError>
+    where
+        T: ValueType,
+    {
+        let (col, value) = self.values.iter().nth(idx).ok_or_else(|| {
+            TryGetError::DbErr(column_index_out_of_bounds(idx, self.values.len()))
+        })?;
+        decode_value(value, col)
+    }
+
+    /// The names of the columns in this row, in lexical order
+    pub fn column_names(&self) -> Vec<String> {
+        self.values.keys().cloned().collect()
     }
 
     pub fn into_column_value_tuples(self) -> impl Iterator<Item = (String, Value)> {
@@ -109,6 +211,23 @@ impl MockRow {
     }
 }
 
+fn decode_value<T>(value: &Value, col: &str) -> Result<T, TryGetError>
+where
+    T: ValueType,
+{
+    if is_null_value(value) {
+        return Err(TryGetError::Null);
+    }
+    T::try_from(value.clone()).map_err(|_| {
+        TryGetError::DbErr(DbErr::Type(format!(
+            "column `{}` holds {:?}, which cannot be decoded as `{}`",
+            col,
+            value,
+            std::any::type_name::<T>()
+        )))
+    })
+}
+
 impl IntoMockRow for MockRow {
     fn into_mock_row(self) -> MockRow {
         self
@@ -135,3 +254,296 @@ impl IntoMockRow for BTreeMap<&str, Value> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::*, tests_cfg::*, DbBackend, DbErr, MockDatabase, Statement, Transaction,
+        TransactionError, TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, PartialEq)]
+    struct MyErr(String);
+
+    impl std::error::Error for MyErr {}
+
+    impl std::fmt::Display for MyErr {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0.as_str())
+        }
+    }
+
+    fn stmt(sql: &str) -> Statement {
+        Statement::from_string(DbBackend::Postgres, sql.to_owned())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_1() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![cake::Model {
+                    id: 1,
+                    name: "Apple Pie".to_owned(),
+                }],
+                vec![cake::Model {
+                    id: 1,
+                    name: "Apple Pie".to_owned(),
+                }],
+            ])
+            .into_connection();
+
+        db.transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move {
+                let cake = cake::ActiveModel {
+                    name: Set("Apple Pie".to_owned()),
+                    ..Default::default()
+                };
+                let res = cake::Entity::insert(cake).exec(txn).await?;
+                let fruit = fruit::ActiveModel {
+                    name: Set("Apple".to_owned()),
+                    cake_id: Set(Some(res.last_insert_id)),
+                    ..Default::default()
+                };
+                fruit::Entity::insert(fruit).exec(txn).await?;
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                stmt("BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id""#,
+                    vec!["Apple Pie".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2) RETURNING "id""#,
+                    vec!["Apple".into(), 1i32.into()]
+                ),
+                stmt("COMMIT"),
+            ])]
+        );
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_2() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+
+        let res = db
+            .transaction::<_, (), _>(|txn| {
+                Box::pin(async move {
+                    cake::Entity::find().one(txn).await.unwrap();
+                    Err(MyErr("test".to_owned()))
+                })
+            })
+            .await;
+        assert!(matches!(res, Err(TransactionError::Transaction(MyErr(_)))));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                stmt("BEGIN"),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    vec![1u64.into()]
+                ),
+                stmt("ROLLBACK"),
+            ])]
+        );
+    }
+
+    #[smol_potat::test]
+    async fn test_nested_transaction() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                Vec::<cake::Model>::new(),
+                Vec::<cake::Model>::new(),
+            ])
+            .into_connection();
+
+        db.transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move {
+                cake::Entity::find().all(txn).await?;
+                let res = txn
+                    .transaction::<_, (), _>(|txn| {
+                        Box::pin(async move {
+                            cake::Entity::find().all(txn).await.unwrap();
+                            Err(MyErr("nested".to_owned()))
+                        })
+                    })
+                    .await;
+                assert!(res.is_err());
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        let select = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+            vec![],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                stmt("BEGIN"),
+                select.clone(),
+                stmt("SAVEPOINT savepoint_1"),
+                select.clone(),
+                stmt("ROLLBACK TO SAVEPOINT savepoint_1"),
+                stmt("COMMIT"),
+            ])]
+        );
+    }
+
+    #[smol_potat::test]
+    async fn test_begin_commit_drop() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                Vec::<cake::Model>::new(),
+                Vec::<cake::Model>::new(),
+                Vec::<cake::Model>::new(),
+            ])
+            .into_connection();
+
+        let txn = db.begin().await?;
+        cake::Entity::find().all(&txn).await?;
+        txn.commit().await?;
+
+        {
+            let txn = db.begin().await?;
+            cake::Entity::find().all(&txn).await?;
+        }
+
+        cake::Entity::find().all(&db).await?;
+
+        let select = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+            vec![],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::many(vec![stmt("BEGIN"), select.clone(), stmt("COMMIT")]),
+                Transaction::many(vec![stmt("BEGIN"), select.clone(), stmt("ROLLBACK")]),
+                Transaction::one(select.clone()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn debug_query_result() -> Result<(), DbErr> {
+        use crate::ConnectionTrait;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![fruit::Model {
+                id: 3,
+                name: "Banana".to_owned(),
+                cake_id: None,
+            }]])
+            .into_connection();
+        let row = db.query_one(stmt("SELECT * FROM fruit")).await?.unwrap();
+
+        // Mock rows keep their columns in lexical order
+        assert_eq!(
+            format!("{:?}", row),
+            r#"QueryResult { cake_id: NULL, id: 3, name: "Banana" }"#
+        );
+        assert_eq!(
+            format!("{:?}", row.with_sensitive_columns(vec!["name"])),
+            r#"QueryResult { cake_id: NULL, id: 3, name: **** }"#
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn mocker_without_transaction_hooks() -> Result<(), DbErr> {
+        use crate::{
+            ConnectionTrait, DatabaseConnection, ExecResult, ExecResultHolder,
+            MockDatabaseConnection, MockDatabaseTrait, MockExecResult, QueryResult,
+        };
+        use std::sync::Arc;
+
+        // Implements only the required methods, as mockers written before transactions did
+        #[derive(Debug, Default)]
+        struct StatementLog(Vec<Statement>);
+
+        impl MockDatabaseTrait for StatementLog {
+            fn execute(&mut self, _: usize, stmt: Statement) -> Result<ExecResult, DbErr> {
+                self.0.push(stmt);
+                Ok(ExecResult {
+                    result: ExecResultHolder::Mock(MockExecResult::default()),
+                })
+            }
+
+            fn query(&mut self, _: usize, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+                self.0.push(stmt);
+                Ok(Vec::new())
+            }
+
+            fn drain_transaction_log(&mut self) -> Vec<Transaction> {
+                Transaction::wrap(std::mem::take(&mut self.0))
+            }
+
+            fn get_database_backend(&self) -> DbBackend {
+                DbBackend::Postgres
+            }
+        }
+
+        let db = DatabaseConnection::MockDatabaseConnection(Arc::new(MockDatabaseConnection::new(
+            StatementLog::default(),
+        )));
+        let txn = db.begin().await?;
+        txn.execute(stmt("DELETE FROM fruit")).await?;
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::one(stmt("DELETE FROM fruit"))]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn try_get_missing_column() -> Result<(), DbErr> {
+        use crate::ConnectionTrait;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Apple Pie".to_owned(),
+            }]])
+            .into_connection();
+        let row = db.query_one(stmt("SELECT * FROM cake")).await?.unwrap();
+
+        assert_eq!(
+            row.try_get::<i32>("", "cake_id"),
+            Err(DbErr::Type("no column cake_id".to_owned()))
+        );
+        assert_eq!(
+            row.try_get::<Option<i32>>("", "cake_id"),
+            Err(DbErr::Type("no column cake_id".to_owned()))
+        );
+        assert_eq!(
+            fruit::Model::from_query_result(&row, ""),
+            Err(DbErr::Type("no column cake_id".to_owned()))
+        );
+
+        Ok(())
+    }
+}
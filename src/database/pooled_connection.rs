@@ -0,0 +1,268 @@
+use std::{pin::Pin, future::Future};
+use crate::{DbBackend, ConnectionTrait, DatabaseTransaction, DbErr, ExecResult, QueryResult, Statement, TransactionError, TransactionTrait, debug_print};
+#[cfg(feature = "sqlx-dep")]
+use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
+#[cfg(feature = "sqlx-dep")]
+use sqlx::Connection;
+
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+use futures::lock::Mutex;
+
+/// A single connection taken out of the pool.
+///
+/// Obtained from [`DatabaseConnection::acquire`](crate::DatabaseConnection::acquire). Every
+/// statement run through it goes to the same physical connection, so session state such as
+/// temporary tables, session variables and advisory locks carries over from one statement to
+/// the next. The connection returns to the pool when this is dropped.
+#[derive(Debug)]
+pub enum PooledConnection {
+    #[cfg(feature = "sqlx-mysql")]
+    SqlxMySqlPooledConnection(Box<Mutex<sqlx::pool::PoolConnection<sqlx::MySql>>>),
+    #[cfg(feature = "sqlx-postgres")]
+    SqlxPostgresPooledConnection(Box<Mutex<sqlx::pool::PoolConnection<sqlx::Postgres>>>),
+    #[cfg(feature = "sqlx-sqlite")]
+    SqlxSqlitePooledConnection(Box<Mutex<sqlx::pool::PoolConnection<sqlx::Sqlite>>>),
+    #[cfg(feature = "mock")]
+    MockPooledConnection(std::sync::Arc<crate::MockDatabaseConnection>),
+    #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+    None,
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl From<sqlx::pool::PoolConnection<sqlx::MySql>> for PooledConnection {
+    fn from(inner: sqlx::pool::PoolConnection<sqlx::MySql>) -> Self {
+        PooledConnection::SqlxMySqlPooledConnection(Box::new(Mutex::new(inner)))
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl From<sqlx::pool::PoolConnection<sqlx::Postgres>> for PooledConnection {
+    fn from(inner: sqlx::pool::PoolConnection<sqlx::Postgres>) -> Self {
+        PooledConnection::SqlxPostgresPooledConnection(Box::new(Mutex::new(inner)))
+    }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl From<sqlx::pool::PoolConnection<sqlx::Sqlite>> for PooledConnection {
+    fn from(inner: sqlx::pool::PoolConnection<sqlx::Sqlite>) -> Self {
+        PooledConnection::SqlxSqlitePooledConnection(Box::new(Mutex::new(inner)))
+    }
+}
+
+impl PooledConnection {
+    /// Close the connection instead of returning it to the pool, ending its session
+    /// along with any state left on it
+    pub(crate) fn discard(self) {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => drop(conn.into_inner().detach()),
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => drop(conn.into_inner().detach()),
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => drop(conn.into_inner().detach()),
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(_) => {},
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => {},
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for PooledConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(_) => DbBackend::MySql,
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(_) => DbBackend::Postgres,
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(_) => DbBackend::Sqlite,
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(conn) => conn.get_database_backend(),
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        }
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        let _res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.execute(&mut *conn).await
+                    .map(Into::into)
+            },
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(conn) => {
+                return conn.execute(stmt).await;
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        };
+        #[cfg(feature = "sqlx-dep")]
+        _res.map_err(sqlx_error_to_exec_err)
+    }
+
+    async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_mysql::sqlx_execute_many(&mut conn, stmts, None).await
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_postgres::sqlx_execute_many(&mut conn, stmts, None).await
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                crate::driver::sqlx_sqlite::sqlx_execute_many(&mut conn, stmts, None).await
+            },
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(_) => {
+                crate::execute_sequentially(self, stmts).await
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let _res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let mut conn = conn.lock().await;
+                query.fetch_one(&mut *conn).await
+                    .map(|row| Some(row.into()))
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.fetch_one(&mut *conn).await
+                    .map(|row| Some(row.into()))
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.fetch_one(&mut *conn).await
+                    .map(|row| Some(row.into()))
+            },
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(conn) => {
+                return conn.query_one(stmt).await;
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        };
+        #[cfg(feature = "sqlx-dep")]
+        if let Err(sqlx::Error::RowNotFound) = _res {
+            Ok(None)
+        }
+        else {
+            _res.map_err(sqlx_error_to_query_err)
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        let _res = match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => {
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let mut conn = conn.lock().await;
+                query.fetch_all(&mut *conn).await
+                    .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => {
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.fetch_all(&mut *conn).await
+                    .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => {
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt)?;
+                let mut conn = conn.lock().await;
+                query.fetch_all(&mut *conn).await
+                    .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+            },
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(conn) => {
+                return conn.query_all(stmt).await;
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        };
+        #[cfg(feature = "sqlx-dep")]
+        _res.map_err(sqlx_error_to_query_err)
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for PooledConnection {
+    /// Execute the function inside a transaction begun on this connection.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    async fn transaction<F, T, E>(&self, _callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction<'_>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>> + Send + Sync,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            PooledConnection::SqlxMySqlPooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                DatabaseTransaction::from(transaction).run(_callback).await
+            },
+            #[cfg(feature = "sqlx-postgres")]
+            PooledConnection::SqlxPostgresPooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                DatabaseTransaction::from(transaction).run(_callback).await
+            },
+            #[cfg(feature = "sqlx-sqlite")]
+            PooledConnection::SqlxSqlitePooledConnection(conn) => {
+                let mut conn = conn.lock().await;
+                let transaction = conn.begin().await.map_err(|e| TransactionError::Connection(DbErr::Query(e.to_string())))?;
+                DatabaseTransaction::from(transaction).run(_callback).await
+            },
+            #[cfg(feature = "mock")]
+            PooledConnection::MockPooledConnection(conn) => {
+                let transaction = DatabaseTransaction::from(crate::MockDatabaseTransaction::new(
+                    std::sync::Arc::clone(conn),
+                ));
+                transaction.run(_callback).await
+            },
+            #[cfg(not(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite")))]
+            _ => unimplemented!(),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::{pin::Pin, future::Future, sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+use crate::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError, TransactionTrait};
+
+/// A primary database together with its read replicas.
+///
+/// Statements starting with `SELECT` go to the replicas in turn, everything else to the
+/// primary, including inserts and updates with `RETURNING`, selects locking rows with
+/// `FOR UPDATE` or `FOR SHARE`, and all statements of a transaction. Inserts and updates
+/// read the rows they wrote back from the primary too. It implements [`ConnectionTrait`],
+/// so queries run on it unchanged.
+///
+/// A replica may lag behind the primary; read what was just written through
+/// [`primary`](Self::primary) instead. A select with side effects, e.g. calling a function
+/// that writes, has to be sent to the primary with [`Statement::on_primary`].
+#[derive(Debug)]
+pub struct ReplicatedConnection {
+    primary: DatabaseConnection,
+    replicas: Vec<DatabaseConnection>,
+    next_replica: AtomicUsize,
+}
+
+impl ReplicatedConnection {
+    /// Route reads to `replicas`, round-robin, and the rest to `primary`.
+    /// Without replicas, everything goes to the primary.
+    pub fn new(primary: DatabaseConnection, replicas: Vec<DatabaseConnection>) -> Self {
+        Self {
+            primary,
+            replicas,
+            next_replica: AtomicUsize::new(0),
+        }
+    }
+
+    /// The primary database, to read from it for read-your-writes consistency
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    /// The replica the next read goes to, or the primary if there are no replicas
+    pub fn replica(&self) -> &DatabaseConnection {
+        if self.replicas.is_empty() {
+            return &self.primary;
+        }
+        let idx = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        &self.replicas[idx]
+    }
+
+    /// Begin a transaction on the primary, see [`DatabaseConnection::begin`]
+    pub async fn begin(&self) -> Result<DatabaseTransaction<'static>, DbErr> {
+        self.primary.begin().await
+    }
+
+    fn route(&self, stmt: &Statement) -> &DatabaseConnection {
+        let sql = stmt.sql.trim_start();
+        let is_select = sql.len() >= 6 && sql[..6].eq_ignore_ascii_case("SELECT");
+        if is_select && !stmt.on_primary && !locks_rows(sql) {
+            self.replica()
+        } else {
+            &self.primary
+        }
+    }
+}
+
+/// Whether the select locks the rows it reads, which only the primary can do
+fn locks_rows(sql: &str) -> bool {
+    let sql = sql.to_ascii_uppercase();
+    ["FOR UPDATE", "FOR SHARE", "FOR NO KEY UPDATE", "FOR KEY SHARE", "LOCK IN SHARE MODE"]
+        .iter()
+        .any(|clause| sql.contains(clause))
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ReplicatedConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.primary.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.primary.execute(stmt).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt).query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt).query_all(stmt).await
+    }
+
+    async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        self.primary.execute_many(stmts).await
+    }
+
+    async fn execute_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<ExecResult, DbErr> {
+        self.primary.execute_with_timeout(stmt, timeout).await
+    }
+
+    async fn query_one_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt).query_one_with_timeout(stmt, timeout).await
+    }
+
+    async fn query_all_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt).query_all_with_timeout(stmt, timeout).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ReplicatedConnection {
+    /// Execute the function inside a transaction on the primary
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction<'_>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>> + Send + Sync,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.primary.transaction(callback).await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::*, query::*, tests_cfg::cake, DatabaseConnection, DbBackend, DbErr,
+        MockDatabase, MockExecResult, ReplicatedConnection, Statement, Transaction,
+        TransactionTrait,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn transaction_log(db: &DatabaseConnection) -> Vec<Transaction> {
+        db.as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .unwrap()
+            .drain_transaction_log()
+    }
+
+    fn select_cake(id: i32) -> Statement {
+        Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = $1 LIMIT $2"#,
+            vec![id.into(), 1u64.into()],
+        )
+    }
+
+    #[smol_potat::test]
+    async fn replicated_connection() -> Result<(), DbErr> {
+        let cake = |id: i32| cake::Model {
+            id,
+            name: "Chocolate Forest".to_owned(),
+        };
+        let replica = |ids: Vec<i32>| {
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results(ids.into_iter().map(|id| vec![cake(id)]).collect())
+                .into_connection()
+        };
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![vec![], vec![cake(4)], vec![cake(5)], vec![cake(5)]])
+            .into_connection();
+        let db = ReplicatedConnection::new(primary, vec![replica(vec![1, 3]), replica(vec![2])]);
+
+        // Reads go to the replicas in turn
+        for id in 1..=3 {
+            assert_eq!(cake::Entity::find_by_id(id).one(&db).await?, Some(cake(id)));
+        }
+
+        // Writes go to the primary, also when Postgres returns the inserted row
+        cake::Entity::delete_many().exec(&db).await?;
+        let inserted = cake::ActiveModel {
+            name: Set("Chocolate Forest".to_owned()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+        assert_eq!(inserted, cake(4));
+
+        // Forced primary reads, and transactions, go to the primary
+        assert_eq!(
+            cake::Entity::find_by_id(5).one(db.primary()).await?,
+            Some(cake(5))
+        );
+        db.transaction::<_, _, DbErr>(|txn| {
+            Box::pin(async move {
+                cake::Entity::find_by_id(5).one(txn).await?;
+                Ok(())
+            })
+        })
+        .await?;
+
+        assert_eq!(
+            transaction_log(&db.replicas[0]),
+            Transaction::wrap(vec![select_cake(1), select_cake(3)])
+        );
+        assert_eq!(
+            transaction_log(&db.replicas[1]),
+            Transaction::wrap(vec![select_cake(2)])
+        );
+        assert_eq!(
+            transaction_log(db.primary()),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake""#,
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                    vec!["Chocolate Forest".into()]
+                ),
+                Transaction::one(select_cake(5)),
+                Transaction::many(vec![
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    select_cake(5),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+                ]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn replicated_connection_reads_back_writes_on_primary() -> Result<(), DbErr> {
+        let cake = |id: i32, name: &str| cake::Model {
+            id,
+            name: name.to_owned(),
+        };
+        let exec_result = |last_insert_id, rows_affected| MockExecResult {
+            last_insert_id,
+            rows_affected,
+        };
+        // The mock counts executed and queried statements together
+        let primary = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![
+                exec_result(4, 1),
+                Default::default(),
+                exec_result(0, 1),
+                Default::default(),
+                exec_result(5, 2),
+            ])
+            .append_query_results(vec![
+                vec![],
+                vec![cake(4, "Apple Pie")],
+                vec![],
+                vec![cake(4, "Lemon Tart")],
+                vec![],
+                vec![cake(5, "Orange Scone"), cake(6, "Cheesecake")],
+                vec![cake(4, "Lemon Tart")],
+                vec![cake(4, "Lemon Tart")],
+            ])
+            .into_connection();
+        let replica = MockDatabase::new(DbBackend::MySql).into_connection();
+        let db = ReplicatedConnection::new(primary, vec![replica]);
+
+        // MySQL has no RETURNING, the written rows are selected from the primary
+        let inserted = cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+        assert_eq!(inserted, cake(4, "Apple Pie"));
+
+        let mut updated: cake::ActiveModel = inserted.into();
+        updated.name = Set("Lemon Tart".to_owned());
+        assert_eq!(updated.update(&db).await?, cake(4, "Lemon Tart"));
+
+        let inserted = cake::Entity::insert_many(["Orange Scone", "Cheesecake"].iter().map(|name| {
+            cake::ActiveModel {
+                name: Set((*name).to_owned()),
+                ..Default::default()
+            }
+        }))
+        .exec_with_returning_many(&db)
+        .await?;
+        assert_eq!(
+            inserted,
+            vec![cake(5, "Orange Scone"), cake(6, "Cheesecake")]
+        );
+
+        // Selects locking rows, or marked to run on the primary, go to the primary
+        assert_eq!(
+            cake::Entity::find_by_id(4).lock_exclusive().one(&db).await?,
+            Some(cake(4, "Lemon Tart"))
+        );
+        let stmt = Statement::from_string(
+            DbBackend::MySql,
+            "SELECT `id`, `name` FROM `cake` WHERE `id` = 4".to_owned(),
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .from_raw_sql(stmt.on_primary())
+                .one(&db)
+                .await?,
+            Some(cake(4, "Lemon Tart"))
+        );
+
+        assert_eq!(transaction_log(&db.replicas[0]), vec![]);
+        let log = transaction_log(db.primary());
+        assert_eq!(log.len(), 8);
+        assert_eq!(
+            log[6],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ? FOR UPDATE",
+                vec![4i32.into(), 1u64.into()]
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{DbBackend, DbErr, Statement};
+use std::fmt;
+
+/// The version of the database server, from [`ConnectionTrait::get_server_version`](crate::ConnectionTrait::get_server_version).
+///
+/// Versions compare by their numbers, e.g. to gate a feature on Postgres 12 or later:
+///
+/// ```
+/// use sea_orm::ServerVersion;
+///
+/// let version = ServerVersion::parse("PostgreSQL 14.5 on x86_64-pc-linux-gnu").unwrap();
+/// assert_eq!((version.major, version.minor, version.patch), (14, 5, 0));
+/// assert!(version.at_least(12, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// The version as reported by the server, e.g. `8.0.27` or `10.6.5-MariaDB-1:10.6.5`
+    pub version: String,
+}
+
+impl ServerVersion {
+    /// Parse the first `major[.minor[.patch]]` number out of a version string,
+    /// skipping a leading product name such as `PostgreSQL`
+    pub fn parse(version: &str) -> Result<Self, DbErr> {
+        let err = || DbErr::Query(format!("cannot parse server version '{}'", version));
+        let start = version.find(|c: char| c.is_ascii_digit()).ok_or_else(err)?;
+        let mut numbers = version[start..]
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|n| n.parse::<u32>());
+        let mut next = || numbers.next().transpose().map_err(|_| err());
+        Ok(Self {
+            major: next()?.ok_or_else(err)?,
+            minor: next()?.unwrap_or(0),
+            patch: next()?.unwrap_or(0),
+            version: version.to_owned(),
+        })
+    }
+
+    /// Whether this is version `major.minor` or later
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.version)
+    }
+}
+
+pub(crate) fn server_version_stmt(backend: DbBackend) -> Statement {
+    let sql = match backend {
+        DbBackend::MySql | DbBackend::Postgres => "SELECT version() AS version",
+        DbBackend::Sqlite => "SELECT sqlite_version() AS version",
+    };
+    Statement::from_string(backend, sql.to_owned())
+}
+
+pub(crate) fn database_name_stmt(backend: DbBackend) -> Statement {
+    let sql = match backend {
+        DbBackend::MySql => "SELECT DATABASE() AS name",
+        DbBackend::Postgres => "SELECT current_database() AS name",
+        DbBackend::Sqlite => "SELECT name FROM pragma_database_list WHERE seq = 0",
+    };
+    Statement::from_string(backend, sql.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DbErr, ServerVersion};
+
+    #[test]
+    fn parse_server_version() {
+        let parse = |version| {
+            let v = ServerVersion::parse(version).unwrap();
+            (v.major, v.minor, v.patch)
+        };
+
+        assert_eq!(
+            parse("PostgreSQL 13.4 (Debian 13.4-1.pgdg100+1) on x86_64-pc-linux-gnu"),
+            (13, 4, 0)
+        );
+        assert_eq!(parse("8.0.27"), (8, 0, 27));
+        assert_eq!(parse("10.6.5-MariaDB-1:10.6.5+maria~focal"), (10, 6, 5));
+        assert_eq!(parse("3.38.2"), (3, 38, 2));
+        assert_eq!(parse("15beta1"), (15, 0, 0));
+
+        assert!(ServerVersion::parse("13.4").unwrap() > ServerVersion::parse("9.6.24").unwrap());
+        assert!(!ServerVersion::parse("11.14").unwrap().at_least(12, 0));
+        assert_eq!(
+            ServerVersion::parse("unknown"),
+            Err(DbErr::Query(
+                "cannot parse server version 'unknown'".to_owned()
+            ))
+        );
+    }
+}
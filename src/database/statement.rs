@@ -3,26 +3,40 @@ use sea_query::{inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, Sqli
 pub use sea_query::{Value, Values};
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Clone)]
 pub struct Statement {
     pub sql: String,
     pub values: Option<Values>,
     pub db_backend: DbBackend,
+    pub(crate) sensitive: Vec<Value>,
+    pub(crate) on_primary: bool,
 }
 
+/// Raw SQL without bound values for the given backend, the same as [`Statement::from_string`]
+impl From<(&str, DbBackend)> for Statement {
+    fn from((sql, db_backend): (&str, DbBackend)) -> Statement {
+        Statement::from_string(db_backend, sql.to_owned())
+    }
+}
+
+/// Build a sea-query statement into a [`Statement`] for a given backend
 pub trait StatementBuilder {
     fn build(&self, db_backend: &DbBackend) -> Statement;
 }
 
 impl Statement {
+    /// Create a statement from raw SQL without any bound values
     pub fn from_string(db_backend: DbBackend, stmt: String) -> Statement {
         Statement {
             sql: stmt,
             values: None,
             db_backend,
+            sensitive: Vec::new(),
+            on_primary: false,
         }
     }
 
+    /// Create a statement from SQL with placeholders and the values bound to them
     pub fn from_sql_and_values<I>(db_backend: DbBackend, sql: &str, values: I) -> Self
     where
         I: IntoIterator<Item = Value>,
@@ -41,17 +55,78 @@ impl Statement {
             sql: stmt.0,
             values: Some(stmt.1),
             db_backend,
+            sensitive: Vec::new(),
+            on_primary: false,
         }
     }
+
+    /// Redact the bound values equal to any of `values` as `****` when the statement
+    /// is displayed or logged. The values are still bound when it is executed.
+    ///
+    /// The values of columns defined as `sensitive` are redacted this way when an
+    /// ActiveModel is inserted or updated.
+    pub fn with_sensitive_values<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        self.sensitive.extend(values);
+        self
+    }
+
+    /// Run the statement on the primary of a [`ReplicatedConnection`](crate::ReplicatedConnection)
+    /// even if it only reads, e.g. a `SELECT` calling a function that writes.
+    ///
+    /// The rows an insert or update just wrote are read back this way.
+    pub fn on_primary(mut self) -> Self {
+        self.on_primary = true;
+        self
+    }
+
+    fn redacted_values(&self) -> Option<Values> {
+        self.values.as_ref().map(|values| {
+            Values(
+                values
+                    .0
+                    .iter()
+                    .map(|value| {
+                        if self.sensitive.contains(value) {
+                            Value::String(Some(Box::new("****".to_owned())))
+                        } else {
+                            value.clone()
+                        }
+                    })
+                    .collect(),
+            )
+        })
+    }
 }
 
+/// Statements are equal if they execute the same, regardless of the values they redact
+impl PartialEq for Statement {
+    fn eq(&self, other: &Self) -> bool {
+        self.sql == other.sql && self.values == other.values && self.db_backend == other.db_backend
+    }
+}
+
+impl fmt::Debug for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Statement")
+            .field("sql", &self.sql)
+            .field("values", &self.redacted_values())
+            .field("db_backend", &self.db_backend)
+            .finish()
+    }
+}
+
+/// Displays the SQL with the bound values inlined and quoted, for logging and debugging.
+/// The statement is always executed with the values bound as parameters.
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match &self.values {
+        match self.redacted_values() {
             Some(values) => {
                 let string = inject_parameters(
                     &self.sql,
-                    values.0.clone(),
+                    values.0,
                     self.db_backend.get_query_builder().as_ref(),
                 );
                 write!(f, "{}", &string)
@@ -105,3 +180,186 @@ build_schema_stmt!(sea_query::TableDropStatement);
 build_schema_stmt!(sea_query::TableAlterStatement);
 build_schema_stmt!(sea_query::TableRenameStatement);
 build_schema_stmt!(sea_query::TableTruncateStatement);
+build_schema_stmt!(sea_query::IndexCreateStatement);
+build_schema_stmt!(sea_query::IndexDropStatement);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests_cfg::*, ColumnTrait, DbBackend, DbErr, EntityTrait, MockDatabase, QueryFilter,
+        QueryTrait, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, Query};
+
+    #[test]
+    fn statement_from_str() {
+        let stmt: Statement = ("DROP TABLE cake", DbBackend::MySql).into();
+        assert_eq!(stmt.sql, "DROP TABLE cake");
+        assert_eq!(stmt.values, None);
+        assert_eq!(stmt.db_backend, DbBackend::MySql);
+    }
+
+    #[test]
+    fn statement_from_sql_and_values() {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."name" FROM "cake" WHERE "id" = $1 AND "name" = $2"#,
+            vec![1.into(), "Cheese".into()],
+        );
+
+        assert_eq!(
+            stmt.to_string(),
+            r#"SELECT "cake"."name" FROM "cake" WHERE "id" = 1 AND "name" = 'Cheese'"#
+        );
+    }
+
+    #[test]
+    fn statement_redacts_sensitive_values() {
+        let stmt = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"UPDATE "user" SET "password" = $1 WHERE "name" = $2"#,
+            vec!["s3cr3t".into(), "Alice".into()],
+        )
+        .with_sensitive_values(vec!["s3cr3t".into()]);
+
+        assert_eq!(
+            stmt.to_string(),
+            r#"UPDATE "user" SET "password" = '****' WHERE "name" = 'Alice'"#
+        );
+        assert!(!format!("{:?}", stmt).contains("s3cr3t"));
+        // Still executed with the value bound
+        assert_eq!(
+            stmt.values,
+            Some(sea_query::Values(vec!["s3cr3t".into(), "Alice".into()]))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn sensitive_column_redacted() {
+        use crate::{ActiveValue, Insert, Update};
+
+        mod user {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "user")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                #[sea_orm(sensitive)]
+                pub password_hash: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let model = user::ActiveModel {
+            id: ActiveValue::set(1),
+            name: ActiveValue::set("Alice".to_owned()),
+            password_hash: ActiveValue::set("$argon2id$hash".to_owned()),
+        };
+
+        assert_eq!(
+            Insert::one(model.clone())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "user" ("id", "name", "password_hash") VALUES (1, 'Alice', '****')"#
+        );
+        assert_eq!(
+            Update::one(model).build(DbBackend::Postgres).to_string(),
+            r#"UPDATE "user" SET "name" = 'Alice', "password_hash" = '****' WHERE "user"."id" = 1"#
+        );
+    }
+
+    #[test]
+    fn statement_display_matches_built_query() {
+        let query = Query::select()
+            .column(cake::Column::Name)
+            .from(cake::Entity)
+            .and_where(Expr::col(cake::Column::Name).eq("Baker's Dozen"))
+            .to_owned();
+
+        assert_eq!(
+            DbBackend::MySql.build(&query).to_string(),
+            r#"SELECT `name` FROM `cake` WHERE `name` = 'Baker\'s Dozen'"#
+        );
+        assert_eq!(
+            DbBackend::Postgres.build(&query).to_string(),
+            r#"SELECT "name" FROM "cake" WHERE "name" = E'Baker\'s Dozen'"#
+        );
+        assert_eq!(
+            DbBackend::Sqlite.build(&query).to_string(),
+            r#"SELECT `name` FROM `cake` WHERE `name` = 'Baker\'s Dozen'"#
+        );
+    }
+
+    #[test]
+    fn statement_placeholders_per_backend() {
+        let query = Query::select()
+            .column(cake::Column::Name)
+            .from(cake::Entity)
+            .and_where(Expr::col(cake::Column::Id).eq(1))
+            .and_where(Expr::col(cake::Column::Name).eq("Cheese"))
+            .to_owned();
+
+        for (backend, sql) in [
+            (
+                DbBackend::Postgres,
+                r#"SELECT "name" FROM "cake" WHERE "id" = $1 AND "name" = $2"#,
+            ),
+            (
+                DbBackend::MySql,
+                r#"SELECT `name` FROM `cake` WHERE `id` = ? AND `name` = ?"#,
+            ),
+            (
+                DbBackend::Sqlite,
+                r#"SELECT `name` FROM `cake` WHERE `id` = ? AND `name` = ?"#,
+            ),
+        ] {
+            let stmt = backend.build(&query);
+            assert_eq!(stmt.db_backend, backend);
+            assert_eq!(stmt.sql, sql);
+            assert_eq!(
+                stmt.values,
+                Some(sea_query::Values(vec![1.into(), "Cheese".into()]))
+            );
+            assert_eq!(
+                query.build_any(backend.get_query_builder().as_ref()).0,
+                stmt.sql
+            );
+        }
+    }
+
+    #[smol_potat::test]
+    async fn statement_display_matches_executed() -> Result<(), DbErr> {
+        let select = cake::Entity::find().filter(cake::Column::Name.contains("cheese"));
+        let stmt = select.build(DbBackend::Postgres);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+        select.all(&db).await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::one(stmt.clone())]
+        );
+        assert_eq!(
+            stmt.to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE "cake"."name" LIKE '%cheese%'"#,
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::{
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use futures::channel::oneshot;
+
+/// When to fire and whom to wake
+type Timer = (Instant, oneshot::Sender<()>);
+
+/// The channel to the thread that fires every timer, started by the first one
+static TIMER_THREAD: Mutex<Option<Sender<Timer>>> = Mutex::new(None);
+
+/// Wait on the timer thread shared by all timers, for when no async runtime is enabled
+pub(crate) async fn sleep(duration: Duration) {
+    let (tx, rx) = oneshot::channel();
+    let deadline = Instant::now() + duration;
+    {
+        let mut timer_thread = TIMER_THREAD.lock().unwrap_or_else(|e| e.into_inner());
+        let sender = timer_thread.get_or_insert_with(spawn_timer_thread);
+        if let Err(mpsc::SendError(timer)) = sender.send((deadline, tx)) {
+            // The thread is gone, start another one
+            let sender = timer_thread.insert(spawn_timer_thread());
+            let _ = sender.send(timer);
+        }
+    }
+    let _ = rx.await;
+}
+
+fn spawn_timer_thread() -> Sender<Timer> {
+    let (sender, receiver) = mpsc::channel::<Timer>();
+    thread::spawn(move || {
+        let mut timers: Vec<Timer> = Vec::new();
+        loop {
+            let now = Instant::now();
+            let (expired, pending) = timers
+                .into_iter()
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            for (_, tx) in expired {
+                let _ = tx.send(());
+            }
+            // Forget the timers of futures that completed before them
+            timers = pending;
+            timers.retain(|(_, tx)| !tx.is_canceled());
+            let next = match timers.iter().map(|(deadline, _)| *deadline).min() {
+                Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(now)),
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+            match next {
+                Ok(timer) => timers.push(timer),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    });
+    sender
+}
@@ -5,7 +5,7 @@ use crate::{
 use std::fmt::Debug;
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
-    Mutex,
+    Arc, Mutex,
 };
 
 #[derive(Debug)]
@@ -17,11 +17,29 @@ pub struct MockDatabaseConnection {
     mocker: Mutex<Box<dyn MockDatabaseTrait>>,
 }
 
+/// A transaction open on a mock connection. The statements run in it are logged
+/// together as one [`Transaction`]; dropping it without a commit rolls it back.
+#[derive(Debug)]
+pub struct MockDatabaseTransaction {
+    conn: Arc<MockDatabaseConnection>,
+    open: bool,
+}
+
 pub trait MockDatabaseTrait: Send + Debug {
     fn execute(&mut self, counter: usize, stmt: Statement) -> Result<ExecResult, DbErr>;
 
     fn query(&mut self, counter: usize, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
+    /// Open a transaction, or a savepoint if one is already open.
+    /// Does nothing by default.
+    fn begin(&mut self) {}
+
+    /// Commit the innermost open transaction or savepoint. Does nothing by default.
+    fn commit(&mut self) {}
+
+    /// Roll back the innermost open transaction or savepoint. Does nothing by default.
+    fn rollback(&mut self) {}
+
     fn drain_transaction_log(&mut self) -> Vec<Transaction>;
 
     fn get_database_backend(&self) -> DbBackend;
@@ -49,9 +67,9 @@ impl MockDatabaseConnector {
     pub async fn connect(string: &str) -> Result<DatabaseConnection, DbErr> {
         macro_rules! connect_mock_db {
             ( $syntax: expr ) => {
-                Ok(DatabaseConnection::MockDatabaseConnection(
+                Ok(DatabaseConnection::MockDatabaseConnection(Arc::new(
                     MockDatabaseConnection::new(MockDatabase::new($syntax)),
-                ))
+                )))
             };
         }
 
@@ -72,9 +90,9 @@ impl MockDatabaseConnector {
 }
 
 impl MockDatabaseConnection {
-    pub fn new<M: 'static>(m: M) -> Self
+    pub fn new<M>(m: M) -> Self
     where
-        M: MockDatabaseTrait,
+        M: MockDatabaseTrait + 'static,
     {
         Self {
             counter: AtomicUsize::new(0),
@@ -108,4 +126,45 @@ impl MockDatabaseConnection {
     pub fn get_database_backend(&self) -> DbBackend {
         self.mocker.lock().unwrap().get_database_backend()
     }
+
+    pub fn begin(&self) {
+        self.mocker.lock().unwrap().begin()
+    }
+
+    pub fn commit(&self) {
+        self.mocker.lock().unwrap().commit()
+    }
+
+    pub fn rollback(&self) {
+        self.mocker.lock().unwrap().rollback()
+    }
+}
+
+impl MockDatabaseTransaction {
+    pub fn new(conn: Arc<MockDatabaseConnection>) -> Self {
+        conn.begin();
+        Self { conn, open: true }
+    }
+
+    pub fn get_connection(&self) -> &Arc<MockDatabaseConnection> {
+        &self.conn
+    }
+
+    pub fn commit(mut self) {
+        self.open = false;
+        self.conn.commit();
+    }
+
+    pub fn rollback(mut self) {
+        self.open = false;
+        self.conn.rollback();
+    }
+}
+
+impl Drop for MockDatabaseTransaction {
+    fn drop(&mut self) {
+        if self.open {
+            self.conn.rollback();
+        }
+    }
 }
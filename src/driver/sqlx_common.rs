@@ -1,9 +1,276 @@
-use crate::DbErr;
+use crate::{AcquireObserver, AfterConnect, ConnAcquireErr, DatabaseErr, DbErr, NewConnection, SqlErr, Statement, TryGetError};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 pub fn sqlx_error_to_exec_err(err: sqlx::Error) -> DbErr {
-    DbErr::Exec(err.to_string())
+    match err {
+        sqlx::Error::Database(_) => sqlx_database_err(err),
+        _ => DbErr::Exec(err.to_string()),
+    }
 }
 
+/// Convert an error from running a query. `RowNotFound` becomes [`DbErr::RecordNotFound`];
+/// `query_one` handles it before calling this and returns `Ok(None)` instead.
 pub fn sqlx_error_to_query_err(err: sqlx::Error) -> DbErr {
-    DbErr::Query(err.to_string())
+    match err {
+        sqlx::Error::Database(_) => sqlx_database_err(err),
+        sqlx::Error::RowNotFound => DbErr::RecordNotFound(err.to_string()),
+        _ => DbErr::Query(err.to_string()),
+    }
+}
+
+/// Convert an error from reading a column of a row. A value that cannot be decoded
+/// into the requested type becomes [`DbErr::Type`], naming the column and both types.
+pub fn sqlx_error_to_try_get_err(err: sqlx::Error) -> TryGetError {
+    match err {
+        sqlx::Error::ColumnDecode { .. } | sqlx::Error::Decode(_) => {
+            TryGetError::DbErr(DbErr::Type(err.to_string()))
+        }
+        _ => TryGetError::DbErr(sqlx_error_to_query_err(err)),
+    }
+}
+
+/// Join statements into one batch if none of them has values to bind, since the
+/// simple query protocol that runs a batch cannot carry parameters. Values are never
+/// written into the SQL, their escaping depends on the server configuration.
+pub(crate) fn sqlx_batch_sql(stmts: &[Statement]) -> Option<String> {
+    if stmts.len() < 2
+        || stmts
+            .iter()
+            .any(|stmt| matches!(&stmt.values, Some(values) if !values.0.is_empty()))
+    {
+        return None;
+    }
+    Some(
+        stmts
+            .iter()
+            .map(|stmt| stmt.sql.trim_end().trim_end_matches(';'))
+            .collect::<Vec<_>>()
+            .join("; "),
+    )
+}
+
+/// Backends without unsigned types store a `u64` as `i64`; refuse a value that
+/// does not fit instead of letting it wrap around to a negative number
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+pub(crate) fn sqlx_unsigned_to_i64(value: Option<u64>) -> Result<Option<i64>, DbErr> {
+    use std::convert::TryFrom;
+
+    value
+        .map(|v| {
+            i64::try_from(v).map_err(|_| {
+                DbErr::Type(format!(
+                    "{} does not fit in a signed 64-bit integer column",
+                    v
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Count every connection the pool opens, including the ones replacing connections
+/// it closed for being idle or too old, and run the
+/// [`ConnectOptions::after_connect`](crate::ConnectOptions::after_connect) hook on it, if any
+#[cfg(any(feature = "sqlx-mysql", feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+pub(crate) fn sqlx_after_connect<DB>(
+    opt: sqlx::pool::PoolOptions<DB>,
+    counter: &Arc<AtomicU64>,
+    hook: Option<AfterConnect>,
+) -> sqlx::pool::PoolOptions<DB>
+where
+    DB: sqlx::Database,
+    for<'c> &'c mut DB::Connection: Into<NewConnection<'c>>,
+{
+    let counter = Arc::clone(counter);
+    opt.after_connect(move |conn| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        let hook = hook.clone();
+        Box::pin(async move {
+            if let Some(hook) = hook {
+                hook.run(conn.into())
+                    .await
+                    .map_err(|err| sqlx::Error::Protocol(err.to_string()))?;
+            }
+            Ok(())
+        })
+    })
+}
+
+/// Wait for `acquire` to take a connection out of the pool, reporting how long it took
+/// to the [`ConnectOptions::acquire_observer`](crate::ConnectOptions::acquire_observer), if any
+pub(crate) async fn sqlx_observe_acquire<T, F>(
+    observer: Option<&AcquireObserver>,
+    acquire: F,
+) -> Result<T, DbErr>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    let observer = match observer {
+        Some(observer) => observer,
+        None => return acquire.await.map_err(sqlx_conn_acquire_err),
+    };
+    let start = Instant::now();
+    let res = acquire.await.map_err(sqlx_conn_acquire_err);
+    observer.observe(start.elapsed(), res.as_ref().map(|_| ()));
+    res
+}
+
+pub fn sqlx_conn_acquire_err(err: sqlx::Error) -> DbErr {
+    match err {
+        sqlx::Error::PoolTimedOut => DbErr::ConnectionAcquire(ConnAcquireErr::Timeout),
+        sqlx::Error::PoolClosed => DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed),
+        _ => DbErr::Conn(err.to_string()),
+    }
+}
+
+fn sqlx_database_err(err: sqlx::Error) -> DbErr {
+    let (message, code, constraint, sql_err) = match &err {
+        sqlx::Error::Database(e) => (
+            e.message().to_owned(),
+            e.code().map(|code| code.into_owned()),
+            sqlx_constraint(e.as_ref()),
+            sqlx_sql_err(e.as_ref()),
+        ),
+        _ => (err.to_string(), None, None, None),
+    };
+    DbErr::Database(DatabaseErr {
+        message,
+        code,
+        constraint,
+        sql_err,
+        source: Some(Arc::new(err)),
+    })
+}
+
+fn sqlx_constraint(err: &dyn sqlx::error::DatabaseError) -> Option<String> {
+    if let Some(constraint) = err.constraint() {
+        return Some(constraint.to_owned());
+    }
+    // MySQL only names the constraint in the message of a foreign key violation:
+    // "... a foreign key constraint fails (`db`.`lineitem`, CONSTRAINT `fk-lineitem-cake` FOREIGN KEY ..."
+    #[cfg(feature = "sqlx-mysql")]
+    if err.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>().is_some() {
+        return mysql_constraint(err.message());
+    }
+    None
+}
+
+#[allow(dead_code)]
+fn mysql_constraint(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once("CONSTRAINT `")?;
+    let (constraint, _) = rest.split_once('`')?;
+    Some(constraint.to_owned())
+}
+
+#[allow(unused_variables)]
+fn sqlx_sql_err(err: &dyn sqlx::error::DatabaseError) -> Option<SqlErr> {
+    // https://dev.mysql.com/doc/mysql-errors/8.0/en/server-error-reference.html
+    #[cfg(feature = "sqlx-mysql")]
+    if let Some(e) = err.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+        return match e.number() {
+            1062 | 1586 => Some(SqlErr::UniqueConstraintViolation),
+            1216 | 1217 | 1451 | 1452 => Some(SqlErr::ForeignKeyConstraintViolation),
+            1048 => Some(SqlErr::NotNullViolation),
+            3819 => Some(SqlErr::CheckViolation),
+            _ => None,
+        };
+    }
+    // https://www.postgresql.org/docs/current/errcodes-appendix.html
+    #[cfg(feature = "sqlx-postgres")]
+    if let Some(e) = err.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+        return match e.code() {
+            "23505" => Some(SqlErr::UniqueConstraintViolation),
+            "23503" => Some(SqlErr::ForeignKeyConstraintViolation),
+            "23502" => Some(SqlErr::NotNullViolation),
+            "23514" => Some(SqlErr::CheckViolation),
+            _ => None,
+        };
+    }
+    // https://www.sqlite.org/rescode.html
+    #[cfg(feature = "sqlx-sqlite")]
+    if err.try_downcast_ref::<sqlx::sqlite::SqliteError>().is_some() {
+        return match err.code().as_deref() {
+            Some("1555") | Some("2067") => Some(SqlErr::UniqueConstraintViolation),
+            Some("787") => Some(SqlErr::ForeignKeyConstraintViolation),
+            Some("1299") => Some(SqlErr::NotNullViolation),
+            Some("275") => Some(SqlErr::CheckViolation),
+            _ => None,
+        };
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mysql_constraint, sqlx_batch_sql};
+    use crate::{entity::*, tests_cfg::*, DbBackend, QueryTrait, Statement};
+
+    fn parent_and_children(db_backend: DbBackend, child: &str) -> Vec<Statement> {
+        vec![
+            cake::Entity::insert(cake::ActiveModel {
+                id: Set(1),
+                name: Set("Apple Pie".to_owned()),
+            })
+            .build(db_backend),
+            fruit::Entity::insert_many(vec![
+                fruit::ActiveModel {
+                    name: Set("Apple".to_owned()),
+                    cake_id: Set(Some(1)),
+                    ..Default::default()
+                },
+                fruit::ActiveModel {
+                    name: Set(child.to_owned()),
+                    cake_id: Set(None),
+                    ..Default::default()
+                },
+            ])
+            .build(db_backend),
+        ]
+    }
+
+    #[test]
+    fn batch_sql_without_values() {
+        let create = |table: &str| {
+            Statement::from_string(
+                DbBackend::MySql,
+                format!("CREATE TABLE `{}` (`id` int NOT NULL);", table),
+            )
+        };
+        assert_eq!(
+            sqlx_batch_sql(&[create("cake"), create("fruit")]),
+            Some("CREATE TABLE `cake` (`id` int NOT NULL); CREATE TABLE `fruit` (`id` int NOT NULL)".to_owned())
+        );
+        assert_eq!(sqlx_batch_sql(&[create("cake")]), None);
+    }
+
+    #[test]
+    fn batch_sql_with_values_runs_sequentially() {
+        for db_backend in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            for name in ["Pear", "Pear's", "Pear\\", "Pear\x1a", "Pear\0"] {
+                assert_eq!(sqlx_batch_sql(&parent_and_children(db_backend, name)), None);
+            }
+        }
+    }
+
+    #[test]
+    fn mysql_constraint_from_message() {
+        assert_eq!(
+            mysql_constraint(
+                "Cannot add or update a child row: a foreign key constraint fails \
+                (`bakery`.`lineitem`, CONSTRAINT `fk-lineitem-cake` FOREIGN KEY (`cake_id`) \
+                REFERENCES `cake` (`id`) ON DELETE CASCADE ON UPDATE CASCADE)"
+            ),
+            Some("fk-lineitem-cake".to_owned())
+        );
+        assert_eq!(
+            mysql_constraint("Duplicate entry '1' for key 'PRIMARY'"),
+            None
+        );
+    }
 }
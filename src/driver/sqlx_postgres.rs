@@ -1,114 +1,275 @@
-use std::{pin::Pin, future::Future};
+use std::{pin::Pin, future::Future, sync::{Arc, atomic::{AtomicU64, Ordering}}, time::Duration};
 
-use sqlx::{Connection, PgPool, Postgres, postgres::{PgArguments, PgQueryResult, PgRow}};
+use sqlx::{Connection, PgConnection, PgPool, Postgres, postgres::{PgArguments, PgConnectOptions, PgQueryResult, PgRow}};
 
-sea_query::sea_query_driver_postgres!();
-use sea_query_driver_postgres::bind_query;
+use sea_query::{Value, Values};
 
-use crate::{DatabaseConnection, DatabaseTransaction, Statement, TransactionError, debug_print, error::*, executor::*};
+use crate::{AcquireObserver, copy_in_sql, ConnectOptions, CopyFormat, DatabaseConnection, DatabaseTransaction, DbBackend, debug_print_statement, PooledConnection, Statement, TransactionError, debug_print, error::*, executor::*, with_timeout};
 
 use super::sqlx_common::*;
 
+/// The size of the chunks of `COPY` data sent to the server
+const COPY_IN_CHUNK_SIZE: usize = 64 * 1024;
+
 #[derive(Debug)]
 pub struct SqlxPostgresConnector;
 
 #[derive(Debug, Clone)]
 pub struct SqlxPostgresPoolConnection {
     pool: PgPool,
+    connections_opened: Option<Arc<AtomicU64>>,
+    acquire_observer: Option<AcquireObserver>,
+    /// Used to open the connection cancelling a timed out statement, outside of the pool
+    connect_options: Option<Arc<PgConnectOptions>>,
 }
 
 impl SqlxPostgresConnector {
     pub fn accepts(string: &str) -> bool {
-        string.starts_with("postgres://")
+        DbBackend::from_connection_string(string) == Ok(DbBackend::Postgres)
     }
 
-    pub async fn connect(string: &str) -> Result<DatabaseConnection, DbErr> {
-        if let Ok(pool) = PgPool::connect(string).await {
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let opt = Self::connect_options(&options)?;
+        let connections_opened = Arc::new(AtomicU64::new(0));
+        let acquire_observer = options.acquire_observer.clone();
+        let after_connect = options.after_connect.clone();
+        let pool_options = sqlx_after_connect(options.pool_options::<Postgres>(), &connections_opened, after_connect);
+        if let Ok(pool) = pool_options.connect_with(opt.clone()).await {
             Ok(DatabaseConnection::SqlxPostgresPoolConnection(
-                SqlxPostgresPoolConnection { pool },
+                SqlxPostgresPoolConnection {
+                    pool,
+                    connections_opened: Some(connections_opened),
+                    acquire_observer,
+                    connect_options: Some(Arc::new(opt)),
+                },
             ))
         } else {
             Err(DbErr::Conn("Failed to connect.".to_owned()))
         }
     }
+
+    pub(crate) fn connect_options(options: &ConnectOptions) -> Result<PgConnectOptions, DbErr> {
+        let mut opt: PgConnectOptions = options
+            .url
+            .parse()
+            .map_err(|e: sqlx::Error| DbErr::Conn(e.to_string()))?;
+        if let Some(capacity) = options.statement_cache_capacity {
+            opt = opt.statement_cache_capacity(capacity);
+        }
+        Ok(opt)
+    }
 }
 
 impl SqlxPostgresConnector {
     pub fn from_sqlx_postgres_pool(pool: PgPool) -> DatabaseConnection {
-        DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection { pool })
+        DatabaseConnection::SqlxPostgresPoolConnection(SqlxPostgresPoolConnection {
+            pool,
+            connections_opened: None,
+            acquire_observer: None,
+            connect_options: None,
+        })
     }
 }
 
 impl SqlxPostgresPoolConnection {
+    /// Get the underlying sqlx pool. This is an escape hatch for features
+    /// SeaORM does not wrap; queries run through it bypass SeaORM entirely.
+    pub fn get_postgres_connection_pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// The number of connections the pool has opened so far, see
+    /// [`DatabaseConnection::connections_opened`]
+    pub fn connections_opened(&self) -> Option<u64> {
+        self.connections_opened
+            .as_ref()
+            .map(|counter| counter.load(Ordering::Relaxed))
+    }
+
+    /// The number of open connections waiting in the pool, see
+    /// [`DatabaseConnection::idle_connections`]
+    pub fn idle_connections(&self) -> usize {
+        self.pool.num_idle()
+    }
+
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.execute(conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
-        } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.execute(conn).await {
+            Ok(res) => Ok(res.into()),
+            Err(err) => Err(sqlx_error_to_exec_err(err)),
         }
     }
 
+    /// Execute the statements in order on one pooled connection, see [`sqlx_execute_many`]
+    pub async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let conn = &mut self.acquire_conn().await?;
+        sqlx_execute_many(conn, stmts, None).await
+    }
+
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.fetch_one(conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(DbErr::Query(err.to_string())),
-                },
-            }
-        } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.fetch_one(conn).await {
+            Ok(row) => Ok(Some(row.into())),
+            Err(sqlx::Error::RowNotFound) => Ok(None),
+            Err(err) => Err(sqlx_error_to_query_err(err)),
         }
     }
 
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.fetch_all(conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.fetch_all(conn).await {
+            Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+            Err(err) => Err(sqlx_error_to_query_err(err)),
+        }
+    }
+
+    /// Execute the statement, cancelling it on the server if it has not completed within `timeout`
+    pub async fn execute_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", stmt);
+
+        self.run_with_timeout(stmt, timeout, |conn, stmt| Box::pin(async move {
+            let query = sqlx_query(stmt)?;
+            query.execute(conn).await
+                .map(Into::into)
+                .map_err(sqlx_error_to_exec_err)
+        })).await
+    }
+
+    /// Fetch the first row, cancelling the query on the server if it has not completed within `timeout`
+    pub async fn query_one_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Option<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        self.run_with_timeout(stmt, timeout, |conn, stmt| Box::pin(async move {
+            let query = sqlx_query(stmt)?;
+            match query.fetch_one(conn).await {
+                Ok(row) => Ok(Some(row.into())),
+                Err(sqlx::Error::RowNotFound) => Ok(None),
                 Err(err) => Err(sqlx_error_to_query_err(err)),
             }
-        } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        })).await
+    }
+
+    /// Fetch all rows, cancelling the query on the server if it has not completed within `timeout`
+    pub async fn query_all_with_timeout(&self, stmt: Statement, timeout: Duration) -> Result<Vec<QueryResult>, DbErr> {
+        debug_print!("{}", stmt);
+
+        self.run_with_timeout(stmt, timeout, |conn, stmt| Box::pin(async move {
+            let query = sqlx_query(stmt)?;
+            query.fetch_all(conn).await
+                .map(|rows| rows.into_iter().map(|r| r.into()).collect())
+                .map_err(sqlx_error_to_query_err)
+        })).await
+    }
+
+    /// Run on a pooled connection whose backend pid is looked up first, so that on
+    /// timeout the statement can be cancelled with `pg_cancel_backend`. The connection
+    /// is in an unknown state after a timeout and is closed instead of returned to the pool.
+    async fn run_with_timeout<T, F>(&self, stmt: Statement, timeout: Duration, run: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c mut PgConnection, &'c Statement) -> Pin<Box<dyn Future<Output = Result<T, DbErr>> + Send + 'c>>,
+    {
+        let mut conn = self.acquire_conn().await?;
+        let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await
+            .map_err(sqlx_error_to_query_err)?;
+        match with_timeout(timeout, run(&mut conn, &stmt)).await {
+            Err(DbErr::Timeout(msg)) => {
+                let conn = conn.detach();
+                let _ = with_timeout(timeout, self.cancel_backend(pid)).await;
+                drop(conn);
+                Err(DbErr::Timeout(msg))
+            }
+            res => res,
+        }
+    }
+
+    /// Cancel the statement running on the backend `pid` over a connection of its own,
+    /// which does not wait for the pool to free one. A pool handed over with
+    /// [`SqlxPostgresConnector::from_sqlx_postgres_pool`] has no options to open it with,
+    /// the cancel then uses an idle connection of the pool, if there is one.
+    async fn cancel_backend(&self, pid: i32) -> Result<(), DbErr> {
+        let cancel = sqlx::query("SELECT pg_cancel_backend($1)").bind(pid);
+        match &self.connect_options {
+            Some(opt) => {
+                let mut conn = PgConnection::connect_with(opt.as_ref()).await.map_err(sqlx_conn_acquire_err)?;
+                cancel.execute(&mut conn).await.map_err(sqlx_error_to_exec_err)?;
+                conn.close().await.map_err(sqlx_error_to_exec_err)
+            }
+            None => match self.pool.try_acquire() {
+                Some(mut conn) => cancel.execute(&mut conn).await.map(|_| ()).map_err(sqlx_error_to_exec_err),
+                None => Ok(()),
+            },
         }
     }
 
+    /// Stream `source` into `table` with `COPY .. FROM STDIN`, see [`DatabaseConnection::copy_in`]
+    pub async fn copy_in<R>(&self, table: &str, columns: &[&str], format: CopyFormat, mut source: R) -> Result<u64, DbErr>
+    where
+        R: futures::AsyncRead + Unpin,
+    {
+        use futures::AsyncReadExt;
+
+        let sql = copy_in_sql(table, columns, format);
+        debug_print!("{}", sql);
+
+        let mut copy = self.pool.copy_in_raw(&sql).await.map_err(sqlx_error_to_exec_err)?;
+        let mut buf = vec![0; COPY_IN_CHUNK_SIZE];
+        loop {
+            let read = match source.read(&mut buf).await {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(err) => {
+                    let msg = format!("Failed to read the rows to copy: {}", err);
+                    let _ = copy.abort(msg.clone()).await;
+                    return Err(DbErr::Exec(msg));
+                }
+            };
+            copy.send(&buf[..read]).await.map_err(sqlx_error_to_exec_err)?;
+        }
+        copy.finish().await.map_err(sqlx_error_to_exec_err)
+    }
+
+    async fn acquire_conn(&self) -> Result<sqlx::pool::PoolConnection<Postgres>, DbErr> {
+        sqlx_observe_acquire(self.acquire_observer.as_ref(), self.pool.acquire()).await
+    }
+
+    pub async fn acquire(&self) -> Result<PooledConnection, DbErr> {
+        self.acquire_conn().await.map(PooledConnection::from)
+    }
+
+    pub async fn begin(&self) -> Result<DatabaseTransaction<'static>, DbErr> {
+        sqlx_observe_acquire(self.acquire_observer.as_ref(), self.pool.begin())
+            .await
+            .map(DatabaseTransaction::from)
+    }
+
     pub async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
     where
         F: for<'b> FnOnce(&'b DatabaseTransaction<'_>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>> + Send + Sync,
         T: Send,
         E: std::error::Error + Send,
     {
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            let transaction = DatabaseTransaction::from(
-                conn.begin().await.map_err(|e| {
-                    TransactionError::Connection(DbErr::Query(e.to_string()))
-                })?
-            );
-            transaction.run(callback).await
-        } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            )))
-        }
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| TransactionError::Connection(sqlx_conn_acquire_err(e)))?;
+        let transaction = DatabaseTransaction::from(
+            conn.begin().await.map_err(|e| {
+                TransactionError::Connection(DbErr::Query(e.to_string()))
+            })?
+        );
+        transaction.run(callback).await
     }
 }
 
@@ -116,6 +277,7 @@ impl From<PgRow> for QueryResult {
     fn from(row: PgRow) -> QueryResult {
         QueryResult {
             row: QueryResultRow::SqlxPostgres(row),
+            sensitive: Vec::new(),
         }
     }
 }
@@ -128,10 +290,60 @@ impl From<PgQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Postgres, PgArguments> {
+/// Statements without values are sent as one batch in a single round trip;
+/// otherwise each statement is prepared and executed in turn, with its values bound. The statements are logged with the id of the transaction they run in, if any.
+pub(crate) async fn sqlx_execute_many(
+    conn: &mut sqlx::PgConnection,
+    stmts: Vec<Statement>,
+    transaction_id: Option<u64>,
+) -> Result<Vec<ExecResult>, DbErr> {
+    if let Some(sql) = sqlx_batch_sql(&stmts) {
+        use futures::TryStreamExt;
+        use sqlx::Executor;
+
+        debug_print_statement(transaction_id, &sql);
+        return conn
+            .execute_many(sql.as_str())
+            .map_ok(Into::into)
+            .try_collect()
+            .await
+            .map_err(sqlx_error_to_exec_err);
+    }
+    let mut results = Vec::with_capacity(stmts.len());
+    for stmt in stmts {
+        debug_print_statement(transaction_id, &stmt);
+        let res = sqlx_query(&stmt)?
+            .execute(&mut *conn)
+            .await
+            .map_err(sqlx_error_to_exec_err)?;
+        results.push(res.into());
+    }
+    Ok(results)
+}
+
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, Postgres, PgArguments>, DbErr> {
     let mut query = sqlx::query(&stmt.sql);
     if let Some(values) = &stmt.values {
-        query = bind_query(query, values);
+        query = bind_query(query, values)?;
+    }
+    Ok(query)
+}
+
+/// Postgres has no unsigned types, so unsigned values are bound as `BIGINT`; a `u64` above `i64::MAX` is refused
+fn bind_query<'a>(
+    mut query: sqlx::query::Query<'a, Postgres, PgArguments>,
+    values: &'a Values,
+) -> Result<sqlx::query::Query<'a, Postgres, PgArguments>, DbErr> {
+    for value in values.0.iter() {
+        query = match value {
+            Value::TinyUnsigned(v) => query.bind(v.map(i64::from)),
+            Value::SmallUnsigned(v) => query.bind(v.map(i64::from)),
+            Value::Unsigned(v) => query.bind(v.map(i64::from)),
+            Value::BigUnsigned(v) => query.bind(sqlx_unsigned_to_i64(*v)?),
+            value => sea_query::bind_params_sqlx_postgres!(query, std::slice::from_ref(value)),
+        };
     }
-    query
+    Ok(query)
 }
@@ -1,11 +1,10 @@
-use std::{pin::Pin, future::Future};
+use std::{pin::Pin, future::Future, sync::{Arc, atomic::{AtomicU64, Ordering}}};
 
-use sqlx::{Connection, Sqlite, SqlitePool, sqlite::{SqliteArguments, SqliteQueryResult, SqliteRow}};
+use sqlx::{Connection, Sqlite, SqlitePool, sqlite::{SqliteArguments, SqliteConnectOptions, SqliteQueryResult, SqliteRow}};
 
-sea_query::sea_query_driver_sqlite!();
-use sea_query_driver_sqlite::bind_query;
+use sea_query::{Value, Values};
 
-use crate::{DatabaseConnection, DatabaseTransaction, Statement, TransactionError, debug_print, error::*, executor::*};
+use crate::{AcquireObserver, AfterConnect, ConnectOptions, DatabaseConnection, DatabaseTransaction, DbBackend, debug_print_statement, PooledConnection, Statement, TransactionError, debug_print, error::*, executor::*};
 
 use super::sqlx_common::*;
 
@@ -15,100 +14,172 @@ pub struct SqlxSqliteConnector;
 #[derive(Debug, Clone)]
 pub struct SqlxSqlitePoolConnection {
     pool: SqlitePool,
+    connections_opened: Option<Arc<AtomicU64>>,
+    acquire_observer: Option<AcquireObserver>,
 }
 
 impl SqlxSqliteConnector {
     pub fn accepts(string: &str) -> bool {
-        string.starts_with("sqlite:")
+        DbBackend::from_connection_string(string) == Ok(DbBackend::Sqlite)
     }
 
-    pub async fn connect(string: &str) -> Result<DatabaseConnection, DbErr> {
-        if let Ok(pool) = SqlitePool::connect(string).await {
+    pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let opt = Self::connect_options(&options)?;
+        let acquire_observer = options.acquire_observer.clone();
+        let after_connect = options.after_connect.clone();
+        Self::connect_pool(opt, options.pool_options(), acquire_observer, after_connect).await
+    }
+
+    /// Open an in-memory database on a single connection the pool never closes,
+    /// see [`Database::connect_memory`](crate::Database::connect_memory)
+    pub async fn connect_memory() -> Result<DatabaseConnection, DbErr> {
+        let mut options = ConnectOptions::new("sqlite::memory:".to_owned());
+        options.max_connections(1).min_connections(1);
+        let opt = Self::connect_options(&options)?;
+        // The database is gone once its last connection is closed, so never recycle it
+        let pool_options = options
+            .pool_options()
+            .idle_timeout(None)
+            .max_lifetime(None);
+        Self::connect_pool(opt, pool_options, None, None).await
+    }
+
+    async fn connect_pool(
+        opt: SqliteConnectOptions,
+        pool_options: sqlx::pool::PoolOptions<Sqlite>,
+        acquire_observer: Option<AcquireObserver>,
+        after_connect: Option<AfterConnect>,
+    ) -> Result<DatabaseConnection, DbErr> {
+        let connections_opened = Arc::new(AtomicU64::new(0));
+        let pool_options = sqlx_after_connect(pool_options, &connections_opened, after_connect);
+        if let Ok(pool) = pool_options.connect_with(opt).await {
             Ok(DatabaseConnection::SqlxSqlitePoolConnection(
-                SqlxSqlitePoolConnection { pool },
+                SqlxSqlitePoolConnection {
+                    pool,
+                    connections_opened: Some(connections_opened),
+                    acquire_observer,
+                },
             ))
         } else {
             Err(DbErr::Conn("Failed to connect.".to_owned()))
         }
     }
+
+    pub(crate) fn connect_options(options: &ConnectOptions) -> Result<SqliteConnectOptions, DbErr> {
+        let mut opt: SqliteConnectOptions = options
+            .url
+            .parse()
+            .map_err(|e: sqlx::Error| DbErr::Conn(e.to_string()))?;
+        opt = opt.foreign_keys(options.sqlite_foreign_keys);
+        if let Some(capacity) = options.statement_cache_capacity {
+            opt = opt.statement_cache_capacity(capacity);
+        }
+        Ok(opt)
+    }
 }
 
 impl SqlxSqliteConnector {
     pub fn from_sqlx_sqlite_pool(pool: SqlitePool) -> DatabaseConnection {
-        DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection { pool })
+        DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
+            pool,
+            connections_opened: None,
+            acquire_observer: None,
+        })
     }
 }
 
 impl SqlxSqlitePoolConnection {
+    /// Get the underlying sqlx pool. This is an escape hatch for features
+    /// SeaORM does not wrap; queries run through it bypass SeaORM entirely.
+    pub fn get_sqlite_connection_pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// The number of connections the pool has opened so far, see
+    /// [`DatabaseConnection::connections_opened`]
+    pub fn connections_opened(&self) -> Option<u64> {
+        self.connections_opened
+            .as_ref()
+            .map(|counter| counter.load(Ordering::Relaxed))
+    }
+
+    /// The number of open connections waiting in the pool, see
+    /// [`DatabaseConnection::idle_connections`]
+    pub fn idle_connections(&self) -> usize {
+        self.pool.num_idle()
+    }
+
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.execute(conn).await {
-                Ok(res) => Ok(res.into()),
-                Err(err) => Err(sqlx_error_to_exec_err(err)),
-            }
-        } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.execute(conn).await {
+            Ok(res) => Ok(res.into()),
+            Err(err) => Err(sqlx_error_to_exec_err(err)),
         }
     }
 
+    /// Execute the statements in order on one pooled connection, see [`sqlx_execute_many`]
+    pub async fn execute_many(&self, stmts: Vec<Statement>) -> Result<Vec<ExecResult>, DbErr> {
+        let conn = &mut self.acquire_conn().await?;
+        sqlx_execute_many(conn, stmts, None).await
+    }
+
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.fetch_one(conn).await {
-                Ok(row) => Ok(Some(row.into())),
-                Err(err) => match err {
-                    sqlx::Error::RowNotFound => Ok(None),
-                    _ => Err(DbErr::Query(err.to_string())),
-                },
-            }
-        } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.fetch_one(conn).await {
+            Ok(row) => Ok(Some(row.into())),
+            Err(sqlx::Error::RowNotFound) => Ok(None),
+            Err(err) => Err(sqlx_error_to_query_err(err)),
         }
     }
 
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            match query.fetch_all(conn).await {
-                Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
-                Err(err) => Err(sqlx_error_to_query_err(err)),
-            }
-        } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+        let query = sqlx_query(&stmt)?;
+        let conn = &mut self.acquire_conn().await?;
+        match query.fetch_all(conn).await {
+            Ok(rows) => Ok(rows.into_iter().map(|r| r.into()).collect()),
+            Err(err) => Err(sqlx_error_to_query_err(err)),
         }
     }
 
+    async fn acquire_conn(&self) -> Result<sqlx::pool::PoolConnection<Sqlite>, DbErr> {
+        sqlx_observe_acquire(self.acquire_observer.as_ref(), self.pool.acquire()).await
+    }
+
+    pub async fn acquire(&self) -> Result<PooledConnection, DbErr> {
+        self.acquire_conn().await.map(PooledConnection::from)
+    }
+
+    pub async fn begin(&self) -> Result<DatabaseTransaction<'static>, DbErr> {
+        sqlx_observe_acquire(self.acquire_observer.as_ref(), self.pool.begin())
+            .await
+            .map(DatabaseTransaction::from)
+    }
+
     pub async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
     where
         F: for<'b> FnOnce(&'b DatabaseTransaction<'_>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'b>> + Send + Sync,
         T: Send,
         E: std::error::Error + Send,
     {
-        if let Ok(conn) = &mut self.pool.acquire().await {
-            let transaction = DatabaseTransaction::from(
-                conn.begin().await.map_err(|e| {
-                    TransactionError::Connection(DbErr::Query(e.to_string()))
-                })?
-            );
-            transaction.run(callback).await
-        } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            )))
-        }
+        let conn = &mut self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| TransactionError::Connection(sqlx_conn_acquire_err(e)))?;
+        let transaction = DatabaseTransaction::from(
+            conn.begin().await.map_err(|e| {
+                TransactionError::Connection(DbErr::Query(e.to_string()))
+            })?
+        );
+        transaction.run(callback).await
     }
 }
 
@@ -116,6 +187,7 @@ impl From<SqliteRow> for QueryResult {
     fn from(row: SqliteRow) -> QueryResult {
         QueryResult {
             row: QueryResultRow::SqlxSqlite(row),
+            sensitive: Vec::new(),
         }
     }
 }
@@ -128,10 +200,57 @@ impl From<SqliteQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Sqlite, SqliteArguments> {
+/// Statements without values are sent as one batch in a single round trip;
+/// otherwise each statement is prepared and executed in turn, with its values bound. The statements are logged with the id of the transaction they run in, if any.
+pub(crate) async fn sqlx_execute_many(
+    conn: &mut sqlx::SqliteConnection,
+    stmts: Vec<Statement>,
+    transaction_id: Option<u64>,
+) -> Result<Vec<ExecResult>, DbErr> {
+    if let Some(sql) = sqlx_batch_sql(&stmts) {
+        use futures::TryStreamExt;
+        use sqlx::Executor;
+
+        debug_print_statement(transaction_id, &sql);
+        return conn
+            .execute_many(sql.as_str())
+            .map_ok(Into::into)
+            .try_collect()
+            .await
+            .map_err(sqlx_error_to_exec_err);
+    }
+    let mut results = Vec::with_capacity(stmts.len());
+    for stmt in stmts {
+        debug_print_statement(transaction_id, &stmt);
+        let res = sqlx_query(&stmt)?
+            .execute(&mut *conn)
+            .await
+            .map_err(sqlx_error_to_exec_err)?;
+        results.push(res.into());
+    }
+    Ok(results)
+}
+
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, Sqlite, SqliteArguments<'_>>, DbErr> {
     let mut query = sqlx::query(&stmt.sql);
     if let Some(values) = &stmt.values {
-        query = bind_query(query, values);
+        query = bind_query(query, values)?;
+    }
+    Ok(query)
+}
+
+/// SQLite stores every integer as `i64`; a `u64` above `i64::MAX` is refused
+fn bind_query<'a>(
+    mut query: sqlx::query::Query<'a, Sqlite, SqliteArguments<'a>>,
+    values: &'a Values,
+) -> Result<sqlx::query::Query<'a, Sqlite, SqliteArguments<'a>>, DbErr> {
+    for value in values.0.iter() {
+        query = match value {
+            Value::BigUnsigned(v) => query.bind(sqlx_unsigned_to_i64(*v)?),
+            value => sea_query::bind_params_sqlx_sqlite!(query, std::slice::from_ref(value)),
+        };
     }
-    query
+    Ok(query)
 }
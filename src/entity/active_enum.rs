@@ -0,0 +1,304 @@
+use crate::{ColumnDef, DbErr, TryGetable};
+use sea_query::{Nullable, Value, ValueType};
+
+/// A Rust representation of enum defined in database.
+///
+/// Each variant is stored as a string or an integer, decided by `rs_type`, in a
+/// column of type `db_type`, e.g. `String(Some(1))` or `Integer`. With `db_type = "Enum"`
+/// the column is a native database enum of the string values, named by `enum_name`,
+/// or else the enum name in snake case, see [`ColumnType::Enum`](crate::ColumnType::Enum).
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq, DeriveActiveEnum)]
+/// #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+/// pub enum OrderStatus {
+///     #[sea_orm(string_value = "P")]
+///     Pending,
+///     #[sea_orm(string_value = "S")]
+///     Shipped,
+/// }
+///
+/// assert_eq!(OrderStatus::Shipped.to_value(), "S".to_owned());
+/// assert_eq!(
+///     OrderStatus::try_from_value(&"P".to_owned()),
+///     Ok(OrderStatus::Pending)
+/// );
+/// assert_eq!(
+///     OrderStatus::try_from_value(&"X".to_owned()).unwrap_err(),
+///     DbErr::Query("unexpected value for OrderStatus enum: \"X\"".to_owned())
+/// );
+/// ```
+pub trait ActiveEnum: Sized {
+    /// The Rust type each variant is stored as
+    type Value: Into<Value> + ValueType + Nullable + TryGetable;
+
+    /// Convert the variant into its stored value
+    fn to_value(&self) -> Self::Value;
+
+    /// Convert a stored value back into a variant, failing on unknown values
+    fn try_from_value(v: &Self::Value) -> Result<Self, DbErr>;
+
+    /// The column definition of this enum
+    fn db_type() -> ColumnDef;
+}
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use crate as sea_orm;
+    use crate::{entity::prelude::*, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn active_enum_string() {
+        #[derive(Debug, PartialEq, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        assert_eq!(Category::Big.to_value(), "B".to_owned());
+        assert_eq!(Category::Small.to_value(), "S".to_owned());
+
+        assert_eq!(Category::try_from_value(&"B".to_owned()), Ok(Category::Big));
+        assert_eq!(
+            Category::try_from_value(&"S".to_owned()),
+            Ok(Category::Small)
+        );
+        assert_eq!(
+            Category::try_from_value(&"A".to_owned()),
+            Err(DbErr::Query(
+                "unexpected value for Category enum: \"A\"".to_owned()
+            ))
+        );
+
+        assert_eq!(Category::db_type(), ColumnType::String(Some(1)).def());
+
+        assert_eq!(
+            Value::from(Category::Big),
+            Value::String(Some(Box::new("B".to_owned())))
+        );
+        assert_eq!(
+            <Category as sea_query::ValueType>::try_from(Value::String(Some(Box::new(
+                "S".to_owned()
+            ))))
+            .ok(),
+            Some(Category::Small)
+        );
+    }
+
+    #[test]
+    fn active_enum_num() {
+        #[derive(Debug, PartialEq, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer")]
+        pub enum Priority {
+            #[sea_orm(num_value = 1)]
+            Low,
+            #[sea_orm(num_value = 10)]
+            High,
+        }
+
+        assert_eq!(Priority::Low.to_value(), 1);
+        assert_eq!(Priority::High.to_value(), 10);
+
+        assert_eq!(Priority::try_from_value(&1), Ok(Priority::Low));
+        assert_eq!(Priority::try_from_value(&10), Ok(Priority::High));
+        assert_eq!(
+            Priority::try_from_value(&2),
+            Err(DbErr::Query(
+                "unexpected value for Priority enum: 2".to_owned()
+            ))
+        );
+
+        assert_eq!(Priority::db_type(), ColumnType::Integer.def());
+    }
+
+    #[test]
+    fn active_enum_db_enum() {
+        #[derive(Debug, PartialEq, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tea")]
+        pub enum Tea {
+            #[sea_orm(string_value = "EverydayTea")]
+            EverydayTea,
+            #[sea_orm(string_value = "BreakfastTea")]
+            BreakfastTea,
+        }
+
+        #[derive(Debug, PartialEq, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum")]
+        pub enum TeaCup {
+            #[sea_orm(string_value = "Mug")]
+            Mug,
+        }
+
+        assert_eq!(
+            Tea::db_type(),
+            ColumnType::Enum(
+                "tea".to_owned(),
+                vec!["EverydayTea".to_owned(), "BreakfastTea".to_owned()]
+            )
+            .def()
+        );
+        assert_eq!(
+            TeaCup::db_type(),
+            ColumnType::Enum("tea_cup".to_owned(), vec!["Mug".to_owned()]).def()
+        );
+        assert_eq!(
+            Tea::try_from_value(&"GreenTea".to_owned()),
+            Err(DbErr::Query(
+                "unexpected value for Tea enum: \"GreenTea\"".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn active_enum_db_enum_in_model() {
+        mod order {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Debug, Clone, PartialEq, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "order_status")]
+            pub enum Status {
+                #[sea_orm(string_value = "Pending")]
+                Pending,
+                #[sea_orm(string_value = "Shipped")]
+                Shipped,
+            }
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "order")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub status: Status,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let find = order::Entity::find()
+            .filter(order::Column::Status.is_in(vec![order::Status::Shipped]))
+            .filter(order::Column::Status.ne(order::Status::Pending));
+        assert_eq!(
+            find.build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT "order"."id", "order"."status" FROM "order""#,
+                r#"WHERE CAST("order"."status" AS text) IN ('Shipped')"#,
+                r#"AND CAST("order"."status" AS text) <> 'Pending'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            find.build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `order`.`id`, `order`.`status` FROM `order`",
+                "WHERE `order`.`status` IN ('Shipped') AND `order`.`status` <> 'Pending'",
+            ]
+            .join(" ")
+        );
+
+        let model = order::ActiveModel {
+            id: Set(1),
+            status: Set(order::Status::Shipped),
+        };
+        let insert = order::Entity::insert(model.clone());
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "order" ("id", "status") VALUES (1, CAST('Shipped' AS "order_status"))"#
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            "INSERT INTO `order` (`id`, `status`) VALUES (1, CAST('Shipped' AS CHAR))"
+        );
+        assert_eq!(
+            order::Entity::update(model)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "order" SET "status" = CAST('Shipped' AS "order_status")"#,
+                r#"WHERE "order"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn active_enum_in_model() {
+        mod order {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Debug, Clone, PartialEq, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+            pub enum Status {
+                #[sea_orm(string_value = "P")]
+                Pending,
+                #[sea_orm(string_value = "S")]
+                Shipped,
+            }
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "order")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub status: Status,
+                pub prev_status: Option<Status>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            order::Column::Status.def(),
+            ColumnType::String(Some(1)).def()
+        );
+        assert_eq!(
+            order::Column::PrevStatus.def(),
+            ColumnType::String(Some(1)).def().null()
+        );
+
+        assert_eq!(
+            order::Entity::find()
+                .filter(order::Column::Status.eq(order::Status::Shipped))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "order"."id", "order"."status", "order"."prev_status""#,
+                r#"FROM "order" WHERE "order"."status" = 'S'"#,
+            ]
+            .join(" ")
+        );
+
+        let mut active_model: order::ActiveModel = order::Model {
+            id: 1,
+            status: order::Status::Pending,
+            prev_status: None,
+        }
+        .into();
+        active_model.set(
+            order::Column::PrevStatus,
+            Some(order::Status::Shipped).into(),
+        );
+        assert_eq!(
+            active_model.get(order::Column::PrevStatus).unwrap(),
+            Value::String(Some(Box::new("S".to_owned())))
+        );
+        assert_eq!(
+            active_model.prev_status.unwrap(),
+            Some(order::Status::Shipped)
+        );
+    }
+}
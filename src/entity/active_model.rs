@@ -1,6 +1,6 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyToColumn,
-    PrimaryKeyTrait, Value,
+    error::*, ConnectionTrait, DeleteResult, EntityTrait, IdenStatic, Iterable,
+    PrimaryKeyToColumn, PrimaryKeyTrait, Value,
 };
 use async_trait::async_trait;
 use std::fmt::Debug;
@@ -30,19 +30,14 @@ where
     ActiveValue::unset()
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 enum ActiveValueState {
     Set,
     Unchanged,
+    #[default]
     Unset,
 }
 
-impl Default for ActiveValueState {
-    fn default() -> Self {
-        Self::Unset
-    }
-}
-
 #[doc(hidden)]
 pub fn unchanged_active_value_not_intended_for_public_use<V>(value: V) -> ActiveValue<V>
 where
@@ -61,44 +56,94 @@ pub trait ActiveModelTrait: Clone + Debug {
 
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// Set a column, failing instead of panicking when the value does not match the field type
+    fn try_set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value) -> Result<(), DbErr> {
+        self.set(c, v);
+        Ok(())
+    }
+
     fn unset(&mut self, c: <Self::Entity as EntityTrait>::Column);
 
     fn is_unset(&self, c: <Self::Entity as EntityTrait>::Column) -> bool;
 
     fn default() -> Self;
 
-    async fn insert<C>(self, db: &C) -> Result<Self, DbErr>
+    /// Build an ActiveModel from a JSON object, keyed by column name.
+    /// Fields present in the object are `Set` (`null` sets an `Option` field to `None`),
+    /// absent fields are left unset. Unknown fields and mistyped values are errors.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake};
+    /// use serde_json::json;
+    ///
+    /// let cake = cake::ActiveModel::from_json(json!({ "name": "Apple Pie" })).unwrap();
+    /// assert_eq!(cake.id, Unset(None));
+    /// assert_eq!(cake.name, Set("Apple Pie".to_owned()));
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn from_json(json: serde_json::Value) -> Result<Self, DbErr> {
+        use std::str::FromStr;
+
+        let obj = match json {
+            serde_json::Value::Object(obj) => obj,
+            json => {
+                return Err(DbErr::Json(format!(
+                    "expected a JSON object, found {}",
+                    json
+                )))
+            }
+        };
+        let mut am = <Self as ActiveModelTrait>::default();
+        for (key, json) in obj {
+            let col = <<Self::Entity as EntityTrait>::Column as FromStr>::from_str(&key)
+                .map_err(|_| DbErr::Json(format!("unknown field `{}`", key)))?;
+            let set = json_to_values(&json)
+                .into_iter()
+                .any(|v| am.try_set(col, v).is_ok());
+            if !set {
+                return Err(DbErr::Json(format!(
+                    "invalid value {} for field `{}`",
+                    json, key
+                )));
+            }
+        }
+        Ok(am)
+    }
+
+    /// Insert the model and return it as stored, including the values generated by
+    /// the database such as an auto-increment primary key or column defaults.
+    /// [`ActiveModelBehavior::before_save`] is called first, e.g. to fill `created_at`.
+    async fn insert<C>(self, db: &C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
+        Self: ActiveModelBehavior,
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         C: ConnectionTrait,
     {
-        let am = self;
-        let exec = <Self::Entity as EntityTrait>::insert(am).exec(db);
-        let res = exec.await?;
-        // Assume valid last_insert_id is not equals to Default::default()
-        if res.last_insert_id
-            != <<Self::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType::default()
-        {
-            let found = <Self::Entity as EntityTrait>::find_by_id(res.last_insert_id)
-                .one(db)
-                .await?;
-            match found {
-                Some(model) => Ok(model.into_active_model()),
-                None => Err(DbErr::Exec("Failed to find inserted item".to_owned())),
-            }
-        } else {
-            Ok(Self::default())
-        }
+        let am = ActiveModelBehavior::before_save(self, true);
+        let exec = <Self::Entity as EntityTrait>::insert(am).exec_with_returning(db);
+        exec.await
     }
 
-    async fn update<C>(self, db: &C) -> Result<Self, DbErr>
-    where C: ConnectionTrait {
-        let exec = Self::Entity::update(self).exec(db);
+    /// Update the model by its primary key and return it as stored.
+    /// Only `Set` columns are written.
+    /// [`ActiveModelBehavior::before_save`] is called first, e.g. to refresh `updated_at`.
+    async fn update<C>(self, db: &C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    where
+        Self: ActiveModelBehavior,
+        C: ConnectionTrait,
+    {
+        let am = ActiveModelBehavior::before_save(self, false);
+        let exec = Self::Entity::update(am).exec_with_returning(db);
         exec.await
     }
 
-    /// Insert the model if primary key is unset, update otherwise.
-    /// Only works if the entity has auto increment primary key.
+    /// Insert or update the model, deciding by its primary key:
+    /// - if any key column is `Unset`, the model is new and is inserted;
+    /// - if the key is `Unchanged`, i.e. the model was loaded from the database, it is updated;
+    /// - if the key is `Set`, it is updated when the key is auto-increment, since new rows get
+    ///   their key from the database, and inserted otherwise.
+    ///
+    /// Returns the model as stored, including values generated by the database.
     async fn save<C>(self, db: &C) -> Result<Self, DbErr>
     where
         Self: ActiveModelBehavior,
@@ -106,25 +151,29 @@ pub trait ActiveModelTrait: Clone + Debug {
         C: ConnectionTrait,
     {
         let mut am = self;
-        am = ActiveModelBehavior::before_save(am);
-        let mut is_update = true;
+        let mut insert = false;
         for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
-            let col = key.into_column();
-            if am.is_unset(col) {
-                is_update = false;
+            let av = am.get(key.into_column());
+            if av.is_unset()
+                || (av.is_set()
+                    && !<<Self::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment())
+            {
+                insert = true;
                 break;
             }
         }
-        if !is_update {
-            am = am.insert(db).await?;
+        // `insert` and `update` call `before_save`
+        if insert {
+            am = am.insert(db).await?.into_active_model();
         } else {
-            am = am.update(db).await?;
+            am = am.update(db).await?.into_active_model();
         }
-        am = ActiveModelBehavior::after_save(am);
+        am = ActiveModelBehavior::after_save(am, insert);
         Ok(am)
     }
 
-    /// Delete an active model by its primary key
+    /// Delete an active model by its primary key, matching every column of a composite key.
+    /// Fails with [`DbErr::Exec`] if a key column is `Unset`.
     async fn delete<C>(self, db: &C) -> Result<DeleteResult, DbErr>
     where
         Self: ActiveModelBehavior,
@@ -132,11 +181,114 @@ pub trait ActiveModelTrait: Clone + Debug {
     {
         let mut am = self;
         am = ActiveModelBehavior::before_delete(am);
+        for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
+            let col = key.into_column();
+            if am.get(col).is_unset() {
+                return Err(DbErr::Exec(format!(
+                    "Primary key `{}` is not set",
+                    col.as_str()
+                )));
+            }
+        }
         let exec = Self::Entity::delete(am).exec(db);
         exec.await
     }
 }
 
+/// Every `Value` a JSON value could stand for; the field type decides which one is accepted
+#[cfg(feature = "with-json")]
+fn json_to_values(json: &serde_json::Value) -> Vec<Value> {
+    use serde_json::Value as Json;
+    use std::convert::TryFrom;
+
+    let mut values = Vec::new();
+    match json {
+        Json::Null => {
+            values.extend(vec![
+                Value::Bool(None),
+                Value::TinyInt(None),
+                Value::SmallInt(None),
+                Value::Int(None),
+                Value::BigInt(None),
+                Value::TinyUnsigned(None),
+                Value::SmallUnsigned(None),
+                Value::Unsigned(None),
+                Value::BigUnsigned(None),
+                Value::Float(None),
+                Value::Double(None),
+                Value::String(None),
+                Value::Bytes(None),
+                Value::Json(None),
+            ]);
+            #[cfg(feature = "with-chrono")]
+            values.extend(vec![
+                Value::Date(None),
+                Value::Time(None),
+                Value::DateTime(None),
+                Value::DateTimeWithTimeZone(None),
+            ]);
+            #[cfg(feature = "with-rust_decimal")]
+            values.push(Value::Decimal(None));
+            #[cfg(feature = "with-uuid")]
+            values.push(Value::Uuid(None));
+        }
+        Json::Bool(b) => values.push(Value::Bool(Some(*b))),
+        Json::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                values.extend(i8::try_from(i).ok().map(Value::from));
+                values.extend(i16::try_from(i).ok().map(Value::from));
+                values.extend(i32::try_from(i).ok().map(Value::from));
+                values.push(Value::from(i));
+            }
+            if let Some(u) = n.as_u64() {
+                values.extend(u8::try_from(u).ok().map(Value::from));
+                values.extend(u16::try_from(u).ok().map(Value::from));
+                values.extend(u32::try_from(u).ok().map(Value::from));
+                values.push(Value::from(u));
+            }
+            if let Some(f) = n.as_f64() {
+                values.push(Value::Float(Some(f as f32)));
+                values.push(Value::Double(Some(f)));
+            }
+            #[cfg(feature = "with-rust_decimal")]
+            values.extend(
+                n.to_string()
+                    .parse::<rust_decimal::Decimal>()
+                    .ok()
+                    .map(Value::from),
+            );
+        }
+        Json::String(s) => {
+            values.push(Value::from(s.as_str()));
+            #[cfg(feature = "with-chrono")]
+            {
+                values.extend(s.parse::<chrono::NaiveDateTime>().ok().map(Value::from));
+                values.extend(
+                    chrono::DateTime::parse_from_rfc3339(s)
+                        .ok()
+                        .map(Value::from),
+                );
+                values.extend(s.parse::<chrono::NaiveDate>().ok().map(Value::from));
+                values.extend(s.parse::<chrono::NaiveTime>().ok().map(Value::from));
+            }
+            #[cfg(feature = "with-rust_decimal")]
+            values.extend(s.parse::<rust_decimal::Decimal>().ok().map(Value::from));
+            #[cfg(feature = "with-uuid")]
+            values.extend(uuid::Uuid::parse_str(s).ok().map(Value::from));
+        }
+        Json::Array(items) => {
+            let bytes: Option<Vec<u8>> = items
+                .iter()
+                .map(|item| item.as_u64().and_then(|u| u8::try_from(u).ok()))
+                .collect();
+            values.extend(bytes.map(Value::from));
+        }
+        Json::Object(_) => {}
+    }
+    values.push(Value::Json(Some(Box::new(json.clone()))));
+    values
+}
+
 /// Behaviors for users to override
 pub trait ActiveModelBehavior: ActiveModelTrait {
     /// Create a new ActiveModel with default values. Also used by `Default::default()`.
@@ -144,13 +296,18 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
         <Self as ActiveModelTrait>::default()
     }
 
-    /// Will be called before saving
-    fn before_save(self) -> Self {
+    /// Will be called before inserting or updating, by `insert`, `update` and `save`.
+    /// `insert` is true when the model is about to be inserted, and false when it is
+    /// about to be updated.
+    #[allow(unused_variables)]
+    fn before_save(self, insert: bool) -> Self {
         self
     }
 
-    /// Will be called after saving
-    fn after_save(self) -> Self {
+    /// Will be called after `save`. `insert` is true when the model has just been inserted,
+    /// and false when it has just been updated.
+    #[allow(unused_variables)]
+    fn after_save(self, insert: bool) -> Self {
         self
     }
 
@@ -160,6 +317,35 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     }
 }
 
+/// A timestamp type which can be filled with the current time, used by the
+/// `#[sea_orm(created_at)]` and `#[sea_orm(updated_at)]` attributes of `DeriveActiveModelBehavior`
+pub trait CurrentTimestamp: Into<Value> {
+    fn current_timestamp() -> Self;
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::NaiveDateTime {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::FixedOffset> {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().into()
+    }
+}
+
+impl<T> CurrentTimestamp for Option<T>
+where
+    T: CurrentTimestamp + sea_query::Nullable,
+{
+    fn current_timestamp() -> Self {
+        Some(T::current_timestamp())
+    }
+}
+
 pub trait IntoActiveModel<A>
 where
     A: ActiveModelTrait,
@@ -176,6 +362,47 @@ where
     }
 }
 
+/// A field value of a DTO converted by `DeriveIntoActiveModel`. A value of the field's own type
+/// is `Set`; an `Option` of a non-nullable field type is `Set` when `Some` and `Unset` when `None`.
+///
+/// For a nullable field, a patch tells "leave alone" from "set to NULL" with an `Option<Option<T>>`:
+/// `None` is `Unset`, `Some(None)` sets NULL and `Some(Some(v))` sets `v`.
+/// ```
+/// use sea_orm::entity::*;
+///
+/// let leave: ActiveValue<Option<i32>> = None::<Option<i32>>.into_active_value();
+/// assert!(leave.is_unset());
+/// assert_eq!(Some(None::<i32>).into_active_value(), Set(None));
+/// assert_eq!(Some(Some(3)).into_active_value(), Set(Some(3)));
+/// ```
+pub trait IntoActiveValue<V>
+where
+    V: Into<Value>,
+{
+    fn into_active_value(self) -> ActiveValue<V>;
+}
+
+impl<V> IntoActiveValue<V> for V
+where
+    V: Into<Value>,
+{
+    fn into_active_value(self) -> ActiveValue<V> {
+        ActiveValue::set(self)
+    }
+}
+
+impl<V> IntoActiveValue<V> for Option<V>
+where
+    V: Into<Value>,
+{
+    fn into_active_value(self) -> ActiveValue<V> {
+        match self {
+            Some(value) => ActiveValue::set(value),
+            None => ActiveValue::unset(),
+        }
+    }
+}
+
 impl<V> ActiveValue<V>
 where
     V: Into<Value>,
@@ -252,3 +479,419 @@ where
         self.value.as_ref() == other.value.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[smol_potat::test]
+    #[cfg(all(feature = "macros", feature = "mock", feature = "with-chrono"))]
+    async fn insert_and_update_with_timestamps() -> Result<(), crate::DbErr> {
+        use crate::{entity::*, DbBackend, MockDatabase, MockExecResult};
+
+        mod post {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveActiveModelBehavior)]
+            #[sea_orm(table_name = "post")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+                #[sea_orm(created_at)]
+                pub created_at: DateTime,
+                #[sea_orm(updated_at)]
+                pub updated_at: DateTime,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter)]
+            pub enum Relation {}
+
+            impl RelationTrait for Relation {
+                fn def(&self) -> RelationDef {
+                    panic!("No RelationDef")
+                }
+            }
+        }
+
+        let created_at = chrono::NaiveDate::from_ymd_opt(2021, 10, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let inserted = post::Model {
+            id: 1,
+            title: "Hello".to_owned(),
+            created_at,
+            updated_at: created_at,
+        };
+        let updated = post::Model {
+            title: "World".to_owned(),
+            updated_at: created_at + chrono::Duration::seconds(1),
+            ..inserted.clone()
+        };
+        let exec_result = || MockExecResult {
+            last_insert_id: 1,
+            rows_affected: 1,
+        };
+
+        // On insert, both timestamps are filled
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![exec_result()])
+            .append_query_results(vec![vec![], vec![inserted.clone()]])
+            .into_connection();
+        let am = post::ActiveModel {
+            title: Set("Hello".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(am.insert(&db).await?, inserted);
+        let log = db.into_transaction_log();
+        assert!(format!("{:?}", log[0])
+            .contains("INSERT INTO `post` (`title`, `created_at`, `updated_at`) VALUES (?, ?, ?)"));
+
+        // On update, only `updated_at` is refreshed
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![exec_result()])
+            .append_query_results(vec![vec![], vec![updated.clone()]])
+            .into_connection();
+        let mut am = inserted.clone().into_active_model();
+        am.title = Set("World".to_owned());
+        assert_eq!(am.update(&db).await?, updated);
+        let log = db.into_transaction_log();
+        assert!(format!("{:?}", log[0])
+            .contains("UPDATE `post` SET `title` = ?, `updated_at` = ? WHERE"));
+
+        // `save` refreshes it the same way
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![exec_result()])
+            .append_query_results(vec![vec![], vec![updated.clone()]])
+            .into_connection();
+        let mut am = inserted.into_active_model();
+        am.title = Set("World".to_owned());
+        let am = am.save(&db).await?;
+        assert_eq!(am, updated.into_active_model());
+
+        let log = db.into_transaction_log();
+        let update = format!("{:?}", log[0]);
+        assert!(update.contains("UPDATE `post` SET `title` = ?, `updated_at` = ? WHERE"));
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn delete_by_primary_key() -> Result<(), crate::DbErr> {
+        use crate::{
+            entity::*, tests_cfg::cake_filling, DbBackend, DbErr, MockDatabase, MockExecResult,
+            Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        // Every column of a composite key is matched
+        let res = cake_filling::Model {
+            cake_id: 1,
+            filling_id: 2,
+        }
+        .delete(&db)
+        .await?;
+        assert_eq!(res.rows_affected, 1);
+
+        let res = cake_filling::ActiveModel {
+            cake_id: Set(1),
+            ..Default::default()
+        }
+        .delete(&db)
+        .await;
+        assert_eq!(
+            res.unwrap_err(),
+            DbErr::Exec("Primary key `filling_id` is not set".to_owned())
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"DELETE FROM "cake_filling" WHERE "cake_filling"."cake_id" = $1 AND "cake_filling"."filling_id" = $2"#,
+                vec![1i32.into(), 2i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn insert_last_insert_id() -> Result<(), crate::DbErr> {
+        use crate::{
+            entity::*, tests_cfg::cake, DbBackend, DbErr, MockDatabase, MockExecResult,
+            TryFromU64,
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 15,
+                    rows_affected: 1,
+                },
+                MockExecResult::default(),
+                MockExecResult {
+                    last_insert_id: i32::MAX as u64 + 1,
+                    rows_affected: 1,
+                },
+            ])
+            .append_query_results(vec![
+                // Exec and query results are counted together
+                vec![],
+                vec![cake::Model {
+                    id: 15,
+                    name: "Apple Pie".to_owned(),
+                }],
+            ])
+            .into_connection();
+
+        let apple = cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            apple.clone().insert(&db).await?,
+            cake::Model {
+                id: 15,
+                name: "Apple Pie".to_owned(),
+            }
+        );
+        assert_eq!(
+            apple.insert(&db).await.unwrap_err(),
+            DbErr::Exec("fail to convert '2147483648' into 'i32'".to_owned())
+        );
+
+        assert_eq!(i64::try_from_u64(u64::MAX >> 1)?, i64::MAX);
+        assert!(i64::try_from_u64(u64::MAX).is_err());
+        #[cfg(feature = "with-uuid")]
+        assert!(uuid::Uuid::try_from_u64(1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn derive_into_active_model_full() {
+        use crate as sea_orm;
+        use crate::{entity::*, tests_cfg::fruit, DeriveIntoActiveModel};
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "fruit::ActiveModel")]
+        struct NewFruit {
+            id: i32,
+            name: String,
+            cake_id: Option<i32>,
+        }
+
+        assert_eq!(
+            NewFruit {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: None,
+            }
+            .into_active_model(),
+            fruit::ActiveModel {
+                id: Set(1),
+                name: Set("Apple".to_owned()),
+                cake_id: Set(None),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn derive_into_active_model_partial() {
+        use crate as sea_orm;
+        use crate::{entity::*, tests_cfg::fruit, DeriveIntoActiveModel};
+
+        // Fields missing from the struct, and a `None` for a non-nullable field, are left unset
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "fruit::ActiveModel")]
+        struct UpdateFruit {
+            name: Option<String>,
+        }
+
+        let am: fruit::ActiveModel = UpdateFruit {
+            name: Some("Pear".to_owned()),
+        }
+        .into_active_model();
+        assert_eq!(
+            am,
+            fruit::ActiveModel {
+                id: Unset(None),
+                name: Set("Pear".to_owned()),
+                cake_id: Unset(None),
+            }
+        );
+        assert!(am.name.is_set() && am.id.is_unset() && am.cake_id.is_unset());
+
+        let am: fruit::ActiveModel = UpdateFruit { name: None }.into_active_model();
+        assert!(am.name.is_unset());
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn from_json_partial() {
+        use crate::{entity::*, tests_cfg::fruit};
+        use serde_json::json;
+
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({ "name": "Apple" })).unwrap(),
+            fruit::ActiveModel {
+                id: Unset(None),
+                name: Set("Apple".to_owned()),
+                cake_id: Unset(None),
+            }
+        );
+
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({ "cake_id": null })).unwrap(),
+            fruit::ActiveModel {
+                id: Unset(None),
+                name: Unset(None),
+                cake_id: Set(None),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn from_json_full() {
+        use crate::{entity::*, tests_cfg::fruit};
+        use serde_json::json;
+
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({
+                "id": 1,
+                "name": "Apple",
+                "cake_id": 2,
+            }))
+            .unwrap(),
+            fruit::ActiveModel {
+                id: Set(1),
+                name: Set("Apple".to_owned()),
+                cake_id: Set(Some(2)),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-json"))]
+    fn from_json_err() {
+        use crate::{entity::*, tests_cfg::fruit, DbErr};
+        use serde_json::json;
+
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({ "colour": "red" })),
+            Err(DbErr::Json("unknown field `colour`".to_owned()))
+        );
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({ "name": 1 })),
+            Err(DbErr::Json("invalid value 1 for field `name`".to_owned()))
+        );
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!({ "id": null })),
+            Err(DbErr::Json("invalid value null for field `id`".to_owned()))
+        );
+        assert_eq!(
+            fruit::ActiveModel::from_json(json!(["Apple"])),
+            Err(DbErr::Json(
+                "expected a JSON object, found [\"Apple\"]".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "macros",
+        feature = "with-json",
+        feature = "with-chrono",
+        feature = "with-rust_decimal",
+        feature = "with-uuid"
+    ))]
+    fn to_json_round_trip() {
+        use crate::entity::*;
+        use rust_decimal_macros::dec;
+
+        mod metric {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel, DeriveActiveModelBehavior)]
+            #[sea_orm(table_name = "metric")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: Uuid,
+                pub name: String,
+                pub note: Option<String>,
+                pub count: u32,
+                pub ratio: f64,
+                pub enabled: bool,
+                pub price: Decimal,
+                pub payload: Json,
+                pub blob: Vec<u8>,
+                pub day: Date,
+                pub recorded_at: DateTime,
+                pub updated_at: Option<DateTimeWithTimeZone>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter)]
+            pub enum Relation {}
+
+            impl RelationTrait for Relation {
+                fn def(&self) -> RelationDef {
+                    panic!("No RelationDef")
+                }
+            }
+        }
+
+        let day = chrono::NaiveDate::from_ymd_opt(2021, 10, 1).unwrap();
+        let model = metric::Model {
+            id: uuid::Uuid::new_v4(),
+            name: "latency".to_owned(),
+            note: None,
+            count: 3_000_000_000,
+            ratio: 0.25,
+            enabled: true,
+            price: dec!(12.34),
+            payload: serde_json::json!({ "p99": [1, 2] }),
+            blob: vec![0, 159, 146, 150],
+            day,
+            recorded_at: day.and_hms_milli_opt(12, 30, 0, 250).unwrap(),
+            updated_at: Some(
+                chrono::DateTime::parse_from_rfc3339("2021-10-01T12:30:00+08:00").unwrap(),
+            ),
+        };
+
+        let json = model.to_json();
+        assert_eq!(json["name"], "latency");
+        assert_eq!(json["note"], serde_json::Value::Null);
+        assert_eq!(json["recorded_at"], "2021-10-01T12:30:00.250");
+
+        assert_eq!(
+            metric::ActiveModel::from_json(json).unwrap(),
+            metric::ActiveModel {
+                id: Set(model.id),
+                name: Set(model.name.clone()),
+                note: Set(None),
+                count: Set(model.count),
+                ratio: Set(model.ratio),
+                enabled: Set(model.enabled),
+                price: Set(model.price),
+                payload: Set(model.payload.clone()),
+                blob: Set(model.blob.clone()),
+                day: Set(model.day),
+                recorded_at: Set(model.recorded_at),
+                updated_at: Set(model.updated_at),
+            }
+        );
+    }
+}
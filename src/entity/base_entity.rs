@@ -1,17 +1,26 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne, FromQueryResult, Insert,
-    ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, RelationBuilder,
-    RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    ActiveModelTrait, ColumnDef, ColumnTrait, DbBackend, DbErr, Delete, DeleteMany, DeleteOne,
+    EntityDescription, FromQueryResult, Insert, InsertFromSelect, ModelTrait, PrimaryKeyToColumn,
+    PrimaryKeyTrait, QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select,
+    SelectModel, SelectorRaw, Statement, Update, UpdateMany, UpdateManyWithValues, UpdateOne,
 };
 use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
 pub use sea_strum::IntoEnumIterator as Iterable;
 use std::fmt::Debug;
 
+/// The iterator of [`EntityTrait::columns_with_defs`]
+pub type ColumnsWithDefs<E> = std::iter::Map<
+    <<E as EntityTrait>::Column as Iterable>::Iterator,
+    fn(<E as EntityTrait>::Column) -> (<E as EntityTrait>::Column, ColumnDef),
+>;
+
 pub trait IdenStatic: Iden + Copy + Debug + 'static {
     fn as_str(&self) -> &str;
 }
 
 pub trait EntityName: IdenStatic + Default {
+    /// The schema the table lives in, set with `#[sea_orm(schema_name = "...")]`.
+    /// When present, statements refer to the table as `"schema"."table"`.
     fn schema_name(&self) -> Option<&str> {
         None
     }
@@ -22,12 +31,34 @@ pub trait EntityName: IdenStatic + Default {
         self.table_name()
     }
 
+    /// The table, qualified with its schema if it has one
     fn table_ref(&self) -> TableRef {
         match self.schema_name() {
-            Some(schema) => (Alias::new(schema).into_iden(), self.into_iden()).into_table_ref(),
+            Some(schema) => self.table_ref_in_schema(schema),
             None => self.into_table_ref(),
         }
     }
+
+    /// The table, qualified with the given schema in place of its own
+    fn table_ref_in_schema(&self, schema: &str) -> TableRef {
+        (Alias::new(schema).into_iden(), self.into_iden()).into_table_ref()
+    }
+
+    /// The table as quoted for `backend`, qualified with its schema if it has one,
+    /// for composing raw SQL without spelling out the table name
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(cake::Entity.quoted_table_ref(DbBackend::Postgres), r#""cake""#);
+    /// assert_eq!(cake::Entity.quoted_table_ref(DbBackend::MySql), "`cake`");
+    /// ```
+    fn quoted_table_ref(&self, backend: DbBackend) -> String {
+        let table = backend.quote_iden(self);
+        match self.schema_name() {
+            Some(schema) => format!("{}.{}", backend.quote_iden(&Alias::new(schema)), table),
+            None => table,
+        }
+    }
 }
 
 /// An Entity implementing `EntityTrait` represents a table in a database.
@@ -72,6 +103,42 @@ pub trait EntityTrait: EntityName {
         RelationBuilder::from_rel(RelationType::HasMany, R::to().rev(), true)
     }
 
+    /// Describe the table of the entity: its name and the definition of each of its
+    /// columns, as given by [`ColumnTrait::def`], to check it against the live schema
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake};
+    ///
+    /// let description = cake::Entity::describe();
+    /// assert_eq!(description.table_name, "cake");
+    /// assert_eq!(
+    ///     description.column("name").map(|def| def.get_column_type()),
+    ///     Some(&ColumnType::String(None))
+    /// );
+    /// ```
+    fn describe() -> EntityDescription {
+        EntityDescription::new(Self::default())
+    }
+
+    /// Every column of the entity with its definition, in declaration order,
+    /// e.g. to tell optional fields by [`ColumnDef::is_null`]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::fruit};
+    ///
+    /// let optional: Vec<String> = fruit::Entity::columns_with_defs()
+    ///     .filter(|(_, def)| def.is_null())
+    ///     .map(|(col, _)| col.as_str().to_owned())
+    ///     .collect();
+    /// assert_eq!(optional, vec!["cake_id"]);
+    /// ```
+    fn columns_with_defs() -> ColumnsWithDefs<Self> {
+        fn with_def<C: ColumnTrait>(col: C) -> (C, ColumnDef) {
+            (col, col.def())
+        }
+        Self::Column::iter().map(with_def::<Self::Column>)
+    }
+
     /// Construct select statement to find one / all models
     ///
     /// - To select columns, join tables and group by expressions, see [`QuerySelect`](crate::query::QuerySelect)
@@ -154,6 +221,22 @@ pub trait EntityTrait: EntityName {
         Select::new()
     }
 
+    /// Run a raw statement, e.g. one the query builder can not express, and decode the rows
+    /// into models. The statement must return every column of the model, under its name.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend, Statement};
+    ///
+    /// let _ = cake::Entity::find_by_statement(Statement::from_string(
+    ///     DbBackend::Postgres,
+    ///     r#"WITH "big" AS (SELECT * FROM "cake" WHERE "id" > 10) SELECT "id", "name" FROM "big""#
+    ///         .to_owned(),
+    /// ));
+    /// ```
+    fn find_by_statement(stmt: Statement) -> SelectorRaw<SelectModel<Self::Model>> {
+        SelectorRaw::<SelectModel<Self::Model>>::from_statement(stmt)
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -454,6 +537,29 @@ pub trait EntityTrait: EntityName {
         Update::many(Self::default())
     }
 
+    /// Update many rows in one statement, each to the values set on its own model,
+    /// see [`Update::many_with_values`]
+    fn update_many_with_values<A, I>(models: I) -> UpdateManyWithValues<Self>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+        I: IntoIterator<Item = A>,
+    {
+        Update::many_with_values(models)
+    }
+
+    /// Insert the rows returned by a select into the given columns of this entity,
+    /// see [`Insert::from_select`]
+    fn insert_from_select<C, S>(
+        columns: C,
+        select: Select<S>,
+    ) -> Result<InsertFromSelect<Self>, DbErr>
+    where
+        C: IntoIterator<Item = Self::Column>,
+        S: EntityTrait,
+    {
+        InsertFromSelect::new(columns, select)
+    }
+
     /// Delete an model from database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -599,4 +705,182 @@ mod tests {
         assert_eq!(hello::Entity.table_name(), "hello");
         assert_eq!(hello::Entity.schema_name(), Some("world"));
     }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn entity_model_quote_identifiers() {
+        use crate::{entity::*, query::*, DbBackend};
+        use sea_query::Expr;
+
+        mod line_item {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "LineItem")]
+            pub struct Model {
+                #[sea_orm(primary_key, column_name = "Id")]
+                pub id: i32,
+                #[sea_orm(column_name = "UnitPrice")]
+                pub unit_price: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        mod order_line {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "order_line", quote_identifiers = false)]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub unit_price: i32,
+                #[sea_orm(column_name = "ShippedAt")]
+                pub shipped_at: Option<String>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        // Mixed-case identifiers are quoted as declared, by default and with quoting turned off
+        assert_eq!(
+            line_item::Entity::find()
+                .filter(line_item::Column::UnitPrice.gt(10))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "LineItem"."Id", "LineItem"."UnitPrice" FROM "LineItem" WHERE "LineItem"."UnitPrice" > 10"#
+        );
+        assert_eq!(
+            order_line::Entity::find()
+                .filter(order_line::Column::UnitPrice.gt(10))
+                .filter(order_line::Column::ShippedAt.is_null())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT order_line.id, order_line.unit_price, order_line."ShippedAt" FROM order_line"#,
+                r#"WHERE order_line.unit_price > 10 AND order_line."ShippedAt" IS NULL"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            order_line::Entity::insert(order_line::ActiveModel {
+                unit_price: Set(12),
+                shipped_at: Set(None),
+                ..Default::default()
+            })
+            .build(DbBackend::MySql)
+            .to_string(),
+            "INSERT INTO order_line (unit_price, `ShippedAt`) VALUES (12, NULL)"
+        );
+        // sea-query keeps the columns of `UPDATE .. SET` as strings and always quotes them,
+        // which names the same column
+        assert_eq!(
+            order_line::Entity::update_many()
+                .col_expr(order_line::Column::UnitPrice, Expr::value(12))
+                .filter(order_line::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "UPDATE order_line SET `unit_price` = 12 WHERE order_line.id = 1"
+        );
+
+        let mut sql = String::new();
+        prepare_iden_quoted_if_needed("Odd\"name", &mut sql, '"');
+        prepare_iden_quoted_if_needed("1st", &mut sql, '"');
+        assert_eq!(sql, r#""Odd""name""1st""#);
+    }
+
+    #[test]
+    fn schema_qualified_statements() {
+        use crate::{
+            entity::*, query::*, tests_cfg::*, DbBackend, QueryTrait,
+        };
+        use rust_decimal_macros::dec;
+
+        assert_eq!(
+            cake_filling_price::Entity::find()
+                .filter(cake_filling_price::Column::CakeId.eq(1))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake_filling_price"."cake_id", "cake_filling_price"."filling_id", "cake_filling_price"."price""#,
+                r#"FROM "public"."cake_filling_price""#,
+                r#"WHERE "cake_filling_price"."cake_id" = 1"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            cake_filling_price::Entity::find()
+                .inner_join(cake_filling::Entity)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake_filling_price"."cake_id", "cake_filling_price"."filling_id", "cake_filling_price"."price""#,
+                r#"FROM "public"."cake_filling_price""#,
+                r#"INNER JOIN "cake_filling" ON"#,
+                r#"("cake_filling_price"."cake_id" = "cake_filling"."cake_id")"#,
+                r#"AND ("cake_filling_price"."filling_id" = "cake_filling"."filling_id")"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            cake_filling::Entity::find()
+                .inner_join(cake_filling_price::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id`",
+                "FROM `cake_filling`",
+                "INNER JOIN `public`.`cake_filling_price` ON",
+                "(`cake_filling`.`cake_id` = `cake_filling_price`.`cake_id`)",
+                "AND (`cake_filling`.`filling_id` = `cake_filling_price`.`filling_id`)",
+            ]
+            .join(" ")
+        );
+
+        let model = cake_filling_price::ActiveModel {
+            cake_id: Set(1),
+            filling_id: Set(2),
+            price: Set(dec!(3.5)),
+        };
+
+        assert_eq!(
+            cake_filling_price::Entity::insert(model.clone())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "public"."cake_filling_price" ("cake_id", "filling_id", "price") VALUES (1, 2, 3.5)"#
+        );
+
+        assert_eq!(
+            cake_filling_price::Entity::update(model.clone())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "public"."cake_filling_price" SET "price" = 3.5"#,
+                r#"WHERE "cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            cake_filling_price::Entity::delete(model)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"DELETE FROM "public"."cake_filling_price""#,
+                r#"WHERE "cake_filling_price"."cake_id" = 1 AND "cake_filling_price"."filling_id" = 2"#,
+            ]
+            .join(" ")
+        );
+    }
 }
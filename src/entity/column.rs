@@ -1,5 +1,9 @@
-use crate::{EntityName, IdenStatic, Iterable};
-use sea_query::{DynIden, Expr, SeaRc, SelectStatement, SimpleExpr, Value};
+use crate::{DbBackend, EntityName, IdenStatic, Iterable};
+use sea_query::{
+    BinOper, ColumnRef, DynIden, Expr, Function, Iden, PostgresQueryBuilder, QuotedBuilder, SeaRc,
+    SelectStatement, SimpleExpr, Value,
+};
+use std::fmt::Write;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -8,6 +12,9 @@ pub struct ColumnDef {
     pub(crate) null: bool,
     pub(crate) unique: bool,
     pub(crate) indexed: bool,
+    pub(crate) default_value: Option<Value>,
+    pub(crate) default_expr: Option<String>,
+    pub(crate) sensitive: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +26,12 @@ pub enum ColumnType {
     SmallInteger,
     Integer,
     BigInteger,
+    /// An unsigned 32-bit integer. Only MySQL has unsigned types,
+    /// the other backends store it as a `BIGINT` which holds every value
+    Unsigned,
+    /// An unsigned 64-bit integer. Only MySQL has unsigned types, the other backends
+    /// store it as a `BIGINT` and refuse to bind a value above `i64::MAX`
+    BigUnsigned,
     Float,
     Double,
     Decimal(Option<(u32, u32)>),
@@ -27,6 +40,8 @@ pub enum ColumnType {
     TimestampWithTimeZone,
     Time,
     Date,
+    /// A byte string of any length, held in a `Vec<u8>`. A `blob` on MySQL, a `bytea` on Postgres,
+    /// and a `binary` column on SQLite, which keeps the bytes as they are
     Binary,
     Boolean,
     Money(Option<(u32, u32)>),
@@ -34,6 +49,16 @@ pub enum ColumnType {
     JsonBinary,
     Custom(String),
     Uuid,
+    /// A native enum, with the name of its type and its values, declared by `db_type = "Enum"`
+    /// on [`DeriveActiveEnum`](crate::DeriveActiveEnum). An `ENUM(..)` column on MySQL and a
+    /// text column on SQLite; on Postgres a column of the type made by
+    /// [`Schema::create_enum_from_active_enum`](crate::Schema::create_enum_from_active_enum).
+    /// Values are written to it as by [`ColumnTrait::save_as`] and compared with it as text
+    Enum(String, Vec<String>),
+    /// A Postgres array of the element type, e.g. `integer[]`, read into a `Vec<T>`.
+    /// Binding a `Vec<T>` needs array values in sea-query, so write arrays in SQL,
+    /// e.g. with [`Expr::cust_with_values`](sea_query::Expr::cust_with_values)
+    Array(Box<ColumnType>),
 }
 
 macro_rules! bind_oper {
@@ -42,7 +67,7 @@ macro_rules! bind_oper {
         where
             V: Into<Value>,
         {
-            Expr::tbl(self.entity_name(), *self).$op(v)
+            Expr::expr(column_expr(self)).$op(v)
         }
     };
 }
@@ -63,7 +88,7 @@ macro_rules! bind_vec_func {
             V: Into<Value>,
             I: IntoIterator<Item = V>,
         {
-            Expr::tbl(self.entity_name(), *self).$func(v)
+            Expr::expr(column_expr(self)).$func(v)
         }
     };
 }
@@ -71,14 +96,27 @@ macro_rules! bind_vec_func {
 macro_rules! bind_subquery_func {
     ( $func: ident ) => {
         #[allow(clippy::wrong_self_convention)]
-        fn $func(&self, s: SelectStatement) -> SimpleExpr {
-            Expr::tbl(self.entity_name(), *self).$func(s)
+        fn $func<S>(&self, s: S) -> SimpleExpr
+        where
+            S: Into<SelectStatement>,
+        {
+            Expr::tbl(self.entity_name(), *self).$func(s.into())
         }
     };
 }
 
 // LINT: when the operand value does not match column type
 /// Wrapper of the identically named method in [`sea_query::Expr`]
+///
+/// Every column of an entity is listed, in declaration order, by [`Iterable::iter`]
+/// ```
+/// use sea_orm::{entity::prelude::*, tests_cfg::cake};
+///
+/// assert_eq!(
+///     cake::Column::iter().map(|col| col.as_str().to_owned()).collect::<Vec<_>>(),
+///     vec!["id", "name"]
+/// );
+/// ```
 pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     type EntityName: EntityName;
 
@@ -92,8 +130,85 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         (self.entity_name(), SeaRc::new(*self) as DynIden)
     }
 
-    bind_oper!(eq);
-    bind_oper!(ne);
+    /// The column qualified with its table, as quoted for `backend`, for composing
+    /// raw SQL without spelling out the column name
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Column::Name.quoted_column_ref(DbBackend::Postgres),
+    ///     r#""cake"."name""#
+    /// );
+    /// assert_eq!(
+    ///     cake::Column::Name.quoted_column_ref(DbBackend::MySql),
+    ///     "`cake`.`name`"
+    /// );
+    /// ```
+    fn quoted_column_ref(&self, backend: DbBackend) -> String {
+        format!(
+            "{}.{}",
+            backend.quote_iden(&Self::EntityName::default()),
+            backend.quote_iden(self)
+        )
+    }
+
+    /// The value as written into the column. A value of a native enum, see [`ColumnType::Enum`],
+    /// is cast to the enum type on Postgres, which takes no text for an enum by itself;
+    /// the other backends convert text on assignment, and the value is written as text there
+    fn save_as(&self, v: Value) -> SimpleExpr {
+        match self.def().col_type {
+            ColumnType::Enum(name, _) => {
+                let enum_type = SimpleExpr::Column(ColumnRef::Column(SeaRc::new(EnumType(name))));
+                SimpleExpr::FunctionCall(
+                    Function::Cast,
+                    vec![SimpleExpr::Binary(
+                        Box::new(SimpleExpr::Value(v)),
+                        BinOper::As,
+                        Box::new(enum_type),
+                    )],
+                )
+            }
+            _ => SimpleExpr::Value(v),
+        }
+    }
+
+    /// Comparing with a null value lowers to `IS NULL`
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.eq(Option::<String>::None))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` IS NULL"
+    /// );
+    /// ```
+    fn eq<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let v = v.into();
+        if is_null_value(&v) {
+            self.is_null()
+        } else {
+            Expr::expr(column_expr(self)).eq(v)
+        }
+    }
+
+    /// Comparing with a null value lowers to `IS NOT NULL`
+    fn ne<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let v = v.into();
+        if is_null_value(&v) {
+            self.is_not_null()
+        } else {
+            Expr::expr(column_expr(self)).ne(v)
+        }
+    }
+
     bind_oper!(gt);
     bind_oper!(gte);
     bind_oper!(lt);
@@ -114,7 +229,7 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     where
         V: Into<Value>,
     {
-        Expr::tbl(self.entity_name(), *self).between(a, b)
+        Expr::expr(column_expr(self)).between(a, b)
     }
 
     /// ```
@@ -132,7 +247,7 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     where
         V: Into<Value>,
     {
-        Expr::tbl(self.entity_name(), *self).not_between(a, b)
+        Expr::expr(column_expr(self)).not_between(a, b)
     }
 
     /// ```
@@ -147,7 +262,7 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     /// );
     /// ```
     fn like(&self, s: &str) -> SimpleExpr {
-        Expr::tbl(self.entity_name(), *self).like(s)
+        Expr::expr(column_expr(self)).like(s)
     }
 
     /// ```
@@ -162,9 +277,12 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     /// );
     /// ```
     fn not_like(&self, s: &str) -> SimpleExpr {
-        Expr::tbl(self.entity_name(), *self).not_like(s)
+        Expr::expr(column_expr(self)).not_like(s)
     }
 
+    /// Match values starting with `s`. Wildcards in `s` are matched literally,
+    /// see [`ColumnTrait::contains`].
+    ///
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     ///
@@ -177,10 +295,12 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     /// );
     /// ```
     fn starts_with(&self, s: &str) -> SimpleExpr {
-        let pattern = format!("{}%", s);
-        Expr::tbl(self.entity_name(), *self).like(&pattern)
+        like_literal(self, "{}%", s)
     }
 
+    /// Match values ending with `s`. Wildcards in `s` are matched literally,
+    /// see [`ColumnTrait::contains`].
+    ///
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     ///
@@ -193,10 +313,14 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     /// );
     /// ```
     fn ends_with(&self, s: &str) -> SimpleExpr {
-        let pattern = format!("%{}", s);
-        Expr::tbl(self.entity_name(), *self).like(&pattern)
+        like_literal(self, "%{}", s)
     }
 
+    /// Match values containing `s`.
+    ///
+    /// `%`, `_` and `\` in `s` are matched literally: they are escaped with `!`, which is
+    /// declared with `ESCAPE '!'`, as backslash is not an escape character on every backend.
+    ///
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     ///
@@ -207,10 +331,16 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     ///         .to_string(),
     ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'"
     /// );
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("50%"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%50!%%' ESCAPE '!'"
+    /// );
     /// ```
     fn contains(&self, s: &str) -> SimpleExpr {
-        let pattern = format!("%{}%", s);
-        Expr::tbl(self.entity_name(), *self).like(&pattern)
+        like_literal(self, "%{}%", s)
     }
 
     bind_agg_func!(max);
@@ -225,13 +355,260 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         Expr::tbl(self.entity_name(), *self).if_null(v)
     }
 
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.is_null())
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` IS NULL"
+    /// );
+    /// ```
+    fn is_null(&self) -> SimpleExpr {
+        Expr::tbl(self.entity_name(), *self).is_null()
+    }
+
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.is_not_null())
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` IS NOT NULL"
+    /// );
+    /// ```
+    fn is_not_null(&self) -> SimpleExpr {
+        Expr::tbl(self.entity_name(), *self).is_not_null()
+    }
+
     bind_vec_func!(is_in);
     bind_vec_func!(is_not_in);
 
+    /// Like [`ColumnTrait::is_in`], but on Postgres the list is bound as one array parameter,
+    /// `= ANY(CAST($1 AS integer[]))`, so the statement is the same whatever the list length.
+    /// Other backends, and lists of values without a Postgres array type, use `IN`.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let stmt = cake::Entity::find()
+    ///     .filter(cake::Column::Id.is_in_array(DbBackend::Postgres, vec![1, 2, 3]))
+    ///     .build(DbBackend::Postgres);
+    /// assert_eq!(
+    ///     stmt.sql,
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = ANY(CAST($1 AS integer[]))"#
+    /// );
+    /// assert_eq!(stmt.values.unwrap().0, vec!["{1,2,3}".into()]);
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Id.is_in_array(DbBackend::MySql, vec![1, 2, 3]))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` IN (1, 2, 3)"
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn is_in_array<V, I>(&self, db_backend: DbBackend, v: I) -> SimpleExpr
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        let values: Vec<Value> = v.into_iter().map(Into::into).collect();
+        match postgres_array(&values) {
+            Some((array_type, literal)) if db_backend == DbBackend::Postgres => SimpleExpr::Binary(
+                Box::new(SimpleExpr::Column(ColumnRef::TableColumn(
+                    self.entity_name(),
+                    SeaRc::new(*self) as DynIden,
+                ))),
+                BinOper::Equal,
+                Box::new(SimpleExpr::CustomWithValues(
+                    format!("ANY(CAST(? AS {}[]))", array_type),
+                    vec![literal.into()],
+                )),
+            ),
+            _ => Expr::expr(column_expr(self)).is_in(values),
+        }
+    }
+
     bind_subquery_func!(in_subquery);
     bind_subquery_func!(not_in_subquery);
 }
 
+/// The column as compared with a value, as text for a native enum, see [`ColumnType::Enum`]
+fn column_expr<C>(col: &C) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    let (table, column) = col.as_column_ref();
+    match col.def().col_type {
+        ColumnType::Enum(_, _) => {
+            SimpleExpr::Column(ColumnRef::Column(SeaRc::new(EnumAsText(table, column))))
+        }
+        _ => SimpleExpr::Column(ColumnRef::TableColumn(table, column)),
+    }
+}
+
+// sea-query writes a cast the same on every backend, but only Postgres needs a cast between
+// an enum and text. The identifiers below are written with the quote character of the backend,
+// and `"` is the one of Postgres, so they cast there and nowhere else.
+
+/// The type a native enum value is cast to in [`ColumnTrait::save_as`]
+#[derive(Debug)]
+struct EnumType(String);
+
+impl Iden for EnumType {
+    fn prepare(&self, s: &mut dyn Write, q: char) {
+        if q == PostgresQueryBuilder.quote() {
+            write!(s, "{}{}{}", q, self.0, q).unwrap();
+        } else {
+            write!(s, "CHAR").unwrap();
+        }
+    }
+
+    fn unquoted(&self, s: &mut dyn Write) {
+        write!(s, "{}", self.0).unwrap();
+    }
+}
+
+/// A native enum column, cast to text on Postgres to compare it with a value bound as text.
+/// MySQL and SQLite compare the column itself, which keeps the collation of the column
+#[derive(Debug)]
+struct EnumAsText(DynIden, DynIden);
+
+impl Iden for EnumAsText {
+    fn prepare(&self, s: &mut dyn Write, q: char) {
+        let cast = q == PostgresQueryBuilder.quote();
+        if cast {
+            write!(s, "CAST(").unwrap();
+        }
+        self.0.prepare(s, q);
+        write!(s, ".").unwrap();
+        self.1.prepare(s, q);
+        if cast {
+            write!(s, " AS text)").unwrap();
+        }
+    }
+
+    fn unquoted(&self, s: &mut dyn Write) {
+        write!(s, "{}.{}", self.0.to_string(), self.1.to_string()).unwrap();
+    }
+}
+
+/// `LIKE` with `s` put into `pattern`, where wildcards and backslashes of `s` are escaped
+fn like_literal<C>(col: &C, pattern: &str, s: &str) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    let left = column_expr(col);
+    if !s.contains(['!', '%', '_', '\\']) {
+        let pattern = pattern.replace("{}", s);
+        return Expr::expr(left).like(&pattern);
+    }
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '!' | '%' | '_') {
+            escaped.push('!');
+        }
+        escaped.push(c);
+    }
+    SimpleExpr::Binary(
+        Box::new(left),
+        BinOper::Like,
+        Box::new(SimpleExpr::CustomWithValues(
+            "? ESCAPE '!'".to_owned(),
+            vec![pattern.replace("{}", &escaped).into()],
+        )),
+    )
+}
+
+/// The Postgres element type and the text form of an array holding `values`,
+/// if they all have one type that Postgres arrays support
+fn postgres_array(values: &[Value]) -> Option<(&'static str, String)> {
+    fn quote(s: &str) -> String {
+        format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    let mut array_type = None;
+    let mut elements = Vec::with_capacity(values.len());
+    for value in values {
+        #[allow(unreachable_patterns)]
+        let (element_type, element) = match value {
+            Value::Bool(v) => ("boolean", v.map(|v| v.to_string())),
+            Value::TinyInt(v) => ("smallint", v.map(|v| v.to_string())),
+            Value::SmallInt(v) => ("smallint", v.map(|v| v.to_string())),
+            Value::Int(v) => ("integer", v.map(|v| v.to_string())),
+            Value::BigInt(v) => ("bigint", v.map(|v| v.to_string())),
+            Value::TinyUnsigned(v) => ("smallint", v.map(|v| v.to_string())),
+            Value::SmallUnsigned(v) => ("integer", v.map(|v| v.to_string())),
+            Value::Unsigned(v) => ("bigint", v.map(|v| v.to_string())),
+            Value::BigUnsigned(v) => ("numeric", v.map(|v| v.to_string())),
+            Value::Float(v) => ("real", v.map(|v| v.to_string())),
+            Value::Double(v) => ("double precision", v.map(|v| v.to_string())),
+            Value::String(v) => ("text", v.as_ref().map(|v| quote(v))),
+            #[cfg(feature = "with-chrono")]
+            Value::Date(v) => ("date", v.as_ref().map(|v| v.to_string())),
+            #[cfg(feature = "with-chrono")]
+            Value::Time(v) => ("time", v.as_ref().map(|v| v.to_string())),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTime(v) => ("timestamp", v.as_ref().map(|v| quote(&v.to_string()))),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTimeWithTimeZone(v) => {
+                ("timestamptz", v.as_ref().map(|v| quote(&v.to_rfc3339())))
+            }
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(v) => ("uuid", v.as_ref().map(|v| v.to_string())),
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(v) => ("numeric", v.as_ref().map(|v| v.to_string())),
+            _ => return None,
+        };
+        match array_type {
+            Some(array_type) if array_type != element_type => return None,
+            _ => array_type = Some(element_type),
+        }
+        elements.push(element.unwrap_or_else(|| "NULL".to_owned()));
+    }
+    array_type.map(|array_type| (array_type, format!("{{{}}}", elements.join(","))))
+}
+
+pub(crate) fn is_null_value(v: &Value) -> bool {
+    #[allow(unreachable_patterns)]
+    match v {
+        Value::Bool(v) => v.is_none(),
+        Value::TinyInt(v) => v.is_none(),
+        Value::SmallInt(v) => v.is_none(),
+        Value::Int(v) => v.is_none(),
+        Value::BigInt(v) => v.is_none(),
+        Value::TinyUnsigned(v) => v.is_none(),
+        Value::SmallUnsigned(v) => v.is_none(),
+        Value::Unsigned(v) => v.is_none(),
+        Value::BigUnsigned(v) => v.is_none(),
+        Value::Float(v) => v.is_none(),
+        Value::Double(v) => v.is_none(),
+        Value::String(v) => v.is_none(),
+        Value::Bytes(v) => v.is_none(),
+        #[cfg(feature = "with-json")]
+        Value::Json(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::Date(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::Time(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::DateTime(v) => v.is_none(),
+        #[cfg(feature = "with-chrono")]
+        Value::DateTimeWithTimeZone(v) => v.is_none(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => v.is_none(),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => v.is_none(),
+        _ => false,
+    }
+}
+
 impl ColumnType {
     pub fn def(self) -> ColumnDef {
         ColumnDef {
@@ -239,11 +616,24 @@ impl ColumnType {
             null: false,
             unique: false,
             indexed: false,
+            default_value: None,
+            default_expr: None,
+            sensitive: false,
         }
     }
 }
 
 impl ColumnDef {
+    /// The type of the column
+    pub fn get_column_type(&self) -> &ColumnType {
+        &self.col_type
+    }
+
+    /// Whether the column is nullable
+    pub fn is_null(&self) -> bool {
+        self.null
+    }
+
     pub fn unique(mut self) -> Self {
         self.unique = true;
         self
@@ -262,6 +652,36 @@ impl ColumnDef {
         self.indexed = true;
         self
     }
+
+    /// The value the database fills the column with when an insert omits it
+    pub fn default_value<T>(mut self, value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    pub fn default<T>(self, value: T) -> Self
+    where
+        T: Into<Value>,
+    {
+        self.default_value(value)
+    }
+
+    /// An SQL expression the database evaluates to fill the column when an
+    /// insert omits it, e.g. `CURRENT_TIMESTAMP`
+    pub fn default_expr(mut self, expr: &str) -> Self {
+        self.default_expr = Some(expr.to_owned());
+        self
+    }
+
+    /// Redact the values of the column in logged statements, such as password hashes
+    /// or tokens, see [`Statement::with_sensitive_values`](crate::Statement::with_sensitive_values)
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
 }
 
 impl From<ColumnType> for sea_query::ColumnType {
@@ -274,6 +694,8 @@ impl From<ColumnType> for sea_query::ColumnType {
             ColumnType::SmallInteger => sea_query::ColumnType::SmallInteger(None),
             ColumnType::Integer => sea_query::ColumnType::Integer(None),
             ColumnType::BigInteger => sea_query::ColumnType::BigInteger(None),
+            ColumnType::Unsigned => sea_query::ColumnType::BigInteger(None),
+            ColumnType::BigUnsigned => sea_query::ColumnType::BigInteger(None),
             ColumnType::Float => sea_query::ColumnType::Float(None),
             ColumnType::Double => sea_query::ColumnType::Double(None),
             ColumnType::Decimal(s) => sea_query::ColumnType::Decimal(s),
@@ -291,6 +713,19 @@ impl From<ColumnType> for sea_query::ColumnType {
                 sea_query::ColumnType::Custom(sea_query::SeaRc::new(sea_query::Alias::new(&s)))
             }
             ColumnType::Uuid => sea_query::ColumnType::Uuid,
+            // the type of Postgres, see `Schema::create_table_from_entity_for_backend` for the others
+            ColumnType::Enum(name, _) => sea_query::ColumnType::Custom(sea_query::SeaRc::new(
+                sea_query::Alias::new(&format!("\"{}\"", name)),
+            )),
+            ColumnType::Array(elem) => {
+                use sea_query::TableBuilder;
+
+                let mut elem_type = sea_query::SqlWriter::new();
+                sea_query::PostgresQueryBuilder.prepare_column_type(&(*elem).into(), &mut elem_type);
+                sea_query::ColumnType::Custom(sea_query::SeaRc::new(sea_query::Alias::new(
+                    &format!("{}[]", elem_type.result()),
+                )))
+            }
         }
     }
 }
@@ -329,10 +764,59 @@ impl From<sea_query::ColumnType> for ColumnType {
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests_cfg::*, ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QueryTrait,
+        tests_cfg::*, ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QuerySelect,
+        QueryTrait,
     };
     use sea_query::Query;
 
+    #[test]
+    fn test_quoted_refs() {
+        use crate::EntityName;
+        use sea_query::Alias;
+
+        let cases = [
+            (
+                DbBackend::MySql,
+                "`cake`",
+                "`cake`.`name`",
+                "`public`.`cake_filling_price`",
+            ),
+            (
+                DbBackend::Postgres,
+                r#""cake""#,
+                r#""cake"."name""#,
+                r#""public"."cake_filling_price""#,
+            ),
+            (
+                DbBackend::Sqlite,
+                "`cake`",
+                "`cake`.`name`",
+                "`public`.`cake_filling_price`",
+            ),
+        ];
+        for (backend, table, column, schema_table) in cases {
+            assert_eq!(cake::Entity.quoted_table_ref(backend), table);
+            assert_eq!(cake::Column::Name.quoted_column_ref(backend), column);
+            assert_eq!(
+                cake_filling_price::Entity.quoted_table_ref(backend),
+                schema_table
+            );
+        }
+
+        assert_eq!(DbBackend::MySql.quote_iden(&Alias::new("a`b")), "`a``b`");
+        assert_eq!(
+            DbBackend::Postgres.quote_iden(&Alias::new(r#"a"b"#)),
+            r#""a""b""#
+        );
+
+        let sql = format!(
+            "SELECT {} FROM {}",
+            cake::Column::Name.quoted_column_ref(DbBackend::Postgres),
+            cake::Entity.quoted_table_ref(DbBackend::Postgres)
+        );
+        assert_eq!(sql, r#"SELECT "cake"."name" FROM "cake""#);
+    }
+
     #[test]
     fn test_in_subquery_1() {
         assert_eq!(
@@ -381,6 +865,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_in_subquery_3() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(
+                    cake::Column::Id.not_in_subquery(
+                        fruit::Entity::find()
+                            .select_only()
+                            .column(fruit::Column::CakeId)
+                            .filter(fruit::Column::Name.contains("apple"))
+                    )
+                )
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE "cake"."id" NOT IN"#,
+                r#"(SELECT "fruit"."cake_id" FROM "fruit" WHERE "fruit"."name" LIKE '%apple%')"#,
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn test_col_from_str() {
         use std::str::FromStr;
@@ -407,6 +914,79 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn column_name_1() {
+        use crate as sea_orm;
+        use crate::{DeriveColumn, IdenStatic};
+        use std::str::FromStr;
+
+        #[derive(Copy, Clone, Debug, DeriveColumn)]
+        enum Column {
+            Id,
+            #[sea_orm(column_name = "orderId")]
+            OrderId,
+            #[sea_orm(column_name = "qty")]
+            Quantity,
+        }
+
+        assert_eq!(Column::Id.as_str(), "id");
+        assert_eq!(Column::OrderId.as_str(), "orderId");
+        assert_eq!(Column::Quantity.as_str(), "qty");
+        assert_eq!(sea_query::Iden::to_string(&Column::Quantity), "qty");
+
+        assert!(matches!(Column::from_str("id"), Ok(Column::Id)));
+        assert!(matches!(Column::from_str("orderId"), Ok(Column::OrderId)));
+        assert!(matches!(Column::from_str("order_id"), Ok(Column::OrderId)));
+        assert!(matches!(Column::from_str("qty"), Ok(Column::Quantity)));
+        assert!(matches!(Column::from_str("quantity"), Ok(Column::Quantity)));
+        assert!(matches!(
+            Column::from_str("does_not_exist"),
+            Err(crate::ColumnFromStrErr(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn column_name_2() {
+        use std::str::FromStr;
+
+        mod hello {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "hello")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(column_name = "lineItemCount")]
+                pub line_items: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            crate::IdenStatic::as_str(&hello::Column::LineItems),
+            "lineItemCount"
+        );
+        assert!(matches!(
+            hello::Column::from_str("lineItemCount"),
+            Ok(hello::Column::LineItems)
+        ));
+        assert_eq!(
+            hello::Entity::find()
+                .filter(hello::Column::LineItems.gt(1))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "hello"."id", "hello"."lineItemCount" FROM "hello" WHERE "hello"."lineItemCount" > 1"#
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn entity_model_column_1() {
@@ -459,4 +1039,284 @@ mod tests {
             ColumnType::Integer.def().unique().indexed().nullable()
         );
     }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn entity_model_matches_expanded() {
+        use crate::entity::*;
+
+        mod derived {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "lineitem")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                #[sea_orm(column_type = "Decimal(Some((19, 4)))")]
+                pub price: Decimal,
+                pub quantity: i32,
+                #[sea_orm(nullable)]
+                pub note: Option<String>,
+                pub order_id: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        mod expanded {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+            pub struct Entity;
+
+            impl EntityName for Entity {
+                fn table_name(&self) -> &str {
+                    "lineitem"
+                }
+            }
+
+            #[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel)]
+            pub struct Model {
+                pub id: i32,
+                pub price: Decimal,
+                pub quantity: i32,
+                pub note: Option<String>,
+                pub order_id: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+            pub enum Column {
+                Id,
+                Price,
+                Quantity,
+                Note,
+                OrderId,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+            pub enum PrimaryKey {
+                Id,
+            }
+
+            impl PrimaryKeyTrait for PrimaryKey {
+                type ValueType = i32;
+
+                fn auto_increment() -> bool {
+                    true
+                }
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter)]
+            pub enum Relation {}
+
+            impl ColumnTrait for Column {
+                type EntityName = Entity;
+
+                fn def(&self) -> ColumnDef {
+                    match self {
+                        Self::Id => ColumnType::Integer.def(),
+                        Self::Price => ColumnType::Decimal(Some((19, 4))).def(),
+                        Self::Quantity => ColumnType::Integer.def(),
+                        Self::Note => ColumnType::String(None).def().null(),
+                        Self::OrderId => ColumnType::Integer.def(),
+                    }
+                }
+            }
+
+            impl RelationTrait for Relation {
+                fn def(&self) -> RelationDef {
+                    panic!("No RelationDef")
+                }
+            }
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(derived::Entity.table_name(), expanded::Entity.table_name());
+        assert_eq!(
+            derived::Column::iter().count(),
+            expanded::Column::iter().count()
+        );
+        for (derived, expanded) in derived::Column::iter().zip(expanded::Column::iter()) {
+            assert_eq!(derived.as_str(), expanded.as_str());
+            assert_eq!(derived.def(), expanded.def());
+        }
+        assert_eq!(
+            derived::PrimaryKey::iter()
+                .map(|pk| pk.into_column().as_str().to_owned())
+                .collect::<Vec<_>>(),
+            expanded::PrimaryKey::iter()
+                .map(|pk| pk.into_column().as_str().to_owned())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            <derived::PrimaryKey as PrimaryKeyTrait>::auto_increment(),
+            <expanded::PrimaryKey as PrimaryKeyTrait>::auto_increment()
+        );
+    }
+
+    #[test]
+    fn comparison_operators() {
+        use sea_query::Expr;
+
+        let sql = |expr: sea_query::SimpleExpr| {
+            Query::select()
+                .expr(Expr::val(1))
+                .and_where(expr)
+                .to_string(sea_query::MysqlQueryBuilder)
+                .replace("SELECT 1 WHERE ", "")
+        };
+
+        assert_eq!(sql(fruit::Column::Id.eq(1)), "`fruit`.`id` = 1");
+        assert_eq!(sql(fruit::Column::Id.ne(1)), "`fruit`.`id` <> 1");
+        assert_eq!(sql(fruit::Column::Id.gt(1)), "`fruit`.`id` > 1");
+        assert_eq!(sql(fruit::Column::Id.gte(1)), "`fruit`.`id` >= 1");
+        assert_eq!(sql(fruit::Column::Id.lt(1)), "`fruit`.`id` < 1");
+        assert_eq!(sql(fruit::Column::Id.lte(1)), "`fruit`.`id` <= 1");
+        assert_eq!(
+            sql(fruit::Column::Id.between(1, 3)),
+            "`fruit`.`id` BETWEEN 1 AND 3"
+        );
+        assert_eq!(
+            sql(fruit::Column::Name.like("App_e")),
+            "`fruit`.`name` LIKE 'App_e'"
+        );
+        assert_eq!(
+            sql(fruit::Column::Name.not_like("App_e")),
+            "`fruit`.`name` NOT LIKE 'App_e'"
+        );
+        assert_eq!(
+            sql(fruit::Column::CakeId.is_null()),
+            "`fruit`.`cake_id` IS NULL"
+        );
+        assert_eq!(
+            sql(fruit::Column::CakeId.is_not_null()),
+            "`fruit`.`cake_id` IS NOT NULL"
+        );
+        assert_eq!(
+            sql(fruit::Column::Id.is_in(vec![1, 2])),
+            "`fruit`.`id` IN (1, 2)"
+        );
+        assert_eq!(
+            sql(fruit::Column::Id.is_not_in(vec![1, 2])),
+            "`fruit`.`id` NOT IN (1, 2)"
+        );
+    }
+
+    #[test]
+    fn is_in_empty() {
+        // An empty list matches nothing, and excludes nothing, instead of being invalid SQL
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::Id.is_in(Vec::<i32>::new()))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE 1 = 2"#
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::Id.is_not_in(Vec::<i32>::new()))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE 1 = 1"#
+        );
+    }
+
+    #[test]
+    fn like_escapes_wildcards() {
+        let sql = |expr: sea_query::SimpleExpr| {
+            cake::Entity::find()
+                .filter(expr)
+                .build(DbBackend::Postgres)
+                .to_string()
+                .replace(r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "#, "")
+        };
+
+        assert_eq!(
+            sql(cake::Column::Name.contains("50%")),
+            r#""cake"."name" LIKE '%50!%%' ESCAPE '!'"#
+        );
+        assert_eq!(
+            sql(cake::Column::Name.starts_with("snake_case")),
+            r#""cake"."name" LIKE 'snake!_case%' ESCAPE '!'"#
+        );
+        assert_eq!(
+            sql(cake::Column::Name.ends_with("wow!")),
+            r#""cake"."name" LIKE '%wow!!' ESCAPE '!'"#
+        );
+        assert_eq!(
+            sql(cake::Column::Name.contains(r"C:\cakes")),
+            r#""cake"."name" LIKE E'%C:\\cakes%' ESCAPE '!'"#
+        );
+
+        let stmt = cake::Entity::find()
+            .filter(cake::Column::Name.contains("100%_"))
+            .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE $1 ESCAPE '!'"#
+        );
+        assert_eq!(
+            stmt.values.unwrap().0,
+            vec![sea_query::Value::String(Some(Box::new("%100!%!_%".to_owned())))]
+        );
+    }
+
+    #[test]
+    fn is_in_array() {
+        let build = |db_backend: DbBackend, expr: sea_query::SimpleExpr| {
+            fruit::Entity::find().filter(expr).build(db_backend)
+        };
+
+        let stmt = build(
+            DbBackend::Postgres,
+            fruit::Column::Name.is_in_array(DbBackend::Postgres, vec!["Apple", r#"Say "hi""#]),
+        );
+        assert_eq!(
+            stmt.sql,
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."name" = ANY(CAST($1 AS text[]))"#
+        );
+        assert_eq!(
+            stmt.values.unwrap().0,
+            vec![r#"{"Apple","Say \"hi\""}"#.into()]
+        );
+
+        let stmt = build(
+            DbBackend::Postgres,
+            fruit::Column::CakeId.is_in_array(DbBackend::Postgres, vec![Some(1), None]),
+        );
+        assert_eq!(stmt.values.unwrap().0, vec!["{1,NULL}".into()]);
+
+        // Falls back to `IN` on other backends, for an empty list and for bytes
+        assert_eq!(
+            build(
+                DbBackend::Sqlite,
+                fruit::Column::Id.is_in_array(DbBackend::Sqlite, vec![1, 2])
+            )
+            .to_string(),
+            "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` WHERE `fruit`.`id` IN (1, 2)"
+        );
+        assert_eq!(
+            build(
+                DbBackend::Postgres,
+                fruit::Column::Id.is_in_array(DbBackend::Postgres, Vec::<i32>::new())
+            )
+            .to_string(),
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE 1 = 2"#
+        );
+        assert_eq!(
+            build(
+                DbBackend::Postgres,
+                fruit::Column::Name.is_in_array(DbBackend::Postgres, vec![vec![1u8]])
+            )
+            .sql,
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."name" IN ($1)"#
+        );
+    }
 }
@@ -0,0 +1,75 @@
+use crate::{ColumnDef, ColumnTrait, EntityTrait, IdenStatic, Iterable, PrimaryKeyToColumn};
+use std::collections::BTreeMap;
+
+/// The table of an entity as its definition declares it, from [`EntityTrait::describe`].
+///
+/// Compare it against a schema known to be right, e.g. one introspected from the
+/// live database in CI, to catch an entity drifting apart from its table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityDescription {
+    pub schema_name: Option<String>,
+    pub table_name: String,
+    /// The definition of every column, by name
+    pub columns: BTreeMap<String, ColumnDef>,
+    /// The names of the primary key columns, in order
+    pub primary_key: Vec<String>,
+}
+
+impl EntityDescription {
+    pub(crate) fn new<E>(entity: E) -> Self
+    where
+        E: EntityTrait,
+    {
+        Self {
+            schema_name: entity.schema_name().map(ToOwned::to_owned),
+            table_name: entity.table_name().to_owned(),
+            columns: E::Column::iter()
+                .map(|column| (column.as_str().to_owned(), column.def()))
+                .collect(),
+            primary_key: E::PrimaryKey::iter()
+                .map(|key| key.into_column().as_str().to_owned())
+                .collect(),
+        }
+    }
+
+    /// The definition of the column `name`
+    pub fn column(&self, name: &str) -> Option<&ColumnDef> {
+        self.columns.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake_filling, fruit};
+    use crate::{ColumnType, EntityDescription, EntityTrait};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn describe_entity() {
+        assert_eq!(
+            fruit::Entity::describe(),
+            EntityDescription {
+                schema_name: None,
+                table_name: "fruit".to_owned(),
+                columns: vec![
+                    ("id".to_owned(), ColumnType::Integer.def()),
+                    ("name".to_owned(), ColumnType::String(None).def()),
+                    ("cake_id".to_owned(), ColumnType::Integer.def().null()),
+                ]
+                .into_iter()
+                .collect(),
+                primary_key: vec!["id".to_owned()],
+            }
+        );
+
+        let description = cake_filling::Entity::describe();
+        assert_eq!(description.primary_key, vec!["cake_id", "filling_id"]);
+        assert_eq!(
+            description
+                .column("filling_id")
+                .map(|def| def.get_column_type()),
+            Some(&ColumnType::Integer)
+        );
+        assert_eq!(description.column("price"), None);
+    }
+}
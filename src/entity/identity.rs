@@ -28,6 +28,24 @@ impl Iden for Identity {
     }
 }
 
+/// Write an identifier the way entities declared with `#[sea_orm(quote_identifiers = false)]` do:
+/// bare if it is a plain lowercase identifier such as `line_item`, which every backend reads
+/// as is, and quoted otherwise. Mixed-case names like `LineItem` keep their quotes, as Postgres
+/// would fold them to lowercase without. Reserved words such as `user` or `order` are not
+/// recognized, so entities named like one should keep the default of quoting everything.
+/// The columns set by an `UPDATE` are always quoted by sea-query, which names the same column.
+pub fn prepare_iden_quoted_if_needed(iden: &str, s: &mut dyn fmt::Write, q: char) {
+    let mut chars = iden.chars();
+    let plain = matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        write!(s, "{}", iden).unwrap();
+    } else {
+        let escaped = iden.replace(q, &q.to_string().repeat(2));
+        write!(s, "{}{}{}", q, escaped, q).unwrap();
+    }
+}
+
 pub trait IntoIdentity {
     fn into_identity(self) -> Identity;
 }
@@ -3,6 +3,24 @@ use sea_query::JoinType;
 
 pub type LinkDef = RelationDef;
 
+/// A chain of relations from one entity to another, joined in order by `find_linked`.
+///
+/// A many-to-many relation through a junction table can be derived with `DeriveLinked`,
+/// naming both entities and the junction's `belongs_to` relation to each of them:
+///
+/// ```
+/// # use sea_orm::tests_cfg::{cake_filling, filling};
+/// # use sea_orm::entity::prelude::*;
+/// # type Entity = sea_orm::tests_cfg::cake::Entity;
+/// #[derive(Debug, DeriveLinked)]
+/// #[sea_orm(
+///     from = "Entity",
+///     to = "filling::Entity",
+///     via_from = "cake_filling::Relation::Cake",
+///     via_to = "cake_filling::Relation::Filling"
+/// )]
+/// pub struct CakeToFilling;
+/// ```
 pub trait Linked {
     type FromEntity: EntityTrait;
 
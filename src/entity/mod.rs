@@ -1,6 +1,8 @@
+mod active_enum;
 mod active_model;
 mod base_entity;
 mod column;
+mod description;
 mod identity;
 mod link;
 mod model;
@@ -8,9 +10,11 @@ pub mod prelude;
 mod primary_key;
 mod relation;
 
+pub use active_enum::*;
 pub use active_model::*;
 pub use base_entity::*;
 pub use column::*;
+pub use description::*;
 pub use identity::*;
 pub use link::*;
 pub use model::*;
@@ -1,10 +1,14 @@
 use crate::{
-    DbErr, EntityTrait, Linked, QueryFilter, QueryResult, Related, Select, SelectModel,
+    primary_key_value_tuple, ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr,
+    DeleteResult, EntityTrait, FromValueTuple, IntoActiveModel, Iterable, Linked,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryResult, QuerySelect, Related, Select, SelectModel,
     SelectorRaw, Statement,
 };
+use async_trait::async_trait;
 pub use sea_query::Value;
 use std::fmt::Debug;
 
+#[async_trait]
 pub trait ModelTrait: Clone + Send + Debug {
     type Entity: EntityTrait;
 
@@ -12,6 +16,83 @@ pub trait ModelTrait: Clone + Send + Debug {
 
     fn set(&mut self, c: <Self::Entity as EntityTrait>::Column, v: Value);
 
+    /// Serialize the model to a JSON object keyed by column name, with `None` as `null`.
+    /// The output is accepted by `ActiveModelTrait::from_json`.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::fruit};
+    /// use serde_json::json;
+    ///
+    /// let apple = fruit::Model {
+    ///     id: 1,
+    ///     name: "Apple".to_owned(),
+    ///     cake_id: None,
+    /// };
+    /// assert_eq!(
+    ///     apple.to_json(),
+    ///     json!({ "id": 1, "name": "Apple", "cake_id": null })
+    /// );
+    /// ```
+    #[cfg(feature = "with-json")]
+    fn to_json(&self) -> serde_json::Value {
+        use crate::{IdenStatic, Iterable};
+
+        serde_json::Value::Object(
+            <<Self::Entity as EntityTrait>::Column as Iterable>::iter()
+                .map(|col| (col.as_str().to_owned(), value_to_json(self.get(col))))
+                .collect(),
+        )
+    }
+
+    /// Get the primary key of the model, as a tuple for a composite key
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, tests_cfg::{cake, cake_filling}};
+    ///
+    /// let cake = cake::Model {
+    ///     id: 1,
+    ///     name: "Chocolate Forest".to_owned(),
+    /// };
+    /// assert_eq!(cake.get_primary_key_value(), 1);
+    ///
+    /// let cake_filling = cake_filling::Model {
+    ///     cake_id: 1,
+    ///     filling_id: 2,
+    /// };
+    /// assert_eq!(cake_filling.get_primary_key_value(), (1, 2));
+    /// ```
+    fn get_primary_key_value(
+        &self,
+    ) -> <<Self::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType {
+        let values = <<Self::Entity as EntityTrait>::PrimaryKey as Iterable>::iter()
+            .map(|key| self.get(key.into_column()))
+            .collect();
+        FromValueTuple::from_value_tuple(primary_key_value_tuple(values))
+    }
+
+    /// Select the models related to this one, joined on the relation and filtered by the
+    /// primary key of this model. Many-to-many relations join through the junction table.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, filling, fruit}, DbBackend};
+    ///
+    /// let cake = cake::Model {
+    ///     id: 1,
+    ///     name: "Chocolate Forest".to_owned(),
+    /// };
+    /// assert_eq!(
+    ///     cake.find_related(fruit::Entity)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" INNER JOIN "cake" ON "cake"."id" = "fruit"."cake_id" WHERE "cake"."id" = 1"#
+    /// );
+    /// assert_eq!(
+    ///     cake.find_related(filling::Entity)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "filling"."id", "filling"."name" FROM "filling" INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id" INNER JOIN "cake" ON "cake"."id" = "cake_filling"."cake_id" WHERE "cake"."id" = 1"#
+    /// );
+    /// ```
     fn find_related<R>(&self, _: R) -> Select<R>
     where
         R: EntityTrait,
@@ -20,12 +101,76 @@ pub trait ModelTrait: Clone + Send + Debug {
         <Self::Entity as Related<R>>::find_related().belongs_to(self)
     }
 
+    /// Select the models reached from this one through the chain of relations of `l`
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let cake = cake::Model {
+    ///     id: 1,
+    ///     name: "Chocolate Forest".to_owned(),
+    /// };
+    /// assert_eq!(
+    ///     cake.find_linked(cake::CakeToFilling)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "filling"."id", "filling"."name" FROM "filling" INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id" INNER JOIN "cake" ON "cake"."id" = "cake_filling"."cake_id" WHERE "cake"."id" = 1"#
+    /// );
+    /// ```
     fn find_linked<L>(&self, l: L) -> Select<L::ToEntity>
     where
         L: Linked<FromEntity = Self::Entity>,
     {
         l.find_linked().belongs_to(self)
     }
+
+    /// Delete the model by its primary key, see [`ActiveModelTrait::delete`]
+    async fn delete<'a, A, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
+    where
+        Self: IntoActiveModel<A>,
+        C: ConnectionTrait,
+        A: ActiveModelTrait<Entity = Self::Entity> + ActiveModelBehavior + Send + 'a,
+    {
+        self.into_active_model().delete(db).await
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn value_to_json(value: Value) -> serde_json::Value {
+    use serde_json::Value as Json;
+
+    match value {
+        Value::Bool(v) => v.into(),
+        Value::TinyInt(v) => v.into(),
+        Value::SmallInt(v) => v.into(),
+        Value::Int(v) => v.into(),
+        Value::BigInt(v) => v.into(),
+        Value::TinyUnsigned(v) => v.into(),
+        Value::SmallUnsigned(v) => v.into(),
+        Value::Unsigned(v) => v.into(),
+        Value::BigUnsigned(v) => v.into(),
+        Value::Float(v) => v.into(),
+        Value::Double(v) => v.into(),
+        Value::String(v) => v.map(|v| *v).into(),
+        Value::Bytes(v) => v.map(|v| *v).into(),
+        Value::Json(v) => v.map(|v| *v).unwrap_or(Json::Null),
+        #[cfg(feature = "with-chrono")]
+        Value::Date(v) => v.map(|v| v.to_string()).into(),
+        #[cfg(feature = "with-chrono")]
+        Value::Time(v) => v.map(|v| v.to_string()).into(),
+        #[cfg(feature = "with-chrono")]
+        Value::DateTime(v) => v
+            .map(|v| v.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+            .into(),
+        #[cfg(feature = "with-chrono")]
+        Value::DateTimeWithTimeZone(v) => v.map(|v| v.to_rfc3339()).into(),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(v) => v.map(|v| v.to_string()).into(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(v) => v.map(|v| v.to_string()).into(),
+        #[allow(unreachable_patterns)]
+        _ => unimplemented!(),
+    }
 }
 
 pub trait FromQueryResult: Sized {
@@ -100,3 +245,39 @@ pub trait FromQueryResult: Sized {
         SelectorRaw::<SelectModel<Self>>::from_statement(stmt)
     }
 }
+
+/// A struct fetching only some columns of an entity, or of the entities joined to it, e.g. for
+/// a list view that does not need whole models. Select it with [`Select::into_partial_model`].
+///
+/// Derive it with [`DerivePartialModel`](crate::DerivePartialModel), which also derives
+/// [`FromQueryResult`]. A field selects the column of the same name of the `entity` of the
+/// struct, or the expression given with `from_expr`, aliased as the field.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend, DerivePartialModel};
+/// use sea_orm::tests_cfg::fruit::Entity as Fruit;
+///
+/// #[derive(DerivePartialModel)]
+/// #[sea_orm(entity = Fruit)]
+/// struct FruitRow {
+///     id: i32,
+///     #[sea_orm(from_expr = "cake::Column::Name")]
+///     cake_name: String,
+/// }
+///
+/// assert_eq!(
+///     FruitRow::select_cols(fruit::Entity::find().select_only())
+///         .inner_join(cake::Entity)
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     [
+///         r#"SELECT "fruit"."id" AS "id", "cake"."name" AS "cake_name" FROM "fruit""#,
+///         r#"INNER JOIN "cake" ON "fruit"."cake_id" = "cake"."id""#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub trait PartialModelTrait: FromQueryResult {
+    /// Add the columns of the struct to the select, each aliased as its field
+    fn select_cols<S: QuerySelect>(select: S) -> S;
+}
@@ -1,6 +1,6 @@
 use super::{ColumnTrait, IdenStatic, Iterable};
 use crate::{TryFromU64, TryGetableMany};
-use sea_query::IntoValueTuple;
+use sea_query::{IntoValueTuple, Value, ValueTuple, ValueType};
 use std::fmt::Debug;
 
 //LINT: composite primary key cannot auto increment
@@ -11,6 +11,7 @@ pub trait PrimaryKeyTrait: IdenStatic + Iterable {
         + Debug
         + PartialEq
         + IntoValueTuple
+        + FromValueTuple
         + TryGetableMany
         + TryFromU64;
 
@@ -26,3 +27,73 @@ pub trait PrimaryKeyToColumn {
     where
         Self: Sized;
 }
+
+/// Group the values of the primary key columns, in key order, into a [`ValueTuple`]
+pub(crate) fn primary_key_value_tuple(values: Vec<Value>) -> ValueTuple {
+    let mut values = values.into_iter();
+    match (values.next(), values.next(), values.next(), values.next()) {
+        (Some(a), None, None, None) => ValueTuple::One(a),
+        (Some(a), Some(b), None, None) => ValueTuple::Two(a, b),
+        (Some(a), Some(b), Some(c), None) => ValueTuple::Three(a, b, c),
+        _ => panic!("a primary key must have one to three columns"),
+    }
+}
+
+/// Rebuild a primary key value from the values of its columns
+pub trait FromValueTuple: Sized {
+    /// Panics if the arity or value types do not match
+    fn from_value_tuple(tuple: ValueTuple) -> Self;
+}
+
+macro_rules! from_value_tuple {
+    ( $type: ty ) => {
+        impl FromValueTuple for $type {
+            fn from_value_tuple(tuple: ValueTuple) -> Self {
+                match tuple {
+                    ValueTuple::One(v) => v.unwrap(),
+                    _ => panic!("expected one value for {}", stringify!($type)),
+                }
+            }
+        }
+    };
+}
+
+from_value_tuple!(i8);
+from_value_tuple!(i16);
+from_value_tuple!(i32);
+from_value_tuple!(i64);
+from_value_tuple!(u8);
+from_value_tuple!(u16);
+from_value_tuple!(u32);
+from_value_tuple!(u64);
+from_value_tuple!(String);
+
+#[cfg(feature = "with-uuid")]
+from_value_tuple!(uuid::Uuid);
+
+impl<A, B> FromValueTuple for (A, B)
+where
+    A: ValueType,
+    B: ValueType,
+{
+    fn from_value_tuple(tuple: ValueTuple) -> Self {
+        match tuple {
+            ValueTuple::Two(a, b) => (a.unwrap(), b.unwrap()),
+            _ => panic!("expected two values"),
+        }
+    }
+}
+
+impl<A, B, C> FromValueTuple for (A, B, C)
+where
+    A: ValueType,
+    B: ValueType,
+    C: ValueType,
+{
+    fn from_value_tuple(tuple: ValueTuple) -> Self {
+        match tuple {
+            ValueTuple::Three(a, b, c) => (a.unwrap(), b.unwrap(), c.unwrap()),
+            _ => panic!("expected three values"),
+        }
+    }
+}
@@ -153,3 +153,143 @@ where
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "macros")]
+mod tests {
+    use crate::entity::*;
+
+    mod user {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "user")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_one = "super::profile::Entity")]
+            Profile,
+            #[sea_orm(has_many = "super::post::Entity")]
+            Post,
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod profile {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "profile")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub user_id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::user::Entity",
+                from = "Column::UserId",
+                to = "super::user::Column::Id",
+                on_update = "Cascade",
+                on_delete = "SetNull"
+            )]
+            User,
+        }
+
+        impl Related<super::user::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::User.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub user_id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::user::Entity",
+                from = "Column::UserId",
+                to = "super::user::Column::Id"
+            )]
+            User,
+        }
+
+        impl Related<super::user::Entity> for Entity {
+            fn to() -> RelationDef {
+                Relation::User.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn assert_relation_def_eq(left: RelationDef, right: RelationDef) {
+        assert_eq!(format!("{:?}", left), format!("{:?}", right));
+    }
+
+    #[test]
+    fn derive_relation_belongs_to() {
+        assert_relation_def_eq(
+            profile::Relation::User.def(),
+            profile::Entity::belongs_to(user::Entity)
+                .from(profile::Column::UserId)
+                .to(user::Column::Id)
+                .on_update(ForeignKeyAction::Cascade)
+                .on_delete(ForeignKeyAction::SetNull)
+                .into(),
+        );
+        assert_relation_def_eq(
+            post::Relation::User.def(),
+            post::Entity::belongs_to(user::Entity)
+                .from(post::Column::UserId)
+                .to(user::Column::Id)
+                .into(),
+        );
+    }
+
+    #[test]
+    fn derive_relation_has_one() {
+        assert_relation_def_eq(
+            user::Relation::Profile.def(),
+            user::Entity::has_one(profile::Entity).into(),
+        );
+        assert!(matches!(
+            user::Relation::Profile.def().rel_type,
+            RelationType::HasOne
+        ));
+    }
+
+    #[test]
+    fn derive_relation_has_many() {
+        assert_relation_def_eq(
+            user::Relation::Post.def(),
+            user::Entity::has_many(post::Entity).into(),
+        );
+        assert!(matches!(
+            user::Relation::Post.def().rel_type,
+            RelationType::HasMany
+        ));
+    }
+}
@@ -1,18 +1,146 @@
+use std::sync::Arc;
+
 #[derive(Debug, PartialEq)]
 pub enum DbErr {
     Conn(String),
+    ConnectionAcquire(ConnAcquireErr),
     Exec(String),
     Query(String),
+    /// A query expected to return a row returned none
+    RecordNotFound(String),
+    /// A query expected to return at most one row returned more
+    MultipleRecords(String),
+    /// A value did not match the type of the field it was assigned to, or a column
+    /// could not be decoded into the type of its field; the message names the column
+    Type(String),
+    /// A JSON value could not be converted to or from a model
+    Json(String),
+    /// An error reported by the database while running a statement
+    Database(DatabaseErr),
+    /// A statement did not complete within the timeout it was run with
+    Timeout(String),
+    /// The applied migrations do not match the ones of the migrator
+    Migration(String),
+}
+
+/// The reason a connection could not be acquired from the pool
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnAcquireErr {
+    /// Every connection stayed in use until the acquire timeout elapsed
+    Timeout,
+    /// The pool has been closed
+    ConnectionClosed,
+}
+
+/// An error reported by the database, carrying the backend error code when there is one
+#[derive(Debug, Clone)]
+pub struct DatabaseErr {
+    pub(crate) message: String,
+    pub(crate) code: Option<String>,
+    pub(crate) constraint: Option<String>,
+    pub(crate) sql_err: Option<SqlErr>,
+    pub(crate) source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+}
+
+/// A database error classified from its backend error code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlErr {
+    /// A unique or primary key constraint was violated
+    UniqueConstraintViolation,
+    /// A foreign key constraint was violated
+    ForeignKeyConstraintViolation,
+    /// NULL was written to a NOT NULL column
+    NotNullViolation,
+    /// A check constraint was violated
+    CheckViolation,
+}
+
+impl DbErr {
+    /// Classify an error reported by the database, e.g. to map unique violations to a conflict
+    pub fn sql_err(&self) -> Option<SqlErr> {
+        match self {
+            Self::Database(e) => e.sql_err(),
+            _ => None,
+        }
+    }
+}
+
+impl DatabaseErr {
+    /// The error message from the database
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The backend error code; SQLSTATE on Postgres and MySQL, the extended result code on SQLite
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The name of the violated constraint, if the backend reports it: always on Postgres,
+    /// for a foreign key violation on MySQL, never on SQLite
+    pub fn constraint(&self) -> Option<&str> {
+        self.constraint.as_deref()
+    }
+
+    /// The classified error, if the code is one SeaORM knows about
+    pub fn sql_err(&self) -> Option<SqlErr> {
+        self.sql_err
+    }
+}
+
+impl PartialEq for DatabaseErr {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.code == other.code
+            && self.constraint == other.constraint
+            && self.sql_err == other.sql_err
+    }
 }
 
-impl std::error::Error for DbErr {}
+impl std::error::Error for DbErr {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Database(e) => e
+                .source
+                .as_deref()
+                .map(|e| e as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
 
 impl std::fmt::Display for DbErr {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::Conn(s) => write!(f, "Connection Error: {}", s),
+            Self::ConnectionAcquire(e) => write!(f, "Connection Acquire Error: {}", e),
             Self::Exec(s) => write!(f, "Execution Error: {}", s),
             Self::Query(s) => write!(f, "Query Error: {}", s),
+            Self::RecordNotFound(s) => write!(f, "RecordNotFound Error: {}", s),
+            Self::MultipleRecords(s) => write!(f, "MultipleRecords Error: {}", s),
+            Self::Type(s) => write!(f, "Type Error: {}", s),
+            Self::Json(s) => write!(f, "Json Error: {}", s),
+            Self::Database(e) => write!(f, "Database Error: {}", e),
+            Self::Timeout(s) => write!(f, "Timeout Error: {}", s),
+            Self::Migration(s) => write!(f, "Migration Error: {}", s),
+        }
+    }
+}
+
+impl std::fmt::Display for DatabaseErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{} (code {})", self.message, code),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::fmt::Display for ConnAcquireErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "Timed out waiting for a connection from the pool"),
+            Self::ConnectionClosed => write!(f, "The connection pool has been closed"),
         }
     }
 }
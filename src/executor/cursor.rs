@@ -0,0 +1,386 @@
+use crate::{
+    query::cte::WithClause, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity,
+    IntoIdentity, Select, SelectModel, SelectorTrait,
+};
+use sea_query::{
+    Condition, DynIden, Expr, IntoValueTuple, Order, SeaRc, SelectStatement, SimpleExpr, Value,
+    ValueTuple,
+};
+use std::marker::PhantomData;
+
+/// Keyset pagination over a select, see [`Select::cursor_by`]
+#[derive(Clone, Debug)]
+pub struct Cursor<S>
+where
+    S: SelectorTrait,
+{
+    pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
+    pub(crate) table: DynIden,
+    pub(crate) order_columns: Identity,
+    pub(crate) after: Option<Vec<Value>>,
+    pub(crate) before: Option<Vec<Value>>,
+    pub(crate) limit: Option<u64>,
+    pub(crate) last: bool,
+    pub(crate) selector: PhantomData<S>,
+}
+
+impl<S> Cursor<S>
+where
+    S: SelectorTrait,
+{
+    pub(crate) fn new(
+        query: SelectStatement,
+        with: WithClause,
+        table: DynIden,
+        order_columns: Identity,
+    ) -> Self {
+        Self {
+            query,
+            with,
+            table,
+            order_columns,
+            after: None,
+            before: None,
+            limit: None,
+            last: false,
+            selector: PhantomData,
+        }
+    }
+
+    /// Only return rows strictly after the given cursor value
+    pub fn after<V>(mut self, values: V) -> Self
+    where
+        V: IntoValueTuple,
+    {
+        self.after = Some(value_tuple_values(values.into_value_tuple()));
+        self
+    }
+
+    /// Only return rows strictly before the given cursor value
+    pub fn before<V>(mut self, values: V) -> Self
+    where
+        V: IntoValueTuple,
+    {
+        self.before = Some(value_tuple_values(values.into_value_tuple()));
+        self
+    }
+
+    /// Return the first `num_rows` rows in cursor order
+    pub fn first(mut self, num_rows: u64) -> Self {
+        self.limit = Some(num_rows);
+        self.last = false;
+        self
+    }
+
+    /// Return the last `num_rows` rows, still in ascending cursor order
+    pub fn last(mut self, num_rows: u64) -> Self {
+        self.limit = Some(num_rows);
+        self.last = true;
+        self
+    }
+
+    /// Build the statement; rows are ordered by the cursor columns after any existing ORDER BY.
+    /// Fails with [`DbErr::Query`] if a cursor value does not have one value per cursor column.
+    pub fn into_query(self) -> Result<SelectStatement, DbErr> {
+        let mut query = self.query;
+        if let Some(values) = self.after {
+            query.cond_where(cursor_condition(
+                &self.table,
+                &self.order_columns,
+                values,
+                Expr::gt,
+            )?);
+        }
+        if let Some(values) = self.before {
+            query.cond_where(cursor_condition(
+                &self.table,
+                &self.order_columns,
+                values,
+                Expr::lt,
+            )?);
+        }
+        for col in identity_columns(&self.order_columns) {
+            let ord = if self.last { Order::Desc } else { Order::Asc };
+            query.order_by((SeaRc::clone(&self.table), col), ord);
+        }
+        if let Some(limit) = self.limit {
+            query.limit(limit);
+        }
+        Ok(query)
+    }
+
+    /// Fetch the selected page
+    pub async fn all<C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let last = self.last;
+        let with = self.with.clone();
+        let stmt = with.build(db.get_database_backend(), &self.into_query()?);
+        let rows = db.query_all(stmt).await?;
+        let mut buffer = Vec::with_capacity(rows.len());
+        for row in rows.into_iter() {
+            buffer.push(S::from_raw_query_result(row)?);
+        }
+        if last {
+            buffer.reverse();
+        }
+        Ok(buffer)
+    }
+
+    /// Decode the rows into a custom model instead
+    pub fn into_model<M>(self) -> Cursor<SelectModel<M>>
+    where
+        M: FromQueryResult,
+    {
+        Cursor {
+            query: self.query,
+            with: self.with,
+            table: self.table,
+            order_columns: self.order_columns,
+            after: self.after,
+            before: self.before,
+            limit: self.limit,
+            last: self.last,
+            selector: PhantomData,
+        }
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Paginate by the values of `order_columns` instead of by offset.
+    /// Composite cursors compare as tuples, e.g. `(a, b) > (x, y)`.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// # fn main() -> Result<(), sea_orm::DbErr> {
+    /// assert_eq!(
+    ///     DbBackend::Postgres.build(
+    ///         &cake::Entity::find()
+    ///             .cursor_by(cake::Column::Id)
+    ///             .after(10)
+    ///             .first(2)
+    ///             .into_query()?
+    ///     )
+    ///     .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."id" > 10 ORDER BY "cake"."id" ASC LIMIT 2"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cursor_by<C>(self, order_columns: C) -> Cursor<SelectModel<E::Model>>
+    where
+        C: IntoIdentity,
+    {
+        let (query, with) = self.into_parts();
+        Cursor::new(
+            query,
+            with,
+            SeaRc::new(E::default()),
+            order_columns.into_identity(),
+        )
+    }
+}
+
+fn identity_columns(identity: &Identity) -> Vec<DynIden> {
+    match identity {
+        Identity::Unary(c1) => vec![SeaRc::clone(c1)],
+        Identity::Binary(c1, c2) => vec![SeaRc::clone(c1), SeaRc::clone(c2)],
+        Identity::Ternary(c1, c2, c3) => {
+            vec![SeaRc::clone(c1), SeaRc::clone(c2), SeaRc::clone(c3)]
+        }
+    }
+}
+
+fn value_tuple_values(values: ValueTuple) -> Vec<Value> {
+    match values {
+        ValueTuple::One(v1) => vec![v1],
+        ValueTuple::Two(v1, v2) => vec![v1, v2],
+        ValueTuple::Three(v1, v2, v3) => vec![v1, v2, v3],
+    }
+}
+
+/// Expand `(c1, c2) > (v1, v2)` into `c1 > v1 OR (c1 = v1 AND c2 > v2)`,
+/// which every backend understands
+fn cursor_condition<F>(
+    table: &DynIden,
+    order_columns: &Identity,
+    values: Vec<Value>,
+    cmp: F,
+) -> Result<Condition, DbErr>
+where
+    F: Fn(Expr, Value) -> SimpleExpr,
+{
+    let columns = identity_columns(order_columns);
+    if columns.len() != values.len() {
+        return Err(DbErr::Query(format!(
+            "a cursor value of {} values does not match the {} cursor columns",
+            values.len(),
+            columns.len()
+        )));
+    }
+    let col = |c: &DynIden| Expr::tbl(SeaRc::clone(table), SeaRc::clone(c));
+    let mut cond = Condition::any();
+    for i in 0..columns.len() {
+        let mut all = Condition::all();
+        for j in 0..i {
+            all = all.add(col(&columns[j]).eq(values[j].clone()));
+        }
+        all = all.add(cmp(col(&columns[i]), values[i].clone()));
+        cond = cond.add(all);
+    }
+    // wrap in `all` so later conditions are ANDed rather than ORed onto it
+    Ok(Condition::all().add(cond))
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+
+    fn fruit_row(id: i32, name: &str, cake_id: Option<i32>) -> fruit::Model {
+        fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn cursor_after_first() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit_row(3, "Pear", Some(1)),
+                fruit_row(4, "Plum", Some(1)),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .cursor_by(fruit::Column::Id)
+                .after(2)
+                .first(2)
+                .all(&db)
+                .await?,
+            vec![fruit_row(3, "Pear", Some(1)), fruit_row(4, "Plum", Some(1))]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."id" > $1 ORDER BY "fruit"."id" ASC LIMIT $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![2i32.into(), 2u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_before_last() -> Result<(), DbErr> {
+        // rows come back in descending order and are flipped back
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit_row(4, "Plum", Some(1)),
+                fruit_row(3, "Pear", Some(1)),
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .cursor_by(fruit::Column::Id)
+                .before(5)
+                .last(2)
+                .all(&db)
+                .await?,
+            vec![fruit_row(3, "Pear", Some(1)), fruit_row(4, "Plum", Some(1))]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."id" < $1 ORDER BY "fruit"."id" DESC LIMIT $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![5i32.into(), 2u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_composite() -> Result<(), DbErr> {
+        assert_eq!(
+            DbBackend::MySql
+                .build(
+                    &cake_filling::Entity::find()
+                        .cursor_by((cake_filling::Column::CakeId, cake_filling::Column::FillingId))
+                        .after((1, 2))
+                        .before((3, 4))
+                        .first(10)
+                        .into_query()?
+                )
+                .to_string(),
+            [
+                "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`",
+                "WHERE (`cake_filling`.`cake_id` > 1",
+                "OR (`cake_filling`.`cake_id` = 1 AND `cake_filling`.`filling_id` > 2))",
+                "AND (`cake_filling`.`cake_id` < 3",
+                "OR (`cake_filling`.`cake_id` = 3 AND `cake_filling`.`filling_id` < 4))",
+                "ORDER BY `cake_filling`.`cake_id` ASC, `cake_filling`.`filling_id` ASC",
+                "LIMIT 10",
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn cursor_value_arity_mismatch() {
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            cake_filling::Entity::find()
+                .cursor_by((cake_filling::Column::CakeId, cake_filling::Column::FillingId))
+                .after(1)
+                .first(10)
+                .all(&db)
+                .await,
+            Err(DbErr::Query(
+                "a cursor value of 1 values does not match the 2 cursor columns".to_owned()
+            ))
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .cursor_by(fruit::Column::Id)
+                .before((1, 2))
+                .into_query()
+                .map(|_| ()),
+            Err(DbErr::Query(
+                "a cursor value of 2 values does not match the 1 cursor columns".to_owned()
+            ))
+        );
+        // Nothing is sent to the database
+        assert_eq!(db.into_transaction_log(), vec![]);
+    }
+}
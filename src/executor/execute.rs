@@ -40,6 +40,8 @@ impl ExecResult {
         }
     }
 
+    /// Number of rows the statement affected, whatever the backend.
+    /// On MySQL, rows an `UPDATE` left unchanged are not counted.
     pub fn rows_affected(&self) -> u64 {
         match &self.result {
             #[cfg(feature = "sqlx-mysql")]
@@ -1,5 +1,5 @@
-use crate::{ActiveModelTrait, DbBackend, ConnectionTrait, EntityTrait, Insert, PrimaryKeyTrait, Statement, TryFromU64, error::*};
-use sea_query::InsertStatement;
+use crate::{ActiveModelTrait, ColumnTrait, Condition, DbBackend, ConnectionTrait, EntityTrait, FromQueryResult, FromValueTuple, Insert, InsertFromSelect, Iterable, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QuerySelect, QueryTrait, Statement, TryFromU64, error::*, primary_key_value_tuple, query::{insert_default_values, insert_on_conflict_do_nothing}};
+use sea_query::{InsertStatement, IntoValueTuple, Value};
 use std::{future::Future, marker::PhantomData};
 
 #[derive(Clone, Debug)]
@@ -8,6 +8,8 @@ where
     A: ActiveModelTrait,
 {
     query: InsertStatement,
+    on_conflict_do_nothing: bool,
+    sensitive: Vec<Value>,
     model: PhantomData<A>,
 }
 
@@ -34,8 +36,10 @@ where
     {
         // TODO: extract primary key's value from query
         // so that self is dropped before entering await
-        let mut query = self.query;
-        if db.get_database_backend() == DbBackend::Postgres {
+        let builder = db.get_database_backend();
+        let insert = self.for_backend(builder);
+        let mut query = insert.query;
+        if builder == DbBackend::Postgres {
             use crate::{sea_query::Query, Iterable};
             if <A::Entity as EntityTrait>::PrimaryKey::iter().count() > 0 {
                 query.returning(
@@ -45,9 +49,143 @@ where
                 );
             }
         }
-        Inserter::<A>::new(query).exec(db)
+        let mut inserter = Inserter::<A>::new(query);
+        inserter.on_conflict_do_nothing = insert.on_conflict_do_nothing;
+        inserter.sensitive = insert.sensitive;
+        inserter.exec(db)
         // TODO: return primary key if extracted before, otherwise use InsertResult
     }
+
+    /// Execute the insert and fetch the inserted row, with the values generated by
+    /// the database such as an auto-increment primary key or column defaults.
+    /// Postgres returns the row with `RETURNING`; other backends select it again by
+    /// the primary key of the model, or by the last insert id if the key was unset.
+    pub fn exec_with_returning<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<<A::Entity as EntityTrait>::Model, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let builder = db.get_database_backend();
+        let insert = self.for_backend(builder);
+        let mut query = insert.query;
+        if builder == DbBackend::Postgres {
+            use crate::{sea_query::Query, Iterable};
+            query.returning(
+                Query::select()
+                    .columns(<A::Entity as EntityTrait>::Column::iter())
+                    .take(),
+            );
+        }
+        let mut stmt =
+            insert_default_values(builder.build(&query)).with_sensitive_values(insert.sensitive);
+        if insert.on_conflict_do_nothing {
+            stmt = insert_on_conflict_do_nothing(stmt);
+        }
+        exec_insert_with_returning::<A, _>(insert.primary_key, stmt, db)
+    }
+
+    /// Execute the insert of many models and fetch the inserted rows, in the order the
+    /// models were added, with the values generated by the database. Postgres returns the
+    /// rows with `RETURNING`; other backends select them again by the primary keys found
+    /// by [`exec_with_returning_keys`](Self::exec_with_returning_keys).
+    pub fn exec_with_returning_many<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let builder = db.get_database_backend();
+        let insert = self.for_backend(builder);
+        let num_rows = insert.rows.len();
+        let primary_keys = insert.primary_keys();
+        let mut query = insert.query;
+        if builder == DbBackend::Postgres {
+            use crate::sea_query::Query;
+            query.returning(
+                Query::select()
+                    .columns(<A::Entity as EntityTrait>::Column::iter())
+                    .take(),
+            );
+        }
+        let mut stmt =
+            insert_default_values(builder.build(&query)).with_sensitive_values(insert.sensitive);
+        if insert.on_conflict_do_nothing {
+            stmt = insert_on_conflict_do_nothing(stmt);
+        }
+        exec_insert_many_with_returning::<A, _>(num_rows, primary_keys, stmt, db)
+    }
+
+    /// Execute the insert of many models and fetch the primary keys of the inserted rows,
+    /// in the order the models were added. Postgres returns them with `RETURNING`. On MySQL
+    /// and SQLite, keys set on every model are taken as they are; otherwise the key has to be
+    /// auto-incremented, and the ids are the range of as many ids as rows were inserted,
+    /// starting from the last insert id on MySQL and ending with it on SQLite.
+    ///
+    /// This relies on one statement being given consecutive ids. SQLite always does, MySQL
+    /// does with `innodb_autoinc_lock_mode` 0 or 1, and with 2, the default since MySQL 8.0,
+    /// as long as the statement is a "simple insert" of a known number of rows, which a
+    /// multi-row `INSERT .. VALUES` is. `auto_increment_increment` has to be left at 1.
+    /// Fails if some rows were skipped by [`on_conflict_do_nothing`](Self::on_conflict_do_nothing),
+    /// as the keys of the inserted ones cannot be told apart then.
+    pub fn exec_with_returning_keys<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let builder = db.get_database_backend();
+        let insert = self.for_backend(builder);
+        let num_rows = insert.rows.len();
+        let primary_keys = insert.primary_keys();
+        let mut query = insert.query;
+        if builder == DbBackend::Postgres {
+            use crate::sea_query::Query;
+            query.returning(
+                Query::select()
+                    .columns(<A::Entity as EntityTrait>::PrimaryKey::iter())
+                    .take(),
+            );
+        }
+        let mut stmt =
+            insert_default_values(builder.build(&query)).with_sensitive_values(insert.sensitive);
+        if insert.on_conflict_do_nothing {
+            stmt = insert_on_conflict_do_nothing(stmt);
+        }
+        exec_insert_many_returning_keys::<A, _>(num_rows, primary_keys, stmt, db)
+    }
+
+    /// Execute the insert without fetching the primary key, returning the number of rows inserted.
+    /// With [`on_conflict_do_nothing`](Self::on_conflict_do_nothing), skipped rows are not counted.
+    pub async fn exec_without_returning<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let builder = db.get_database_backend();
+        let res = db.execute(self.build(builder)).await?;
+        Ok(res.rows_affected())
+    }
+}
+
+impl<E> InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Execute the insert, returning the number of rows inserted
+    pub async fn exec<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let builder = db.get_database_backend();
+        let res = db.execute(self.build(builder)).await?;
+        Ok(res.rows_affected())
+    }
 }
 
 impl<A> Inserter<A>
@@ -57,6 +195,8 @@ where
     pub fn new(query: InsertStatement) -> Self {
         Self {
             query,
+            on_conflict_do_nothing: false,
+            sensitive: Vec::new(),
             model: PhantomData,
         }
     }
@@ -70,7 +210,12 @@ where
         A: 'a,
     {
         let builder = db.get_database_backend();
-        exec_insert(builder.build(&self.query), db)
+        let mut stmt = insert_default_values(builder.build(&self.query))
+            .with_sensitive_values(self.sensitive);
+        if self.on_conflict_do_nothing {
+            stmt = insert_on_conflict_do_nothing(stmt);
+        }
+        exec_insert(stmt, db)
     }
 }
 
@@ -91,8 +236,11 @@ where
             let cols = PrimaryKey::<A>::iter()
                 .map(|col| col.to_string())
                 .collect::<Vec<_>>();
-            let res = db.query_one(statement).await?.unwrap();
-            res.try_get_many("", cols.as_ref()).unwrap_or_default()
+            match db.query_one(statement).await? {
+                Some(res) => res.try_get_many("", cols.as_ref()).unwrap_or_default(),
+                // No row is returned when the insert was skipped on conflict
+                None => Default::default(),
+            }
         },
         _ => {
             let last_insert_id = db.execute(statement).await?.last_insert_id();
@@ -103,3 +251,135 @@ where
     };
     Ok(InsertResult { last_insert_id })
 }
+
+async fn exec_insert_with_returning<A, C>(
+    primary_key: Option<Vec<Value>>,
+    statement: Statement,
+    db: &C,
+) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type ValueTypeOf<A> = <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType;
+    let found = match db.get_database_backend() {
+        DbBackend::Postgres => match db.query_one(statement).await? {
+            Some(row) => Some(<A::Entity as EntityTrait>::Model::from_query_result(&row, "")?),
+            None => None,
+        },
+        _ => {
+            let last_insert_id = db.execute(statement).await?.last_insert_id();
+            let primary_key = match primary_key {
+                Some(values) => ValueTypeOf::<A>::from_value_tuple(primary_key_value_tuple(values)),
+                None => ValueTypeOf::<A>::try_from_u64(last_insert_id)?,
+            };
+            <A::Entity as EntityTrait>::find_by_id(primary_key).limit(1).on_primary(db.get_database_backend()).one(db).await?
+        },
+    };
+    // No row is found when the insert was skipped on conflict
+    found.ok_or_else(|| DbErr::RecordNotFound("Failed to find inserted item".to_owned()))
+}
+
+async fn exec_insert_many_returning_keys<A, C>(
+    num_rows: usize,
+    primary_keys: Option<Vec<Vec<Value>>>,
+    statement: Statement,
+    db: &C,
+) -> Result<Vec<<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
+    type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
+    if num_rows == 0 {
+        return Ok(Vec::new());
+    }
+    match db.get_database_backend() {
+        DbBackend::Postgres => {
+            use crate::sea_query::Iden;
+            let cols = PrimaryKey::<A>::iter()
+                .map(|col| col.to_string())
+                .collect::<Vec<_>>();
+            db.query_all(statement)
+                .await?
+                .iter()
+                .map(|row| row.try_get_many("", cols.as_ref()))
+                .collect()
+        },
+        backend => {
+            let res = db.execute(statement).await?;
+            if res.rows_affected() != num_rows as u64 {
+                return Err(DbErr::Exec(format!(
+                    "{} of {} rows were inserted, cannot tell which keys they have",
+                    res.rows_affected(),
+                    num_rows
+                )));
+            }
+            if let Some(primary_keys) = primary_keys {
+                return Ok(primary_keys
+                    .into_iter()
+                    .map(|values| ValueTypeOf::<A>::from_value_tuple(primary_key_value_tuple(values)))
+                    .collect());
+            }
+            if !<PrimaryKey<A> as PrimaryKeyTrait>::auto_increment() {
+                return Err(DbErr::Exec(
+                    "the primary key is neither set on every model nor auto-incremented".to_owned(),
+                ));
+            }
+            // MySQL reports the id of the first row, SQLite the one of the last
+            let last_insert_id = res.last_insert_id();
+            let first_id = match backend {
+                DbBackend::MySql => last_insert_id,
+                _ => (last_insert_id + 1).saturating_sub(num_rows as u64),
+            };
+            (first_id..first_id + num_rows as u64)
+                .map(ValueTypeOf::<A>::try_from_u64)
+                .collect()
+        },
+    }
+}
+
+async fn exec_insert_many_with_returning<A, C>(
+    num_rows: usize,
+    primary_keys: Option<Vec<Vec<Value>>>,
+    statement: Statement,
+    db: &C,
+) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
+    if num_rows == 0 {
+        return Ok(Vec::new());
+    }
+    if db.get_database_backend() == DbBackend::Postgres {
+        return db.query_all(statement)
+            .await?
+            .iter()
+            .map(|row| <A::Entity as EntityTrait>::Model::from_query_result(row, ""))
+            .collect();
+    }
+    let keys: Vec<Vec<Value>> = exec_insert_many_returning_keys::<A, C>(num_rows, primary_keys, statement, db)
+        .await?
+        .into_iter()
+        .map(|key| key.into_value_tuple().into_iter().collect())
+        .collect();
+    let mut condition = Condition::any();
+    for key in keys.iter() {
+        let mut key_condition = Condition::all();
+        for (col, value) in PrimaryKey::<A>::iter().zip(key) {
+            key_condition = key_condition.add(col.into_column().eq(value.clone()));
+        }
+        condition = condition.add(key_condition);
+    }
+    let mut models = <A::Entity as EntityTrait>::find().filter(condition).on_primary(db.get_database_backend()).all(db).await?;
+    models.sort_by_key(|model| {
+        let key: Vec<Value> = PrimaryKey::<A>::iter()
+            .map(|col| model.get(col.into_column()))
+            .collect();
+        keys.iter().position(|k| *k == key)
+    });
+    Ok(models)
+}
@@ -1,4 +1,4 @@
-use crate::{DbBackend, ConnectionTrait, SelectorTrait, error::*};
+use crate::{query::cte::WithClause, DbBackend, ConnectionTrait, SelectorTrait, error::*};
 use async_stream::stream;
 use futures::Stream;
 use sea_query::{Alias, Expr, SelectStatement};
@@ -13,6 +13,7 @@ where
     S: SelectorTrait + 'db,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
     pub(crate) page: usize,
     pub(crate) page_size: usize,
     pub(crate) db: &'db C,
@@ -35,7 +36,7 @@ where
             .offset((self.page_size * page) as u64)
             .to_owned();
         let builder = self.db.get_database_backend();
-        let stmt = builder.build(&query);
+        let stmt = self.with.build(builder, &query);
         let rows = self.db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
@@ -53,7 +54,8 @@ where
     /// Get the total number of items
     pub async fn num_items(&self) -> Result<usize, DbErr> {
         let builder = self.db.get_database_backend();
-        let stmt = builder.build(
+        let stmt = self.with.build(
+            builder,
             SelectStatement::new()
                 .expr(Expr::cust("COUNT(*) AS num_items"))
                 .from_subquery(
@@ -142,12 +144,8 @@ where
     /// ```
     pub fn into_stream(mut self) -> PinBoxStream<'db, Result<Vec<S::Item>, DbErr>> {
         Box::pin(stream! {
-            loop {
-                if let Some(vec) = self.fetch_and_next().await? {
-                    yield Ok(vec);
-                } else {
-                    break
-                }
+            while let Some(vec) = self.fetch_and_next().await? {
+                yield Ok(vec);
             }
         })
     }
@@ -272,7 +270,7 @@ mod tests {
 
         let num_items = num_items as usize;
         let page_size = 2_usize;
-        let num_pages = (num_items / page_size) + (num_items % page_size > 0) as usize;
+        let num_pages = num_items.div_ceil(page_size);
         let paginator = fruit::Entity::find().paginate(&db, page_size);
 
         assert_eq!(paginator.num_pages().await?, num_pages);
@@ -1,11 +1,9 @@
-#[cfg(feature = "mock")]
-use crate::debug_print;
 use crate::DbErr;
 use std::fmt;
 
-#[derive(Debug)]
 pub struct QueryResult {
     pub(crate) row: QueryResultRow,
+    pub(crate) sensitive: Vec<String>,
 }
 
 pub(crate) enum QueryResultRow {
@@ -21,6 +19,19 @@ pub(crate) enum QueryResultRow {
 
 pub trait TryGetable: Sized {
     fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError>;
+
+    /// Get the value of the column at `idx`, counting from 0 in select order.
+    /// By default this looks up the column name, so the first of any duplicate names wins.
+    fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+        let columns = res.column_names();
+        match columns.get(idx) {
+            Some(col) => Self::try_get(res, "", col),
+            None => Err(TryGetError::DbErr(column_index_out_of_bounds(
+                idx,
+                columns.len(),
+            ))),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -33,11 +44,23 @@ impl From<TryGetError> for DbErr {
     fn from(e: TryGetError) -> DbErr {
         match e {
             TryGetError::DbErr(e) => e,
-            TryGetError::Null => DbErr::Query("error occurred while decoding: Null".to_owned()),
+            TryGetError::Null => DbErr::Type("error occurred while decoding: Null".to_owned()),
         }
     }
 }
 
+/// A NULL read into a type that is not an `Option`, naming the column it came from
+fn try_get_err<T>(err: TryGetError, column: &str) -> DbErr {
+    match err {
+        TryGetError::DbErr(e) => e,
+        TryGetError::Null => DbErr::Type(format!(
+            "column {} is NULL, which cannot be decoded as `{}`; use an `Option` for a nullable column",
+            column,
+            std::any::type_name::<T>()
+        )),
+    }
+}
+
 // QueryResult //
 
 impl QueryResult {
@@ -45,7 +68,7 @@ impl QueryResult {
     where
         T: TryGetable,
     {
-        Ok(T::try_get(self, pre, col)?)
+        T::try_get(self, pre, col).map_err(|e| try_get_err::<T>(e, &format!("`{}{}`", pre, col)))
     }
 
     pub fn try_get_many<T>(&self, pre: &str, cols: &[String]) -> Result<T, DbErr>
@@ -54,6 +77,114 @@ impl QueryResult {
     {
         Ok(T::try_get_many(self, pre, cols)?)
     }
+
+    /// Get a value by its position in the row, e.g. when a join selects two columns of the same name
+    pub fn try_get_by_index<T>(&self, idx: usize) -> Result<T, DbErr>
+    where
+        T: TryGetable,
+    {
+        T::try_get_by_index(self, idx).map_err(|e| try_get_err::<T>(e, &idx.to_string()))
+    }
+
+    /// The names of the columns in this row, in the order they were selected
+    pub fn column_names(&self) -> Vec<String> {
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::{Column, Row};
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::{Column, Row};
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::{Column, Row};
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.column_names(),
+        }
+    }
+
+    /// Print the values of `columns` as `****` in the `Debug` output of the row,
+    /// e.g. those of columns defined as `sensitive`
+    pub fn with_sensitive_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.sensitive.extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    /// The value of the column at `idx` as printed by `Debug`, decoded as the first type that fits
+    fn debug_value(&self, idx: usize) -> String {
+        macro_rules! debug_as {
+            ( $( $type: ty ),* ) => {
+                $(
+                    match <$type>::try_get_by_index(self, idx) {
+                        Ok(value) => return format!("{:?}", value),
+                        Err(TryGetError::Null) => return "NULL".to_owned(),
+                        Err(TryGetError::DbErr(_)) => {}
+                    }
+                )*
+            };
+        }
+        // Postgres has no unsigned integers, and only Postgres decodes a timestamp with its offset
+        let is_postgres = match &self.row {
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(_) => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        };
+        debug_as!(i64, i32, i16, i8);
+        if !is_postgres {
+            debug_as!(u64, u32, u16, u8);
+        }
+        debug_as!(f64, f32, bool);
+        #[cfg(feature = "with-rust_decimal")]
+        debug_as!(rust_decimal::Decimal);
+        debug_as!(String);
+        #[cfg(feature = "with-chrono")]
+        {
+            if is_postgres {
+                debug_as!(chrono::DateTime<chrono::FixedOffset>);
+            }
+            debug_as!(chrono::NaiveDateTime, chrono::NaiveDate, chrono::NaiveTime);
+        }
+        #[cfg(feature = "with-uuid")]
+        debug_as!(uuid::Uuid);
+        #[cfg(feature = "with-json")]
+        debug_as!(serde_json::Value);
+        debug_as!(Vec<u8>);
+        "<unknown type>".to_owned()
+    }
+}
+
+/// Prints the columns of the row with their values, to inspect it with `dbg!`,
+/// e.g. `QueryResult { id: 1, name: "Apple Pie" }`
+impl fmt::Debug for QueryResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut row = f.debug_struct("QueryResult");
+        for (idx, col) in self.column_names().iter().enumerate() {
+            if self.sensitive.contains(col) {
+                row.field(col, &format_args!("****"));
+            } else {
+                row.field(col, &format_args!("{}", self.debug_value(idx)));
+            }
+        }
+        row.finish()
+    }
+}
+
+pub(crate) fn column_index_out_of_bounds(idx: usize, len: usize) -> DbErr {
+    DbErr::Query(format!(
+        "column index out of bounds: the len is {}, but the index is {}",
+        len, idx
+    ))
 }
 
 impl fmt::Debug for QueryResultRow {
@@ -81,6 +212,14 @@ impl<T: TryGetable> TryGetable for Option<T> {
             Err(e) => Err(e),
         }
     }
+
+    fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+        match T::try_get_by_index(res, idx) {
+            Ok(v) => Ok(Some(v)),
+            Err(TryGetError::Null) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 macro_rules! try_getable_all {
@@ -93,29 +232,53 @@ macro_rules! try_getable_all {
                     QueryResultRow::SqlxMySql(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "sqlx-postgres")]
                     QueryResultRow::SqlxPostgres(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "sqlx-sqlite")]
                     QueryResultRow::SqlxSqlite(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "mock")]
-                    #[allow(unused_variables)]
-                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        TryGetError::Null
-                    }),
+                    QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+                }
+            }
+
+            fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(idx),
                 }
             }
         }
@@ -132,7 +295,7 @@ macro_rules! try_getable_unsigned {
                     QueryResultRow::SqlxMySql(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "sqlx-postgres")]
@@ -143,22 +306,45 @@ macro_rules! try_getable_unsigned {
                     QueryResultRow::SqlxSqlite(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "mock")]
-                    #[allow(unused_variables)]
-                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        TryGetError::Null
-                    }),
+                    QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+                }
+            }
+
+            fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(_) => {
+                        panic!("{} unsupported by sqlx-postgres", stringify!($type))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(idx),
                 }
             }
         }
     };
 }
 
-macro_rules! try_getable_mysql {
+/// Only MySQL has unsigned types; Postgres and SQLite store the value in a `BIGINT`,
+/// so it is decoded as `i64` and checked to be in range
+macro_rules! try_getable_unsigned_int {
     ( $type: ty ) => {
         impl TryGetable for $type {
             fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
@@ -168,29 +354,77 @@ macro_rules! try_getable_mysql {
                     QueryResultRow::SqlxMySql(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "sqlx-postgres")]
-                    QueryResultRow::SqlxPostgres(_) => {
-                        panic!("{} unsupported by sqlx-postgres", stringify!($type))
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<i64>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                            .and_then(unsigned_from_i64)
                     }
                     #[cfg(feature = "sqlx-sqlite")]
-                    QueryResultRow::SqlxSqlite(_) => {
-                        panic!("{} unsupported by sqlx-sqlite", stringify!($type))
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<i64>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                            .and_then(unsigned_from_i64)
                     }
                     #[cfg(feature = "mock")]
-                    #[allow(unused_variables)]
-                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        TryGetError::Null
-                    }),
+                    QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+                }
+            }
+
+            fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<i64>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                            .and_then(unsigned_from_i64)
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<i64>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                            .and_then(unsigned_from_i64)
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(idx),
                 }
             }
         }
     };
 }
 
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+fn unsigned_from_i64<T>(v: i64) -> Result<T, TryGetError>
+where
+    T: std::convert::TryFrom<i64>,
+{
+    T::try_from(v).map_err(|_| {
+        TryGetError::DbErr(DbErr::Type(format!(
+            "{} is out of range for {}",
+            v,
+            std::any::type_name::<T>()
+        )))
+    })
+}
+
 macro_rules! try_getable_postgres {
     ( $type: ty ) => {
         impl TryGetable for $type {
@@ -205,7 +439,7 @@ macro_rules! try_getable_postgres {
                     QueryResultRow::SqlxPostgres(row) => {
                         use sqlx::Row;
                         row.try_get::<Option<$type>, _>(column.as_str())
-                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .map_err(crate::sqlx_error_to_try_get_err)
                             .and_then(|opt| opt.ok_or(TryGetError::Null))
                     }
                     #[cfg(feature = "sqlx-sqlite")]
@@ -213,11 +447,29 @@ macro_rules! try_getable_postgres {
                         panic!("{} unsupported by sqlx-sqlite", stringify!($type))
                     }
                     #[cfg(feature = "mock")]
-                    #[allow(unused_variables)]
-                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                        debug_print!("{:#?}", e.to_string());
-                        TryGetError::Null
-                    }),
+                    QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+                }
+            }
+
+            fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => {
+                        panic!("{} unsupported by sqlx-mysql", stringify!($type))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => {
+                        panic!("{} unsupported by sqlx-sqlite", stringify!($type))
+                    }
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get_by_index(idx),
                 }
             }
         }
@@ -231,16 +483,96 @@ try_getable_all!(i32);
 try_getable_all!(i64);
 try_getable_unsigned!(u8);
 try_getable_unsigned!(u16);
-try_getable_all!(u32);
-try_getable_mysql!(u64);
+try_getable_unsigned_int!(u32);
+try_getable_unsigned_int!(u64);
 try_getable_all!(f32);
 try_getable_all!(f64);
-try_getable_all!(String);
 try_getable_all!(Vec<u8>);
 
+impl TryGetable for String {
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+        let column = format!("{}{}", pre, col);
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<String>, _>(column.as_str())
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::ColumnIndex;
+                let idx = column
+                    .as_str()
+                    .index(row)
+                    .map_err(crate::sqlx_error_to_try_get_err)?;
+                try_get_postgres_string(row, idx)
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<String>, _>(column.as_str())
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+        }
+    }
+
+    fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<String>, _>(idx)
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => try_get_postgres_string(row, idx),
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<String>, _>(idx)
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.try_get_by_index(idx),
+        }
+    }
+}
+
+/// sqlx decodes a `String` from text columns only, but a native enum, see
+/// [`ColumnType::Enum`](crate::ColumnType::Enum), is sent as the text of its label as well
+#[cfg(feature = "sqlx-postgres")]
+fn try_get_postgres_string(row: &sqlx::postgres::PgRow, idx: usize) -> Result<String, TryGetError> {
+    use sqlx::{postgres::PgTypeKind, Column, Row, TypeInfo};
+
+    let type_info = row.column(idx).type_info();
+    // a type sqlx has not looked up is named "?", and has no kind to ask for
+    let is_enum = type_info.name() != "?" && matches!(type_info.kind(), PgTypeKind::Enum(_));
+    let value = if is_enum {
+        row.try_get_unchecked::<Option<String>, _>(idx)
+    } else {
+        row.try_get::<Option<String>, _>(idx)
+    };
+    value
+        .map_err(crate::sqlx_error_to_try_get_err)
+        .and_then(|opt| opt.ok_or(TryGetError::Null))
+}
+
 #[cfg(feature = "with-json")]
 try_getable_all!(serde_json::Value);
 
+#[cfg(feature = "with-chrono")]
+try_getable_all!(chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+try_getable_all!(chrono::NaiveTime);
+
 #[cfg(feature = "with-chrono")]
 try_getable_all!(chrono::NaiveDateTime);
 
@@ -259,14 +591,14 @@ impl TryGetable for Decimal {
             QueryResultRow::SqlxMySql(row) => {
                 use sqlx::Row;
                 row.try_get::<Option<Decimal>, _>(column.as_str())
-                    .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                    .map_err(crate::sqlx_error_to_try_get_err)
                     .and_then(|opt| opt.ok_or(TryGetError::Null))
             }
             #[cfg(feature = "sqlx-postgres")]
             QueryResultRow::SqlxPostgres(row) => {
                 use sqlx::Row;
                 row.try_get::<Option<Decimal>, _>(column.as_str())
-                    .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                    .map_err(crate::sqlx_error_to_try_get_err)
                     .and_then(|opt| opt.ok_or(TryGetError::Null))
             }
             #[cfg(feature = "sqlx-sqlite")]
@@ -274,23 +606,57 @@ impl TryGetable for Decimal {
                 use sqlx::Row;
                 let val: Option<f64> = row
                     .try_get(column.as_str())
-                    .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))?;
+                    .map_err(crate::sqlx_error_to_try_get_err)?;
                 use rust_decimal::prelude::FromPrimitive;
                 match val {
                     Some(v) => Decimal::from_f64(v).ok_or_else(|| {
-                        TryGetError::DbErr(DbErr::Query(
-                            "Failed to convert f64 into Decimal".to_owned(),
-                        ))
+                        TryGetError::DbErr(DbErr::Type(format!(
+                            "column `{}` holds {}, which cannot be decoded as `Decimal`",
+                            column, v
+                        )))
                     }),
                     None => Err(TryGetError::Null),
                 }
             }
             #[cfg(feature = "mock")]
-            #[allow(unused_variables)]
-            QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
-                debug_print!("{:#?}", e.to_string());
-                TryGetError::Null
-            }),
+            QueryResultRow::Mock(row) => row.try_get_column(column.as_str()),
+        }
+    }
+
+    fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+        match &res.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<Decimal>, _>(idx)
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::Row;
+                row.try_get::<Option<Decimal>, _>(idx)
+                    .map_err(crate::sqlx_error_to_try_get_err)
+                    .and_then(|opt| opt.ok_or(TryGetError::Null))
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::Row;
+                let val: Option<f64> =
+                    row.try_get(idx).map_err(crate::sqlx_error_to_try_get_err)?;
+                use rust_decimal::prelude::FromPrimitive;
+                match val {
+                    Some(v) => Decimal::from_f64(v).ok_or_else(|| {
+                        TryGetError::DbErr(DbErr::Type(format!(
+                            "column {} holds {}, which cannot be decoded as `Decimal`",
+                            idx, v
+                        )))
+                    }),
+                    None => Err(TryGetError::Null),
+                }
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.try_get_by_index(idx),
         }
     }
 }
@@ -298,10 +664,79 @@ impl TryGetable for Decimal {
 #[cfg(feature = "with-uuid")]
 try_getable_all!(uuid::Uuid);
 
+/// Arrays are only decoded from Postgres rows
+fn array_unsupported(backend: &str) -> TryGetError {
+    TryGetError::DbErr(DbErr::Type(format!(
+        "array columns are only supported on Postgres, not on {}",
+        backend
+    )))
+}
+
+/// A Postgres array read into a `Vec`. An empty array is an empty `Vec`,
+/// and a NULL array is `None` in an `Option<Vec<_>>`.
+macro_rules! try_getable_array {
+    ( $type: ty ) => {
+        impl TryGetable for Vec<$type> {
+            #[allow(unused_variables)]
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(array_unsupported("MySQL")),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<Vec<$type>>, _>(column.as_str())
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(array_unsupported("SQLite")),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(_) => Err(array_unsupported("the mock database")),
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn try_get_by_index(res: &QueryResult, idx: usize) -> Result<Self, TryGetError> {
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(array_unsupported("MySQL")),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<Vec<$type>>, _>(idx)
+                            .map_err(crate::sqlx_error_to_try_get_err)
+                            .and_then(|opt| opt.ok_or(TryGetError::Null))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(array_unsupported("SQLite")),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(_) => Err(array_unsupported("the mock database")),
+                }
+            }
+        }
+    };
+}
+
+try_getable_array!(bool);
+try_getable_array!(i16);
+try_getable_array!(i32);
+try_getable_array!(i64);
+try_getable_array!(f32);
+try_getable_array!(f64);
+try_getable_array!(String);
+
+#[cfg(feature = "with-uuid")]
+try_getable_array!(uuid::Uuid);
+
 // TryGetableMany //
 
 pub trait TryGetableMany: Sized {
     fn try_get_many(res: &QueryResult, pre: &str, cols: &[String]) -> Result<Self, TryGetError>;
+
+    /// Get the values of the leading columns of the row, by position
+    fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError>;
 }
 
 impl<T> TryGetableMany for T
@@ -312,6 +747,10 @@ where
         try_get_many_with_slice_len_of(1, cols)?;
         T::try_get(res, pre, &cols[0])
     }
+
+    fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+        T::try_get_by_index(res, 0)
+    }
 }
 
 impl<A, B> TryGetableMany for (A, B)
@@ -326,6 +765,13 @@ where
             B::try_get(res, pre, &cols[1])?,
         ))
     }
+
+    fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+        Ok((
+            A::try_get_by_index(res, 0)?,
+            B::try_get_by_index(res, 1)?,
+        ))
+    }
 }
 
 impl<A, B, C> TryGetableMany for (A, B, C)
@@ -342,6 +788,14 @@ where
             C::try_get(res, pre, &cols[2])?,
         ))
     }
+
+    fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+        Ok((
+            A::try_get_by_index(res, 0)?,
+            B::try_get_by_index(res, 1)?,
+            C::try_get_by_index(res, 2)?,
+        ))
+    }
 }
 
 impl<A, B, C, D> TryGetableMany for (A, B, C, D)
@@ -360,6 +814,15 @@ where
             D::try_get(res, pre, &cols[3])?,
         ))
     }
+
+    fn try_get_many_by_index(res: &QueryResult) -> Result<Self, TryGetError> {
+        Ok((
+            A::try_get_by_index(res, 0)?,
+            B::try_get_by_index(res, 1)?,
+            C::try_get_by_index(res, 2)?,
+            D::try_get_by_index(res, 3)?,
+        ))
+    }
 }
 
 fn try_get_many_with_slice_len_of(len: usize, cols: &[String]) -> Result<(), TryGetError> {
@@ -376,7 +839,11 @@ fn try_get_many_with_slice_len_of(len: usize, cols: &[String]) -> Result<(), Try
 
 // TryFromU64 //
 
+/// Convert the `last_insert_id` a MySQL or SQLite insert reports into the value of an
+/// auto-generated primary key. Integers are range checked; keys that are not generated by
+/// counting, like `Uuid`, always fail and have to be read back with `RETURNING` instead.
 pub trait TryFromU64: Sized {
+    /// Convert `n`, failing if it does not fit into `Self`
     fn try_from_u64(n: u64) -> Result<Self, DbErr>;
 }
 
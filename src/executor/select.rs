@@ -1,5 +1,5 @@
-use crate::{ConnectionTrait, EntityTrait, FromQueryResult, IdenStatic, Iterable, JsonValue, ModelTrait, Paginator, PrimaryKeyToColumn, QueryResult, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement, error::*};
-use sea_query::SelectStatement;
+use crate::{query::cte::WithClause, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable, JsonValue, ModelTrait, Paginator, PartialModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryResult, QuerySelect, QueryTrait, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement, TryGetableMany, error::*};
+use sea_query::{Expr, SelectStatement};
 use std::marker::PhantomData;
 
 #[derive(Clone, Debug)]
@@ -8,6 +8,8 @@ where
     S: SelectorTrait,
 {
     query: SelectStatement,
+    with: WithClause,
+    #[allow(dead_code)]
     selector: S,
 }
 
@@ -17,6 +19,7 @@ where
     S: SelectorTrait,
 {
     stmt: Statement,
+    #[allow(dead_code)]
     selector: S,
 }
 
@@ -43,6 +46,25 @@ where
     model: PhantomData<(M, N)>,
 }
 
+#[derive(Debug)]
+pub struct SelectGetableTuple<T>
+where
+    T: TryGetableMany,
+{
+    model: PhantomData<T>,
+}
+
+impl<T> SelectorTrait for SelectGetableTuple<T>
+where
+    T: TryGetableMany,
+{
+    type Item = T;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        Ok(T::try_get_many_by_index(&res)?)
+    }
+}
+
 impl<M> SelectorTrait for SelectModel<M>
 where
     M: FromQueryResult + Sized,
@@ -81,29 +103,115 @@ where
         }
     }
 
+    /// Fetch the models from the primary of a [`ReplicatedConnection`](crate::ReplicatedConnection),
+    /// to read back the rows that were just written
+    pub(crate) fn on_primary(self, db_backend: DbBackend) -> SelectorRaw<SelectModel<E::Model>> {
+        let stmt = self.build(db_backend).on_primary();
+        E::find().from_raw_sql(stmt)
+    }
+
     pub fn into_model<M>(self) -> Selector<SelectModel<M>>
     where
         M: FromQueryResult,
     {
+        let (query, with) = self.into_parts();
         Selector {
-            query: self.query,
+            query,
+            with,
             selector: SelectModel { model: PhantomData },
         }
     }
 
+    /// Select only the columns of the partial model `M` and decode each row into it,
+    /// see [`PartialModelTrait`]
+    pub fn into_partial_model<M>(self) -> Selector<SelectModel<M>>
+    where
+        M: PartialModelTrait,
+    {
+        M::select_cols(self.select_only()).into_model()
+    }
+
+    /// Decode each row into a tuple of the selected columns, matched by position.
+    /// A single column decodes into a plain value; use `Option` for columns that may be NULL.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, tests_cfg::*, MockDatabase, Transaction, DbBackend};
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![
+    /// #         maplit::btreemap! {
+    /// #             "count" => Into::<Value>::into(2i64),
+    /// #             "max_id" => Into::<Value>::into(Some(4)),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// use sea_query::{Expr, Func};
+    ///
+    /// # let _: Result<(), DbErr> = smol::block_on(async {
+    /// #
+    /// let res: Option<(i64, Option<i32>)> = cake::Entity::find()
+    ///     .select_only()
+    ///     .column_as(cake::Column::Id.count(), "count")
+    ///     .column_as(cake::Column::Id.max(), "max_id")
+    ///     .into_tuple()
+    ///     .one(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(res, Some((2, Some(4))));
+    /// #
+    /// # Ok(())
+    /// # });
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT COUNT("cake"."id") AS "count", MAX("cake"."id") AS "max_id" FROM "cake" LIMIT $1"#,
+    ///         vec![1u64.into()]
+    ///     ),]
+    /// );
+    /// ```
+    pub fn into_tuple<T>(self) -> Selector<SelectGetableTuple<T>>
+    where
+        T: TryGetableMany,
+    {
+        let (query, with) = self.into_parts();
+        Selector {
+            query,
+            with,
+            selector: SelectGetableTuple { model: PhantomData },
+        }
+    }
+
     #[cfg(feature = "with-json")]
     pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
+        let (query, with) = self.into_parts();
         Selector {
-            query: self.query,
+            query,
+            with,
             selector: SelectModel { model: PhantomData },
         }
     }
 
+    /// Fetch the first matching model, or `None` if there is no row. Runs on any
+    /// [`ConnectionTrait`]: a pool connection, a transaction or the mock database.
     pub async fn one<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
     where C: ConnectionTrait {
         self.into_model().one(db).await
     }
 
+    /// Fetch the only matching model, or `None` if there is no row. Fails with
+    /// [`DbErr::MultipleRecords`] if more than one row matches, e.g. to catch
+    /// duplicates in a lookup by a column that is expected to be unique.
+    pub async fn one_strict<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
+    where C: ConnectionTrait {
+        self.into_model().one_strict(db).await
+    }
+
+    /// Fetch all matching models, on any [`ConnectionTrait`]
     pub async fn all<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
     where C: ConnectionTrait {
         self.into_model().all(db).await
@@ -118,9 +226,79 @@ where
         self.into_model().paginate(db, page_size)
     }
 
-    pub async fn count<C>(self, db: &C) -> Result<usize, DbErr>
+    /// Count the rows this query would return, keeping its filters, joins and grouping.
+    /// A grouped query counts the groups.
+    pub async fn count<C>(self, db: &C) -> Result<u64, DbErr>
+    where C: ConnectionTrait {
+        let num_items = self.paginate(db, 1).num_items().await?;
+        Ok(num_items as u64)
+    }
+
+    /// Check whether any row matches, with `SELECT EXISTS(SELECT 1 ... LIMIT 1)`,
+    /// without fetching a model
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # use sea_orm::{error::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![maplit::btreemap! {
+    /// #         "exists" => Into::<sea_orm::Value>::into(true),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// # let _: Result<(), DbErr> = smol::block_on(async {
+    /// #
+    /// assert!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.eq("Chocolate Forest"))
+    ///         .exists(&db)
+    ///         .await?
+    /// );
+    /// #
+    /// # Ok(())
+    /// # });
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT EXISTS(SELECT 1 FROM "cake" WHERE "cake"."name" = $1 LIMIT $2) AS "exists""#,
+    ///         vec!["Chocolate Forest".into(), 1u64.into()]
+    ///     )]
+    /// );
+    /// ```
+    pub async fn exists<C>(self, db: &C) -> Result<bool, DbErr>
+    where C: ConnectionTrait {
+        let (mut query, with) = self.into_parts();
+        query.clear_selects().expr(Expr::cust("1")).limit(1);
+        let backend = db.get_database_backend();
+        let stmt = with.build_exists(backend, &query);
+        let row = match db.query_one(stmt).await? {
+            Some(row) => row,
+            None => return Ok(false),
+        };
+        match backend {
+            DbBackend::Postgres => row.try_get("", "exists"),
+            // MySQL and SQLite return the boolean as 0 or 1
+            DbBackend::MySql | DbBackend::Sqlite => Ok(row.try_get::<i64>("", "exists")? != 0),
+        }
+    }
+
+    /// Fetch only the primary key of each matching row, without decoding whole models.
+    /// Composite primary keys are returned as tuples.
+    pub async fn into_primary_key_values<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>, DbErr>
     where C: ConnectionTrait {
-        self.paginate(db, 1).num_items().await
+        let mut select = self.select_only();
+        for key in E::PrimaryKey::iter() {
+            select = select.column(key.into_column());
+        }
+        select.into_tuple().all(db).await
     }
 }
 
@@ -136,6 +314,7 @@ where
     {
         Selector {
             query: self.query,
+            with: self.with,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -144,6 +323,7 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            with: self.with,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -156,6 +336,15 @@ where
         self.into_model().one(db).await
     }
 
+    /// Fetch the only matching pair, see [`Select::one_strict`]
+    pub async fn one_strict<C>(
+        self,
+        db: &C,
+    ) -> Result<Option<(E::Model, Option<F::Model>)>, DbErr>
+    where C: ConnectionTrait {
+        self.into_model().one_strict(db).await
+    }
+
     pub async fn all<C>(
         self,
         db: &C,
@@ -173,9 +362,12 @@ where
         self.into_model().paginate(db, page_size)
     }
 
-    pub async fn count<C>(self, db: &C) -> Result<usize, DbErr>
+    /// Count the rows this query would return, keeping its filters, joins and grouping.
+    /// A grouped query counts the groups.
+    pub async fn count<C>(self, db: &C) -> Result<u64, DbErr>
     where C: ConnectionTrait {
-        self.paginate(db, 1).num_items().await
+        let num_items = self.paginate(db, 1).num_items().await?;
+        Ok(num_items as u64)
     }
 }
 
@@ -191,6 +383,7 @@ where
     {
         Selector {
             query: self.query,
+            with: self.with,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -199,6 +392,7 @@ where
     pub fn into_json(self) -> Selector<SelectTwoModel<JsonValue, JsonValue>> {
         Selector {
             query: self.query,
+            with: self.with,
             selector: SelectTwoModel { model: PhantomData },
         }
     }
@@ -238,17 +432,35 @@ where
     where C: ConnectionTrait {
         let builder = db.get_database_backend();
         self.query.limit(1);
-        let row = db.query_one(builder.build(&self.query)).await?;
+        let row = db.query_one(self.with.build(builder, &self.query)).await?;
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
             None => Ok(None),
         }
     }
 
+    /// Fetch the only matching item, see [`Select::one_strict`]
+    pub async fn one_strict<C>(mut self, db: &C) -> Result<Option<S::Item>, DbErr>
+    where C: ConnectionTrait {
+        let builder = db.get_database_backend();
+        // A second row is enough to tell that the match is not unique
+        self.query.limit(2);
+        let stmt = self.with.build(builder, &self.query);
+        let sql = stmt.sql.clone();
+        let mut rows = db.query_all(stmt).await?;
+        if rows.len() > 1 {
+            return Err(DbErr::MultipleRecords(format!("more than one row returned by: {}", sql)));
+        }
+        match rows.pop() {
+            Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
+            None => Ok(None),
+        }
+    }
+
     pub async fn all<C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where C: ConnectionTrait {
         let builder = db.get_database_backend();
-        let rows = db.query_all(builder.build(&self.query)).await?;
+        let rows = db.query_all(self.with.build(builder, &self.query)).await?;
         let mut models = Vec::new();
         for row in rows.into_iter() {
             models.push(S::from_raw_query_result(row)?);
@@ -260,6 +472,7 @@ where
     where C: ConnectionTrait {
         Paginator {
             query: self.query,
+            with: self.with,
             page: 0,
             page_size,
             db,
@@ -455,7 +668,12 @@ where
     where C: ConnectionTrait {
         let row = db.query_one(self.stmt).await?;
         match row {
-            Some(row) => Ok(Some(S::from_raw_query_result(row)?)),
+            Some(row) => {
+                let columns = row.column_names();
+                let model = S::from_raw_query_result(row)
+                    .map_err(|err| raw_decode_err::<S::Item>(err, &columns))?;
+                Ok(Some(model))
+            }
             None => Ok(None),
         }
     }
@@ -494,14 +712,33 @@ where
     pub async fn all<C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
     where C: ConnectionTrait {
         let rows = db.query_all(self.stmt).await?;
+        let columns = match rows.first() {
+            Some(row) => row.column_names(),
+            None => return Ok(Vec::new()),
+        };
         let mut models = Vec::new();
         for row in rows.into_iter() {
-            models.push(S::from_raw_query_result(row)?);
+            let model = S::from_raw_query_result(row)
+                .map_err(|err| raw_decode_err::<S::Item>(err, &columns))?;
+            models.push(model);
         }
         Ok(models)
     }
 }
 
+/// A raw statement's rows may not fit the model, so name both in the error
+fn raw_decode_err<T>(err: DbErr, columns: &[String]) -> DbErr {
+    match err {
+        DbErr::Query(msg) => DbErr::Query(format!(
+            "Failed to decode `{}` from the columns [{}] of the statement: {}",
+            std::any::type_name::<T>(),
+            columns.join(", "),
+            msg
+        )),
+        err => err,
+    }
+}
+
 fn consolidate_query_result<L, R>(
     rows: Vec<(L::Model, Option<R::Model>)>,
 ) -> Vec<(L::Model, Vec<R::Model>)>
@@ -529,11 +766,567 @@ where
                 }
             }
         }
-        if r.is_some() {
-            acc.push((l, vec![r.unwrap()]));
-        } else {
-            acc.push((l, vec![]));
+        match r {
+            Some(r) => acc.push((l, vec![r])),
+            None => acc.push((l, vec![])),
         }
     }
     acc
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, query::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+    use crate::FromQueryResult;
+    use sea_query::{Alias, Expr};
+
+    #[smol_potat::test]
+    async fn one_strict() -> Result<(), DbErr> {
+        let apple = |id: i32| fruit::Model {
+            id,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![apple(1)],
+                vec![apple(1), apple(2)],
+                vec![],
+            ])
+            .into_connection();
+
+        let find_apple = || fruit::Entity::find().filter(fruit::Column::Name.eq("Apple"));
+        assert_eq!(find_apple().one_strict(&db).await?, Some(apple(1)));
+        assert_eq!(
+            find_apple().one_strict(&db).await,
+            Err(DbErr::MultipleRecords(
+                [
+                    r#"more than one row returned by: SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                    r#"FROM "fruit" WHERE "fruit"."name" = $1 LIMIT $2"#,
+                ]
+                .join(" ")
+            ))
+        );
+        assert_eq!(find_apple().one_strict(&db).await?, None);
+
+        let limit_two = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" WHERE "fruit"."name" = $1 LIMIT $2"#,
+            vec!["Apple".into(), 2u64.into()],
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![limit_two.clone(), limit_two.clone(), limit_two]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "with-rust_decimal")]
+    async fn into_model_aggregate() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use rust_decimal_macros::dec;
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct CakeTotal {
+            cake_id: i32,
+            total: Decimal,
+            num_fillings: i64,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "cake_id" => Into::<Value>::into(1),
+                    "total" => Into::<Value>::into(dec!(12.5)),
+                    "num_fillings" => Into::<Value>::into(2i64),
+                },
+                maplit::btreemap! {
+                    "cake_id" => Into::<Value>::into(2),
+                    "total" => Into::<Value>::into(dec!(3)),
+                    "num_fillings" => Into::<Value>::into(1i64),
+                },
+            ]])
+            .into_connection();
+
+        let totals = cake_filling_price::Entity::find()
+            .select_only()
+            .column(cake_filling_price::Column::CakeId)
+            .column_as(cake_filling_price::Column::Price.sum(), "total")
+            .column_as(
+                Expr::col(cake_filling_price::Column::FillingId).count(),
+                "num_fillings",
+            )
+            .group_by(cake_filling_price::Column::CakeId)
+            .into_model::<CakeTotal>()
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            totals,
+            vec![
+                CakeTotal {
+                    cake_id: 1,
+                    total: dec!(12.5),
+                    num_fillings: 2,
+                },
+                CakeTotal {
+                    cake_id: 2,
+                    total: dec!(3),
+                    num_fillings: 1,
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling_price"."cake_id", SUM("cake_filling_price"."price") AS "total", COUNT("filling_id") AS "num_fillings""#,
+                    r#"FROM "public"."cake_filling_price""#,
+                    r#"GROUP BY "cake_filling_price"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_model_partial() -> Result<(), DbErr> {
+        use crate as sea_orm;
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct FruitName {
+            id: i32,
+            name: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2),
+                    "name" => Into::<Value>::into("Pear"),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Apple"),
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .columns(vec![fruit::Column::Id, fruit::Column::Name])
+                .filter(fruit::Column::CakeId.eq(1))
+                .order_by_desc(fruit::Column::CakeId)
+                .into_model::<FruitName>()
+                .all(&db)
+                .await?,
+            vec![
+                FruitName {
+                    id: 2,
+                    name: "Pear".to_owned(),
+                },
+                FruitName {
+                    id: 1,
+                    name: "Apple".to_owned(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" = $1 ORDER BY "fruit"."cake_id" DESC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_tuple_single() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "max_id" => Into::<Value>::into(Some(3)),
+                }],
+                vec![maplit::btreemap! {
+                    "max_id" => Value::Int(None),
+                }],
+            ])
+            .into_connection();
+
+        let select = cake::Entity::find()
+            .select_only()
+            .column_as(cake::Column::Id.max(), "max_id")
+            .group_by(cake::Column::Name)
+            // The alias of the column is kept, so it can be ordered by
+            .order_by_desc(Expr::col(Alias::new("max_id")));
+
+        assert_eq!(
+            select.clone().into_tuple::<Option<i32>>().one(&db).await?,
+            Some(Some(3))
+        );
+        assert_eq!(
+            select.into_tuple::<Option<i32>>().one(&db).await?,
+            Some(None)
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT MAX("cake"."id") AS "max_id" FROM "cake""#,
+                        r#"GROUP BY "cake"."name" ORDER BY "max_id" DESC LIMIT $1"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT MAX("cake"."id") AS "max_id" FROM "cake""#,
+                        r#"GROUP BY "cake"."name" ORDER BY "max_id" DESC LIMIT $1"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_tuple_many() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Apple"),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2),
+                    "name" => Into::<Value>::into("Pear"),
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .column(fruit::Column::Name)
+                .filter(fruit::Column::CakeId.eq(1))
+                .into_tuple::<(i32, String)>()
+                .all(&db)
+                .await?,
+            vec![(1, "Apple".to_owned()), (2, "Pear".to_owned())]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" = $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_primary_key_values() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![
+                    maplit::btreemap! {
+                        "id" => Into::<Value>::into(1),
+                    },
+                    maplit::btreemap! {
+                        "id" => Into::<Value>::into(2),
+                    },
+                ],
+                vec![maplit::btreemap! {
+                    "cake_id" => Into::<Value>::into(1),
+                    "filling_id" => Into::<Value>::into(3),
+                }],
+            ])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::CakeId.eq(1))
+                .into_primary_key_values(&db)
+                .await?,
+            vec![1, 2]
+        );
+        assert_eq!(
+            cake_filling::Entity::find()
+                .filter(cake_filling::Column::FillingId.eq(3))
+                .into_primary_key_values(&db)
+                .await?,
+            vec![(1, 3)]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "fruit"."id" FROM "fruit" WHERE "fruit"."cake_id" = $1"#,
+                    vec![1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                        r#"WHERE "cake_filling"."filling_id" = $1"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![3i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn num_items_result(num_items: i64) -> Vec<std::collections::BTreeMap<&'static str, Value>> {
+        vec![maplit::btreemap! {
+            "num_items" => Into::<Value>::into(num_items),
+        }]
+    }
+
+    #[smol_potat::test]
+    async fn count_filtered() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![num_items_result(2)])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::Name.contains("berry"))
+                .count(&db)
+                .await?,
+            2
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT COUNT(*) AS num_items FROM"#,
+                    r#"(SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."name" LIKE $1) AS "sub_query""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec!["%berry%".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn count_grouped() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![num_items_result(3)])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .group_by(fruit::Column::CakeId)
+                .count(&db)
+                .await?,
+            3
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT COUNT(*) AS num_items FROM"#,
+                    r#"(SELECT "fruit"."cake_id" FROM "fruit" GROUP BY "fruit"."cake_id") AS "sub_query""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn count_zero_rows() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![num_items_result(0), vec![]])
+            .into_connection();
+
+        assert_eq!(cake::Entity::find().count(&db).await?, 0);
+        assert_eq!(cake::Entity::find().count(&db).await?, 0);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn exists() -> Result<(), DbErr> {
+        let exists_result = |exists: i64| {
+            vec![maplit::btreemap! {
+                "exists" => Into::<Value>::into(exists),
+            }]
+        };
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_query_results(vec![exists_result(1), exists_result(0)])
+            .into_connection();
+
+        let find_by_name = |name: &str| {
+            fruit::Entity::find()
+                .filter(fruit::Column::Name.eq(name))
+                .order_by_asc(fruit::Column::Id)
+        };
+        assert!(find_by_name("Apple").exists(&db).await?);
+        assert!(!find_by_name("Durian").exists(&db).await?);
+
+        let select_exists = |name: &str| {
+            Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                [
+                    "SELECT EXISTS(SELECT 1 FROM `fruit` WHERE `fruit`.`name` = ?",
+                    "ORDER BY `fruit`.`id` ASC LIMIT ?) AS `exists`",
+                ]
+                .join(" ")
+                .as_str(),
+                vec![name.into(), 1u64.into()],
+            )
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![select_exists("Apple"), select_exists("Durian")]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn into_partial_model() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::DerivePartialModel;
+        use fruit::Entity as Fruit;
+
+        #[derive(Debug, PartialEq, DerivePartialModel)]
+        #[sea_orm(entity = Fruit)]
+        struct FruitRow {
+            id: i32,
+            #[sea_orm(from_expr = "cake::Column::Name")]
+            cake_name: String,
+            #[sea_orm(from_expr = "Expr::tbl(Fruit, fruit::Column::Name).count()")]
+            num_of_names: i64,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "id" => Into::<Value>::into(1),
+                "cake_name" => Into::<Value>::into("Chocolate Forest"),
+                "num_of_names" => Into::<Value>::into(2i64),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            fruit::Entity::find()
+                .inner_join(cake::Entity)
+                .group_by(fruit::Column::Id)
+                .group_by(cake::Column::Name)
+                .into_partial_model::<FruitRow>()
+                .all(&db)
+                .await?,
+            vec![FruitRow {
+                id: 1,
+                cake_name: "Chocolate Forest".to_owned(),
+                num_of_names: 2,
+            }]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id" AS "id", "cake"."name" AS "cake_name", COUNT("fruit"."name") AS "num_of_names""#,
+                    r#"FROM "fruit" INNER JOIN "cake" ON "fruit"."cake_id" = "cake"."id""#,
+                    r#"GROUP BY "fruit"."id", "cake"."name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn decode_type_mismatch() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into(2),
+                }],
+                vec![maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Value::String(None),
+                }],
+            ])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find().one(&db).await,
+            Err(DbErr::Type(
+                "column `name` holds Int(Some(2)), which cannot be decoded as `alloc::string::String`"
+                    .to_owned()
+            ))
+        );
+        assert_eq!(
+            cake::Entity::find().one(&db).await,
+            Err(DbErr::Type(
+                "column `name` is NULL, which cannot be decoded as `alloc::string::String`; use an `Option` for a nullable column"
+                    .to_owned()
+            ))
+        );
+    }
+}
@@ -1,10 +1,11 @@
-use crate::{ActiveModelTrait, ConnectionTrait, EntityTrait, Statement, UpdateMany, UpdateOne, error::*};
-use sea_query::UpdateStatement;
+use crate::{ActiveModelTrait, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, FromValueTuple, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, QuerySelect, Statement, UpdateMany, UpdateManyWithValues, UpdateOne, error::*, primary_key_value_tuple};
+use sea_query::{Query, UpdateStatement, Value};
 use std::future::Future;
 
 #[derive(Clone, Debug)]
 pub struct Updater {
     query: UpdateStatement,
+    sensitive: Vec<Value>,
 }
 
 #[derive(Clone, Debug)]
@@ -19,7 +20,43 @@ where
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<A, DbErr>> + 'a
     where C: ConnectionTrait {
         // so that self is dropped before entering await
-        exec_update_and_return_original(self.query, self.model, db)
+        let updater = Updater::new(self.query).with_sensitive_values(self.sensitive);
+        exec_update_and_return_original(updater, self.model, db)
+    }
+
+    /// Execute the update and fetch the updated row. Postgres returns it with `RETURNING`;
+    /// other backends select it again by the primary key. If no column is set, nothing is
+    /// updated and the row is only fetched. Fails with [`DbErr::RecordNotFound`] if no row
+    /// has the primary key of the model.
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<<A::Entity as EntityTrait>::Model, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        let primary_key: Vec<Value> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| self.model.get(key.into_column()).into_value())
+            .collect();
+        let has_values = <A::Entity as EntityTrait>::Column::iter().any(|col| {
+            <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_none()
+                && self.model.get(col).is_set()
+        });
+        let builder = db.get_database_backend();
+        let mut query = self.query;
+        let statement = if has_values {
+            if builder == DbBackend::Postgres {
+                query.returning(
+                    Query::select()
+                        .columns(<A::Entity as EntityTrait>::Column::iter())
+                        .take(),
+                );
+            }
+            Some(builder.build(&query).with_sensitive_values(self.sensitive))
+        } else {
+            None
+        };
+        exec_update_with_returning::<A, _>(statement, primary_key, db)
     }
 }
 
@@ -37,9 +74,33 @@ where
     }
 }
 
+impl<'a, E> UpdateManyWithValues<E>
+where
+    E: EntityTrait,
+{
+    /// Update every row in one statement. Without a value to set,
+    /// nothing is sent to the database and no row is affected.
+    pub fn exec<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<UpdateResult, DbErr>> + 'a
+    where C: ConnectionTrait {
+        // so that self is dropped before entering await
+        exec_update_if_any(self.build(db.get_database_backend()), db)
+    }
+}
+
 impl Updater {
     pub fn new(query: UpdateStatement) -> Self {
-        Self { query }
+        Self {
+            query,
+            sensitive: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_sensitive_values(mut self, values: Vec<Value>) -> Self {
+        self.sensitive = values;
+        self
     }
 
     pub fn exec<C>(
@@ -48,7 +109,12 @@ impl Updater {
     ) -> impl Future<Output = Result<UpdateResult, DbErr>> + '_
     where C: ConnectionTrait {
         let builder = db.get_database_backend();
-        exec_update(builder.build(&self.query), db)
+        exec_update(
+            builder
+                .build(&self.query)
+                .with_sensitive_values(self.sensitive),
+            db,
+        )
     }
 }
 
@@ -60,8 +126,19 @@ where C: ConnectionTrait {
     Updater::new(query).exec(db).await
 }
 
+async fn exec_update_if_any<C>(
+    statement: Option<Statement>,
+    db: &C,
+) -> Result<UpdateResult, DbErr>
+where C: ConnectionTrait {
+    match statement {
+        Some(statement) => exec_update(statement, db).await,
+        None => Ok(UpdateResult { rows_affected: 0 }),
+    }
+}
+
 async fn exec_update_and_return_original<A, C>(
-    query: UpdateStatement,
+    updater: Updater,
     model: A,
     db: &C,
 ) -> Result<A, DbErr>
@@ -69,10 +146,39 @@ where
     A: ActiveModelTrait,
     C: ConnectionTrait,
 {
-    Updater::new(query).exec(db).await?;
+    updater.exec(db).await?;
     Ok(model)
 }
 
+async fn exec_update_with_returning<A, C>(
+    statement: Option<Statement>,
+    primary_key: Vec<Value>,
+    db: &C,
+) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+where
+    A: ActiveModelTrait,
+    C: ConnectionTrait,
+{
+    type ValueTypeOf<A> = <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType;
+    let found = match statement {
+        Some(statement) if db.get_database_backend() == DbBackend::Postgres => {
+            match db.query_one(statement).await? {
+                Some(row) => Some(<A::Entity as EntityTrait>::Model::from_query_result(&row, "")?),
+                None => None,
+            }
+        }
+        statement => {
+            // Rows affected can not tell a missing row apart from an unchanged one on MySQL
+            if let Some(statement) = statement {
+                db.execute(statement).await?;
+            }
+            let primary_key = ValueTypeOf::<A>::from_value_tuple(primary_key_value_tuple(primary_key));
+            <A::Entity as EntityTrait>::find_by_id(primary_key).limit(1).on_primary(db.get_database_backend()).one(db).await?
+        }
+    };
+    found.ok_or_else(|| DbErr::RecordNotFound("Failed to find updated item".to_owned()))
+}
+
 // Only Statement impl Send
 async fn exec_update<C>(statement: Statement, db: &C) -> Result<UpdateResult, DbErr>
 where C: ConnectionTrait {
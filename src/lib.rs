@@ -275,7 +275,7 @@
 //! pear.name = Set("Sweet pear".to_owned());
 //!
 //! // update one
-//! let pear: fruit::ActiveModel = pear.update(db).await?;
+//! let pear: fruit::Model = pear.update(db).await?;
 //!
 //! // update many: UPDATE "fruit" SET "cake_id" = NULL WHERE "fruit"."name" LIKE '%Apple%'
 //! Fruit::update_many()
@@ -362,6 +362,7 @@ mod driver;
 pub mod entity;
 pub mod error;
 mod executor;
+pub mod migration;
 pub mod query;
 pub mod schema;
 #[doc(hidden)]
@@ -373,12 +374,15 @@ pub use driver::*;
 pub use entity::*;
 pub use error::*;
 pub use executor::*;
+pub use migration::*;
 pub use query::*;
 pub use schema::*;
 
 pub use sea_orm_macros::{
-    DeriveActiveModel, DeriveActiveModelBehavior, DeriveColumn, DeriveCustomColumn, DeriveEntity,
-    DeriveEntityModel, DeriveModel, DerivePrimaryKey, DeriveRelation, FromQueryResult,
+    DeriveActiveEnum, DeriveActiveModel, DeriveActiveModelBehavior, DeriveColumn,
+    DeriveCustomColumn, DeriveEntity, DeriveEntityModel, DeriveIntoActiveModel, DeriveLinked,
+    DeriveMigrationName, DeriveModel, DerivePartialModel, DerivePrimaryKey, DeriveRelation,
+    FromQueryResult,
 };
 
 pub use sea_query;
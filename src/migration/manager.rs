@@ -0,0 +1,71 @@
+use crate::{ConnectionTrait, DbBackend, DbErr, StatementBuilder};
+use sea_query::{
+    IndexCreateStatement, IndexDropStatement, TableAlterStatement, TableCreateStatement,
+    TableDropStatement, TableRenameStatement, TableTruncateStatement,
+};
+use std::fmt;
+
+/// Runs the schema statements of a migration, on the transaction the migration is applied in
+pub struct SchemaManager<'c> {
+    conn: &'c dyn ConnectionTrait,
+}
+
+impl<'c> SchemaManager<'c> {
+    pub fn new(conn: &'c dyn ConnectionTrait) -> Self {
+        Self { conn }
+    }
+
+    /// The connection to run anything else on, e.g. raw SQL with
+    /// [`execute_sql`](ConnectionTrait::execute_sql) or statements to migrate data
+    pub fn get_connection(&self) -> &'c dyn ConnectionTrait {
+        self.conn
+    }
+
+    pub fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    pub async fn exec_stmt<S>(&self, stmt: S) -> Result<(), DbErr>
+    where
+        S: StatementBuilder,
+    {
+        let builder = self.conn.get_database_backend();
+        self.conn.execute(builder.build(&stmt)).await.map(|_| ())
+    }
+
+    pub async fn create_table(&self, stmt: TableCreateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn alter_table(&self, stmt: TableAlterStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn drop_table(&self, stmt: TableDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn rename_table(&self, stmt: TableRenameStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn truncate_table(&self, stmt: TableTruncateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn create_index(&self, stmt: IndexCreateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    pub async fn drop_index(&self, stmt: IndexDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+}
+
+impl fmt::Debug for SchemaManager<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaManager")
+            .field("backend", &self.conn.get_database_backend())
+            .finish()
+    }
+}
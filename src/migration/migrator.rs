@@ -0,0 +1,175 @@
+use crate::{ConnectionTrait, DatabaseConnection, DbErr, MigrationTrait, SchemaManager};
+use sea_query::{ColumnDef, Expr, Iden, Query, Table};
+use std::time::SystemTime;
+
+/// The table the applied migrations are recorded in
+#[derive(Iden)]
+enum SeaqlMigrations {
+    Table,
+    Version,
+    AppliedAt,
+}
+
+/// Applies and rolls back a list of migrations, recording the applied ones in the
+/// `seaql_migrations` table, so running [`up`](Self::up) again only applies new migrations.
+/// Each migration runs in a transaction together with its record; MySQL commits DDL
+/// statements implicitly, so a migration failing there may be left half applied.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, DbConn, MigrationTrait, MigratorTrait};
+///
+/// mod m20220101_000001_create_cake {
+///     use sea_orm::{
+///         entity::prelude::*, sea_query::Table, tests_cfg::cake, DeriveMigrationName,
+///         MigrationTrait, Schema, SchemaManager,
+///     };
+///
+///     #[derive(DeriveMigrationName)]
+///     pub struct Migration;
+///
+///     #[async_trait::async_trait]
+///     impl MigrationTrait for Migration {
+///         async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+///             manager.create_table(Schema::create_table_from_entity(cake::Entity)).await
+///         }
+///
+///         async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+///             manager.drop_table(Table::drop().table(cake::Entity).to_owned()).await
+///         }
+///     }
+/// }
+///
+/// pub struct Migrator;
+///
+/// impl MigratorTrait for Migrator {
+///     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+///         vec![Box::new(m20220101_000001_create_cake::Migration)]
+///     }
+/// }
+///
+/// # async fn function(db: &DbConn) -> Result<(), DbErr> {
+/// // Apply every pending migration
+/// Migrator::up(db, None).await?;
+///
+/// // Roll back the last applied migration
+/// Migrator::down(db, Some(1)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait MigratorTrait: Send {
+    /// The migrations in the order they are applied
+    fn migrations() -> Vec<Box<dyn MigrationTrait>>;
+
+    /// Create the `seaql_migrations` table if it does not exist yet
+    async fn install(db: &DatabaseConnection) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(SeaqlMigrations::Table)
+            .if_not_exists()
+            .col(
+                ColumnDef::new(SeaqlMigrations::Version)
+                    .string()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(SeaqlMigrations::AppliedAt)
+                    .big_integer()
+                    .not_null(),
+            )
+            .to_owned();
+        let builder = db.get_database_backend();
+        db.execute(builder.build(&stmt)).await.map(|_| ())
+    }
+
+    /// The migrations recorded as applied, in the order they are applied
+    async fn get_applied_migrations(
+        db: &DatabaseConnection,
+    ) -> Result<Vec<Box<dyn MigrationTrait>>, DbErr> {
+        Self::install(db).await?;
+        let (applied, _) = migrations_by_status(db, Self::migrations()).await?;
+        Ok(applied)
+    }
+
+    /// The migrations not applied yet, in the order they are applied
+    async fn get_pending_migrations(
+        db: &DatabaseConnection,
+    ) -> Result<Vec<Box<dyn MigrationTrait>>, DbErr> {
+        Self::install(db).await?;
+        let (_, pending) = migrations_by_status(db, Self::migrations()).await?;
+        Ok(pending)
+    }
+
+    /// Apply the first `steps` pending migrations, or all of them if `None`
+    async fn up(db: &DatabaseConnection, steps: Option<u32>) -> Result<(), DbErr> {
+        Self::install(db).await?;
+        let (_, pending) = migrations_by_status(db, Self::migrations()).await?;
+        let steps = steps.map_or(pending.len(), |steps| steps as usize);
+        for migration in pending.into_iter().take(steps) {
+            let txn = db.begin().await?;
+            migration.up(&SchemaManager::new(&txn)).await?;
+            let applied_at = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64);
+            let stmt = Query::insert()
+                .into_table(SeaqlMigrations::Table)
+                .columns(vec![SeaqlMigrations::Version, SeaqlMigrations::AppliedAt])
+                .values_panic(vec![migration.name().into(), applied_at.into()])
+                .to_owned();
+            let builder = txn.get_database_backend();
+            txn.execute(builder.build(&stmt)).await?;
+            txn.commit().await?;
+        }
+        Ok(())
+    }
+
+    /// Roll back the last `steps` applied migrations, or all of them if `None`
+    async fn down(db: &DatabaseConnection, steps: Option<u32>) -> Result<(), DbErr> {
+        Self::install(db).await?;
+        let (applied, _) = migrations_by_status(db, Self::migrations()).await?;
+        let steps = steps.map_or(applied.len(), |steps| steps as usize);
+        for migration in applied.into_iter().rev().take(steps) {
+            let txn = db.begin().await?;
+            migration.down(&SchemaManager::new(&txn)).await?;
+            let stmt = Query::delete()
+                .from_table(SeaqlMigrations::Table)
+                .and_where(Expr::col(SeaqlMigrations::Version).eq(migration.name()))
+                .to_owned();
+            let builder = txn.get_database_backend();
+            txn.execute(builder.build(&stmt)).await?;
+            txn.commit().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Split `migrations` into the applied and the pending ones, failing with
+/// [`DbErr::Migration`] if a recorded migration is not among them
+async fn migrations_by_status(
+    db: &DatabaseConnection,
+    migrations: Vec<Box<dyn MigrationTrait>>,
+) -> Result<(Vec<Box<dyn MigrationTrait>>, Vec<Box<dyn MigrationTrait>>), DbErr> {
+    let stmt = Query::select()
+        .column(SeaqlMigrations::Version)
+        .from(SeaqlMigrations::Table)
+        .to_owned();
+    let builder = db.get_database_backend();
+    let versions = db
+        .query_all(builder.build(&stmt))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "version"))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(version) = versions
+        .iter()
+        .find(|version| !migrations.iter().any(|m| m.name() == version.as_str()))
+    {
+        return Err(DbErr::Migration(format!(
+            "migration '{}' is applied but is not one of the migrations of the migrator",
+            version
+        )));
+    }
+    Ok(migrations
+        .into_iter()
+        .partition(|m| versions.iter().any(|version| version == m.name())))
+}
@@ -0,0 +1,25 @@
+mod manager;
+mod migrator;
+
+pub use manager::*;
+pub use migrator::*;
+
+use crate::DbErr;
+
+/// The name a migration is recorded under in the `seaql_migrations` table.
+/// Derive it with `DeriveMigrationName`, which names the migration after its module,
+/// e.g. `m20220101_000001_create_cake`; migrations are applied in the order
+/// [`MigratorTrait::migrations`] lists them, not by their name.
+pub trait MigrationName {
+    fn name(&self) -> &str;
+}
+
+/// A change to the schema, and how to revert it
+#[async_trait::async_trait]
+pub trait MigrationTrait: MigrationName + Send + Sync {
+    /// Apply the change
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr>;
+
+    /// Revert the change made by [`up`](Self::up)
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr>;
+}
@@ -1,8 +1,8 @@
 use crate::{
-    EntityTrait, IdenStatic, IntoSimpleExpr, Iterable, QueryTrait, Select, SelectTwo, SelectTwoMany,
+    query::cte::WithClause, EntityTrait, IdenStatic, IntoSimpleExpr, Iterable, QueryTrait, Select,
+    SelectTwo, SelectTwoMany,
 };
 use core::marker::PhantomData;
-pub use sea_query::JoinType;
 use sea_query::{Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr};
 
 macro_rules! select_def {
@@ -54,20 +54,20 @@ where
         self
     }
 
-    pub fn select_also<F>(mut self, _: F) -> SelectTwo<E, F>
+    pub fn select_also<F>(self, _: F) -> SelectTwo<E, F>
     where
         F: EntityTrait,
     {
-        self = self.apply_alias(SelectA.as_str());
-        SelectTwo::new(self.into_query())
+        let (query, with) = self.apply_alias(SelectA.as_str()).into_parts();
+        SelectTwo::new(query, with)
     }
 
-    pub fn select_with<F>(mut self, _: F) -> SelectTwoMany<E, F>
+    pub fn select_with<F>(self, _: F) -> SelectTwoMany<E, F>
     where
         F: EntityTrait,
     {
-        self = self.apply_alias(SelectA.as_str());
-        SelectTwoMany::new(self.into_query())
+        let (query, with) = self.apply_alias(SelectA.as_str()).into_parts();
+        SelectTwoMany::new(query, with)
     }
 }
 
@@ -76,9 +76,10 @@ where
     E: EntityTrait,
     F: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
+    pub(crate) fn new(query: SelectStatement, with: WithClause) -> Self {
         Self {
             query,
+            with,
             entity: PhantomData,
         }
         .prepare_select()
@@ -95,9 +96,10 @@ where
     E: EntityTrait,
     F: EntityTrait,
 {
-    pub(crate) fn new(query: SelectStatement) -> Self {
+    pub(crate) fn new(query: SelectStatement, with: WithClause) -> Self {
         Self {
             query,
+            with,
             entity: PhantomData,
         }
         .prepare_select()
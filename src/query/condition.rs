@@ -0,0 +1,434 @@
+use sea_query::{Expr, IntoCondition, SelectStatement, SimpleExpr};
+
+/// A set of conditions joined by `AND` ([`Condition::all`]) or `OR` ([`Condition::any`]),
+/// which can be nested to group expressions.
+///
+/// It lowers to a [`sea_query::Condition`]. An empty `all()` is true and an empty
+/// `any()` is false; a constant condition is folded into its parent, so an empty
+/// `all()` adds nothing to the query while an empty `any()` yields `1 = 2`.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    any: bool,
+    negate: bool,
+    conditions: Vec<ConditionExpression>,
+}
+
+/// Anything that can be added to a [`Condition`]
+#[derive(Debug, Clone)]
+pub enum ConditionExpression {
+    Condition(Condition),
+    SimpleExpr(SimpleExpr),
+    SeaQuery(sea_query::Condition),
+}
+
+enum Lowered {
+    Const(bool),
+    Expr(sea_query::ConditionExpression),
+}
+
+impl Condition {
+    /// Create a condition that is true if any of the conditions is true.
+    /// An empty `any()` is false.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(
+    ///             Condition::any()
+    ///                 .add(
+    ///                     Condition::all()
+    ///                         .add(cake::Column::Id.gt(1))
+    ///                         .add(cake::Column::Id.lt(5))
+    ///                 )
+    ///                 .add(cake::Column::Name.like("%cheese%"))
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE (`cake`.`id` > 1 AND `cake`.`id` < 5) OR `cake`.`name` LIKE '%cheese%'"
+    /// );
+    /// ```
+    pub fn any() -> Self {
+        Self {
+            any: true,
+            negate: false,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Create a condition that is true if all of the conditions are true.
+    /// An empty `all()` is true.
+    pub fn all() -> Self {
+        Self {
+            any: false,
+            negate: false,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Create a condition that is true if the subquery returns any row.
+    ///
+    /// It lowers to `1 IN (SELECT 1 ...)`, which is equivalent to `EXISTS (SELECT ...)`
+    /// and is supported by every backend. Values bound in the subquery keep their
+    /// position in the statement.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(Condition::exists(fruit::Entity::find().filter(
+    ///             Expr::tbl(fruit::Entity, fruit::Column::CakeId).equals(cake::Entity, cake::Column::Id)
+    ///         )))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE 1 IN (SELECT 1 FROM `fruit` WHERE `fruit`.`cake_id` = `cake`.`id`)"
+    /// );
+    /// ```
+    pub fn exists<S>(subquery: S) -> Self
+    where
+        S: Into<SelectStatement>,
+    {
+        let mut subquery = subquery.into();
+        subquery.clear_selects().expr(Expr::cust("1"));
+        Self::all().add(Expr::expr(Expr::cust("1")).in_subquery(subquery))
+    }
+
+    /// Create a condition that is true if the subquery returns no row.
+    /// See [`Condition::exists`].
+    pub fn not_exists<S>(subquery: S) -> Self
+    where
+        S: Into<SelectStatement>,
+    {
+        let mut subquery = subquery.into();
+        subquery.clear_selects().expr(Expr::cust("1"));
+        Self::all().add(Expr::expr(Expr::cust("1")).not_in_subquery(subquery))
+    }
+
+    /// Add a condition to the set
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C>(mut self, condition: C) -> Self
+    where
+        C: Into<ConditionExpression>,
+    {
+        self.conditions.push(condition.into());
+        self
+    }
+
+    /// Add a condition to the set if it is `Some`
+    pub fn add_option<C>(self, condition: Option<C>) -> Self
+    where
+        C: Into<ConditionExpression>,
+    {
+        match condition {
+            Some(condition) => self.add(condition),
+            None => self,
+        }
+    }
+
+    /// Negate the condition
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        self.negate = !self.negate;
+        self
+    }
+
+    /// Whether no condition has been added
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    fn lower(self) -> Lowered {
+        // `true` absorbs `any()` and `false` absorbs `all()`
+        let absorbing = self.any;
+        let mut condition = if self.any {
+            sea_query::Condition::any()
+        } else {
+            sea_query::Condition::all()
+        };
+        let mut is_const = true;
+        for expr in self.conditions {
+            let lowered = match expr {
+                ConditionExpression::Condition(c) => c.lower(),
+                ConditionExpression::SimpleExpr(e) => Lowered::Expr(e.into()),
+                ConditionExpression::SeaQuery(c) => Lowered::Expr(c.into()),
+            };
+            match lowered {
+                Lowered::Const(b) if b == absorbing => return Lowered::Const(b != self.negate),
+                Lowered::Const(_) => {}
+                Lowered::Expr(e) => {
+                    condition = condition.add(e);
+                    is_const = false;
+                }
+            }
+        }
+        if is_const {
+            Lowered::Const(absorbing == self.negate)
+        } else if self.negate {
+            Lowered::Expr(condition.not().into())
+        } else {
+            Lowered::Expr(condition.into())
+        }
+    }
+}
+
+impl IntoCondition for Condition {
+    fn into_condition(self) -> sea_query::Condition {
+        match self.lower() {
+            Lowered::Const(true) => sea_query::Condition::all(),
+            Lowered::Const(false) => sea_query::Condition::all().add(Expr::val(1).eq(2)),
+            Lowered::Expr(e) => sea_query::Condition::all().add(e),
+        }
+    }
+}
+
+impl From<Condition> for sea_query::ConditionExpression {
+    fn from(condition: Condition) -> Self {
+        condition.into_condition().into()
+    }
+}
+
+impl From<Condition> for ConditionExpression {
+    fn from(condition: Condition) -> Self {
+        ConditionExpression::Condition(condition)
+    }
+}
+
+impl From<SimpleExpr> for ConditionExpression {
+    fn from(expr: SimpleExpr) -> Self {
+        ConditionExpression::SimpleExpr(expr)
+    }
+}
+
+impl From<sea_query::Condition> for ConditionExpression {
+    fn from(condition: sea_query::Condition) -> Self {
+        ConditionExpression::SeaQuery(condition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::cake;
+    use crate::{ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QueryTrait};
+    use pretty_assertions::assert_eq;
+
+    fn find_cake_where(condition: Condition) -> String {
+        cake::Entity::find()
+            .filter(condition)
+            .build(DbBackend::MySql)
+            .to_string()
+    }
+
+    #[test]
+    fn condition_nested() {
+        assert_eq!(
+            find_cake_where(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(cake::Column::Id.gte(1))
+                            .add(cake::Column::Name.like("%cheese%"))
+                    )
+                    .add(cake::Column::Id.eq(5))
+            ),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (`cake`.`id` >= 1 AND `cake`.`name` LIKE '%cheese%') OR `cake`.`id` = 5",
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            find_cake_where(
+                Condition::all()
+                    .add(
+                        Condition::any()
+                            .add(cake::Column::Id.eq(1))
+                            .add(cake::Column::Id.eq(2))
+                    )
+                    .add(
+                        Condition::any()
+                            .add(cake::Column::Name.eq("a"))
+                            .add(cake::Column::Name.eq("b"))
+                            .not()
+                    )
+            ),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (`cake`.`id` = 1 OR `cake`.`id` = 2)",
+                "AND (NOT (`cake`.`name` = 'a' OR `cake`.`name` = 'b'))",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn condition_chained_filter() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(
+                    Condition::any()
+                        .add(cake::Column::Id.eq(1))
+                        .add(cake::Column::Id.eq(2))
+                )
+                .filter(cake::Column::Name.eq("a"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (`cake`.`id` = 1 OR `cake`.`id` = 2) AND `cake`.`name` = 'a'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn condition_empty() {
+        assert_eq!(
+            find_cake_where(Condition::all()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+        assert_eq!(
+            find_cake_where(Condition::any()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE 1 = 2"
+        );
+        assert_eq!(
+            find_cake_where(Condition::all().not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE 1 = 2"
+        );
+        assert_eq!(
+            find_cake_where(Condition::any().not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+    }
+
+    #[test]
+    fn condition_empty_nested() {
+        assert_eq!(
+            find_cake_where(
+                Condition::all()
+                    .add(cake::Column::Id.eq(1))
+                    .add(Condition::all())
+            ),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1"
+        );
+        assert_eq!(
+            find_cake_where(
+                Condition::all()
+                    .add(cake::Column::Id.eq(1))
+                    .add(Condition::any())
+            ),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE 1 = 2"
+        );
+        assert_eq!(
+            find_cake_where(
+                Condition::any()
+                    .add(cake::Column::Id.eq(1))
+                    .add(Condition::any())
+            ),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1"
+        );
+        assert_eq!(
+            find_cake_where(
+                Condition::any()
+                    .add(cake::Column::Id.eq(1))
+                    .add(Condition::all())
+            ),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+    }
+
+    #[test]
+    fn condition_optional_filters() {
+        let search = |id: Option<i32>, name: Option<&str>| {
+            find_cake_where(
+                Condition::all()
+                    .add_option(id.map(|id| cake::Column::Id.eq(id)))
+                    .add_option(name.map(|name| cake::Column::Name.contains(name))),
+            )
+        };
+
+        assert_eq!(
+            search(None, None),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+        assert_eq!(
+            search(Some(1), None),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1"
+        );
+        assert_eq!(
+            search(Some(1), Some("cheese")),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn condition_is_null() {
+        assert_eq!(
+            find_cake_where(
+                Condition::any()
+                    .add(cake::Column::Name.eq(Option::<String>::None))
+                    .add(cake::Column::Name.ne(Option::<String>::None))
+            ),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`name` IS NULL OR `cake`.`name` IS NOT NULL",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            find_cake_where(Condition::all().add(cake::Column::Name.eq(Some("a")))),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` = 'a'"
+        );
+    }
+
+    #[test]
+    fn condition_exists() {
+        use crate::tests_cfg::fruit;
+        use crate::{Statement, Value};
+        use sea_query::Expr;
+
+        let fruits_of_cake = |name: &str| {
+            fruit::Entity::find()
+                .filter(
+                    Expr::tbl(fruit::Entity, fruit::Column::CakeId)
+                        .equals(cake::Entity, cake::Column::Id),
+                )
+                .filter(fruit::Column::Name.like(name))
+        };
+
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Name.like("%cheese%"))
+                .filter(
+                    Condition::any()
+                        .add(Condition::exists(fruits_of_cake("%apple%")))
+                        .add(Condition::not_exists(fruits_of_cake("%berry%")))
+                )
+                .filter(cake::Column::Id.gt(2))
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &[
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"WHERE "cake"."name" LIKE $1"#,
+                    r#"AND (1 IN (SELECT 1 FROM "fruit" WHERE "fruit"."cake_id" = "cake"."id" AND "fruit"."name" LIKE $2)"#,
+                    r#"OR 1 NOT IN (SELECT 1 FROM "fruit" WHERE "fruit"."cake_id" = "cake"."id" AND "fruit"."name" LIKE $3))"#,
+                    r#"AND "cake"."id" > $4"#,
+                ]
+                .join(" "),
+                vec![
+                    Value::from("%cheese%"),
+                    Value::from("%apple%"),
+                    Value::from("%berry%"),
+                    Value::from(2),
+                ]
+            )
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{DbBackend, EntityTrait, Select, Statement};
+use sea_query::{
+    Alias, DynIden, Iden, QueryBuilder, SeaRc, SelectStatement, SqlWriter, Value, Values,
+};
+use std::fmt::Write;
+
+/// The common table expressions of a select, written as a `WITH` clause in front of it
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WithClause {
+    recursive: bool,
+    ctes: Vec<(DynIden, SelectStatement)>,
+}
+
+impl WithClause {
+    fn push(&mut self, name: DynIden, query: SelectStatement, recursive: bool) {
+        self.recursive |= recursive;
+        self.ctes.push((name, query));
+    }
+
+    /// Take over the common table expressions of another select
+    pub(crate) fn append(&mut self, other: WithClause) {
+        self.recursive |= other.recursive;
+        self.ctes.extend(other.ctes);
+    }
+
+    /// Build `query` behind the `WITH` clause. The values of the common table expressions
+    /// are bound ahead of the ones of `query`, in the order they appear in the SQL.
+    pub(crate) fn build(&self, db_backend: DbBackend, query: &SelectStatement) -> Statement {
+        if self.ctes.is_empty() {
+            return db_backend.build(query);
+        }
+        self.build_with(db_backend, |query_builder, sql, collector| {
+            query_builder.prepare_select_statement(query, sql, collector);
+        })
+    }
+
+    /// Build `SELECT EXISTS(query) AS "exists"` behind the `WITH` clause
+    pub(crate) fn build_exists(&self, db_backend: DbBackend, query: &SelectStatement) -> Statement {
+        self.build_with(db_backend, |query_builder, sql, collector| {
+            write!(sql, "SELECT EXISTS(").unwrap();
+            query_builder.prepare_select_statement(query, sql, collector);
+            write!(sql, ") AS ").unwrap();
+            Alias::new("exists").prepare(sql, query_builder.quote());
+        })
+    }
+
+    fn build_with<F>(&self, db_backend: DbBackend, write_query: F) -> Statement
+    where
+        F: FnOnce(&dyn QueryBuilder, &mut SqlWriter, &mut dyn FnMut(Value)),
+    {
+        let query_builder = db_backend.get_query_builder();
+        let mut sql = SqlWriter::new();
+        let mut values = Vec::new();
+        let mut collector = |value| values.push(value);
+        self.prepare(query_builder.as_ref(), &mut sql, &mut collector);
+        write_query(query_builder.as_ref(), &mut sql, &mut collector);
+        Statement::from_string_values_tuple(db_backend, (sql.result(), Values(values)))
+    }
+
+    /// Write the `WITH` clause, followed by a space, if there is any common table expression
+    pub(crate) fn prepare(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if self.ctes.is_empty() {
+            return;
+        }
+        write!(sql, "WITH ").unwrap();
+        if self.recursive {
+            write!(sql, "RECURSIVE ").unwrap();
+        }
+        for (i, (name, cte)) in self.ctes.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, query_builder.quote());
+            write!(sql, " AS (").unwrap();
+            query_builder.prepare_select_statement(cte, sql, collector);
+            write!(sql, ") ").unwrap();
+        }
+    }
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Define the common table expression `name` as `query`, in a `WITH` clause in front
+    /// of the select. It can be referenced by name in subqueries and joins, or become the
+    /// source of the entity with [`from_cte`](Self::from_cte).
+    ///
+    /// The clause is kept when the select is built, executed, paginated or turned into
+    /// a cursor, but not by [`into_query`](crate::QueryTrait::into_query).
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    /// use sea_query::{Alias, Query};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .with(
+    ///             "fruity",
+    ///             Query::select()
+    ///                 .column(fruit::Column::CakeId)
+    ///                 .from(fruit::Entity)
+    ///                 .and_where(fruit::Column::Name.contains("apple"))
+    ///                 .to_owned()
+    ///         )
+    ///         .filter(
+    ///             cake::Column::Id.in_subquery(
+    ///                 Query::select()
+    ///                     .column(fruit::Column::CakeId)
+    ///                     .from(Alias::new("fruity"))
+    ///                     .to_owned()
+    ///             )
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH "fruity" AS (SELECT "cake_id" FROM "fruit" WHERE "fruit"."name" LIKE '%apple%')"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."id" IN (SELECT "cake_id" FROM "fruity")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn with(mut self, name: &str, query: SelectStatement) -> Self {
+        self.with.push(SeaRc::new(Alias::new(name)), query, false);
+        self
+    }
+
+    /// Like [`with`](Self::with), but the common table expression may reference itself,
+    /// e.g. to walk a tree of categories down from its root. The `WITH` clause becomes
+    /// `WITH RECURSIVE`, which covers all of its expressions.
+    pub fn with_recursive(mut self, name: &str, query: SelectStatement) -> Self {
+        self.with.push(SeaRc::new(Alias::new(name)), query, true);
+        self
+    }
+
+    /// Select the rows of the entity from the common table expression `name`
+    /// instead of its table. The expression has to return the columns of the entity.
+    pub fn from_cte(mut self, name: &str) -> Self {
+        self.query.from_as(Alias::new(name), E::default());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryTrait, Values};
+    use pretty_assertions::assert_eq;
+    use sea_query::{Alias, Expr, Query, UnionType};
+
+    #[test]
+    fn with_cte() {
+        let stmt = cake::Entity::find()
+            .with(
+                "sweet",
+                Query::select()
+                    .columns(vec![cake::Column::Id, cake::Column::Name])
+                    .from(cake::Entity)
+                    .and_where(cake::Column::Name.like("%chocolate%"))
+                    .to_owned(),
+            )
+            .from_cte("sweet")
+            .filter(cake::Column::Id.gt(2))
+            .build(DbBackend::Postgres);
+
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"WITH "sweet" AS (SELECT "id", "name" FROM "cake" WHERE "cake"."name" LIKE $1)"#,
+                r#"SELECT "cake"."id", "cake"."name" FROM "sweet" AS "cake" WHERE "cake"."id" > $2"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec!["%chocolate%".into(), 2i32.into()]))
+        );
+    }
+
+    #[test]
+    fn with_recursive_cte() {
+        let ids = Query::select()
+            .expr_as(Expr::val(1), Alias::new("id"))
+            .union(
+                UnionType::All,
+                Query::select()
+                    .expr(Expr::col(Alias::new("id")).add(1))
+                    .from(Alias::new("ids"))
+                    .and_where(Expr::col(Alias::new("id")).lt(3))
+                    .to_owned(),
+            )
+            .to_owned();
+
+        let stmt = fruit::Entity::find()
+            .with_recursive("ids", ids)
+            .filter(
+                fruit::Column::CakeId.in_subquery(
+                    Query::select()
+                        .column(Alias::new("id"))
+                        .from(Alias::new("ids"))
+                        .to_owned(),
+                ),
+            )
+            .filter(fruit::Column::Name.eq("Apple"))
+            .build(DbBackend::MySql);
+
+        assert_eq!(
+            stmt.sql,
+            [
+                "WITH RECURSIVE `ids` AS (SELECT ? AS `id` UNION ALL SELECT `id` + ? FROM `ids` WHERE `id` < ?)",
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`cake_id` IN (SELECT `id` FROM `ids`) AND `fruit`.`name` = ?",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec![
+                1i32.into(),
+                1i32.into(),
+                3i32.into(),
+                "Apple".into()
+            ]))
+        );
+    }
+}
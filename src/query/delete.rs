@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    ActiveModelTrait, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
+    PrimaryKeyToColumn, QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{DeleteStatement, IntoIden};
+use sea_query::DeleteStatement;
 
 #[derive(Clone, Debug)]
 pub struct Delete;
@@ -91,7 +91,7 @@ impl Delete {
     {
         DeleteMany {
             query: DeleteStatement::new()
-                .from_table(entity.into_iden())
+                .from_table(entity.table_ref())
                 .to_owned(),
             entity: PhantomData,
         }
@@ -114,6 +114,25 @@ where
         }
         self
     }
+
+    /// Delete from the entity's table in the given schema instead of its default one
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query
+            .from_table(A::Entity::default().table_ref_in_schema(schema));
+        self
+    }
+}
+
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Delete from the entity's table in the given schema instead of its default one
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query
+            .from_table(E::default().table_ref_in_schema(schema));
+        self
+    }
 }
 
 impl<A> QueryFilter for DeleteOne<A>
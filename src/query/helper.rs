@@ -2,8 +2,12 @@ use crate::{
     ColumnTrait, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable, ModelTrait,
     PrimaryKeyToColumn, RelationDef,
 };
-pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
-use sea_query::{Expr, IntoCondition, LockType, SeaRc, SelectExpr, SelectStatement, SimpleExpr, TableRef};
+use sea_query::{
+    inject_parameters, Condition, Expr, IntoCondition, IntoIden, LockType, MysqlQueryBuilder,
+    PostgresQueryBuilder, QueryBuilder, SeaRc, SelectExpr, SelectStatement, SimpleExpr, SqlWriter,
+    TableRef,
+};
+pub use sea_query::{ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
 // LINT: when the column does not appear in tables selected from
 // LINT: when there is a group by clause, but some columns don't have aggregate functions
@@ -40,6 +44,32 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add select columns, in order. Filtering and ordering may still refer to any column.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .columns(vec![cake::Column::Id, cake::Column::Name])
+    ///         .filter(cake::Column::Id.gt(1))
+    ///         .order_by_desc(cake::Column::Id)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > 1 ORDER BY "cake"."id" DESC"#
+    /// );
+    /// ```
+    fn columns<C, I>(mut self, cols: I) -> Self
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = C>,
+    {
+        for col in cols.into_iter() {
+            self.query().expr(col.into_simple_expr());
+        }
+        self
+    }
+
     /// Add a select column with alias
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
@@ -65,6 +95,101 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a select expression
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .expr(Expr::col(cake::Column::Id).max())
+    ///         .expr(Expr::col(cake::Column::Id).add(1))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT MAX("id"), "id" + 1 FROM "cake""#
+    /// );
+    /// ```
+    fn expr<T>(mut self, expr: T) -> Self
+    where
+        T: Into<SelectExpr>,
+    {
+        self.query().expr(expr);
+        self
+    }
+
+    /// Select distinct rows
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let select = cake::Entity::find()
+    ///     .select_only()
+    ///     .column(cake::Column::Name)
+    ///     .distinct();
+    ///
+    /// assert_eq!(
+    ///     select.build(DbBackend::MySql).to_string(),
+    ///     "SELECT DISTINCT `cake`.`name` FROM `cake`"
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT DISTINCT "cake"."name" FROM "cake""#
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Sqlite).to_string(),
+    ///     "SELECT DISTINCT `cake`.`name` FROM `cake`"
+    /// );
+    /// ```
+    fn distinct(mut self) -> Self {
+        self.query().distinct();
+        self
+    }
+
+    /// Select the first row of each group of rows sharing the same values in `cols`,
+    /// with the first row decided by the ORDER BY clause. The ORDER BY must start with `cols`.
+    ///
+    /// `DISTINCT ON` is only supported by Postgres; other backends will reject the statement.
+    /// It is attached to the first selected column, so call it after [`select_only`](Self::select_only).
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .distinct_on(vec![fruit::Column::CakeId])
+    ///         .order_by_asc(fruit::Column::CakeId)
+    ///         .order_by_desc(fruit::Column::Id)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT DISTINCT ON ("fruit"."cake_id") "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+    ///         r#"FROM "fruit" ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn distinct_on<T, I>(mut self, cols: I) -> Self
+    where
+        T: IntoSimpleExpr,
+        I: IntoIterator<Item = T>,
+    {
+        let cols: Vec<String> = cols
+            .into_iter()
+            .map(|col| prepare_postgres_expr(&col.into_simple_expr()))
+            .collect();
+        let distinct_on = format!("DISTINCT ON ({})", cols.join(", "));
+        let mut first = true;
+        self.query().exprs_mut_for_each(|select| {
+            if first {
+                select.expr = Expr::cust(&format!(
+                    "{} {}",
+                    distinct_on,
+                    prepare_postgres_expr(&select.expr)
+                ));
+                first = false;
+            }
+        });
+        self
+    }
+
     /// Add a group by column
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
@@ -141,23 +266,185 @@ pub trait QuerySelect: Sized {
         self
     }
 
-    /// Select lock
+    /// Join via [`RelationDef`] with table alias, such that the same table can be joined
+    /// more than once.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Alias, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .join_as(
+    ///             JoinType::LeftJoin,
+    ///             cake::Relation::Fruit.def(),
+    ///             Alias::new("fruit_1"),
+    ///         )
+    ///         .join_as(
+    ///             JoinType::InnerJoin,
+    ///             cake::Relation::Fruit.def(),
+    ///             Alias::new("fruit_2"),
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+    ///         "LEFT JOIN `fruit` AS `fruit_1` ON `cake`.`id` = `fruit_1`.`cake_id`",
+    ///         "INNER JOIN `fruit` AS `fruit_2` ON `cake`.`id` = `fruit_2`.`cake_id`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn join_as<I>(mut self, join: JoinType, rel: RelationDef, alias: I) -> Self
+    where
+        I: IntoIden,
+    {
+        let alias = alias.into_iden();
+        let from_tbl = unpack_table_ref(&rel.from_tbl);
+        let condition =
+            join_tbl_on_condition(from_tbl, SeaRc::clone(&alias), rel.from_col, rel.to_col);
+        self.query().join_as(join, rel.to_tbl, alias, condition);
+        self
+    }
+
+    /// Join via [`RelationDef`] with table alias but in reverse direction.
+    /// Assume when there exist a relation A to B.
+    /// You can reverse join B from A.
+    fn join_as_rev<I>(mut self, join: JoinType, rel: RelationDef, alias: I) -> Self
+    where
+        I: IntoIden,
+    {
+        let alias = alias.into_iden();
+        let to_tbl = unpack_table_ref(&rel.to_tbl);
+        let condition =
+            join_tbl_on_condition(SeaRc::clone(&alias), to_tbl, rel.from_col, rel.to_col);
+        self.query().join_as(join, rel.from_tbl, alias, condition);
+        self
+    }
+
+    /// Select lock, to lock the selected rows until the end of the current transaction.
+    /// SQLite has no row locking, so the lock clause is omitted there.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::LockType, tests_cfg::cake, DbBackend};
+    ///
+    /// let select = cake::Entity::find()
+    ///     .filter(cake::Column::Id.eq(1))
+    ///     .lock(LockType::Exclusive);
+    ///
+    /// assert_eq!(
+    ///     select.build(DbBackend::MySql).to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1 FOR UPDATE"
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1 FOR UPDATE"#
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Sqlite).to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1 "
+    /// );
+    /// ```
     fn lock(mut self, lock_type: LockType) -> Self {
         self.query().lock(lock_type);
         self
     }
 
     /// Select lock shared
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_shared()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR SHARE"#
+    /// );
+    /// ```
     fn lock_shared(mut self) -> Self {
         self.query().lock_shared();
         self
     }
 
     /// Select lock exclusive
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_exclusive()
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` FOR UPDATE"
+    /// );
+    /// ```
     fn lock_exclusive(mut self) -> Self {
         self.query().lock_exclusive();
         self
     }
+
+    /// Return at most `limit` rows
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .order_by_desc(cake::Column::Id)
+    ///         .limit(10)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` ORDER BY `cake`.`id` DESC LIMIT 10"
+    /// );
+    /// ```
+    fn limit(mut self, limit: u64) -> Self {
+        self.query().limit(limit);
+        self
+    }
+
+    /// Skip the first `offset` rows. MySQL and SQLite only take an offset along with a limit,
+    /// so without a [`limit`](Self::limit) the largest one every backend accepts is set.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .limit(10)
+    ///         .offset(20)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT 10 OFFSET 20"#
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .offset(20)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` LIMIT 9223372036854775807 OFFSET 20"
+    /// );
+    /// ```
+    fn offset(mut self, offset: u64) -> Self {
+        let query = self.query();
+        if !has_limit(query) {
+            query.limit(i64::MAX as u64);
+        }
+        query.offset(offset);
+        self
+    }
+}
+
+/// Whether a limit is set on the query; sea-query does not expose it,
+/// but the limit is always bound as a value of its own
+fn has_limit(query: &SelectStatement) -> bool {
+    let mut unlimited = query.clone();
+    unlimited.reset_limit();
+    let (_, values) = query.build(MysqlQueryBuilder);
+    let (_, unlimited_values) = unlimited.build(MysqlQueryBuilder);
+    values.0.len() != unlimited_values.0.len()
+}
+
+/// Where NULL values are placed in an ordering
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NullOrdering {
+    First,
+    Last,
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -183,7 +470,7 @@ pub trait QueryOrder: Sized {
     where
         C: IntoSimpleExpr,
     {
-        self.query().order_by_expr(col.into_simple_expr(), ord);
+        self.add_order_by(col.into_simple_expr(), ord);
         self
     }
 
@@ -203,8 +490,7 @@ pub trait QueryOrder: Sized {
     where
         C: IntoSimpleExpr,
     {
-        self.query()
-            .order_by_expr(col.into_simple_expr(), Order::Asc);
+        self.add_order_by(col.into_simple_expr(), Order::Asc);
         self
     }
 
@@ -224,10 +510,66 @@ pub trait QueryOrder: Sized {
     where
         C: IntoSimpleExpr,
     {
-        self.query()
-            .order_by_expr(col.into_simple_expr(), Order::Desc);
+        self.add_order_by(col.into_simple_expr(), Order::Desc);
         self
     }
+
+    /// Add an order_by expression with NULL values placed first or last.
+    /// It orders by whether the value is NULL before ordering by the value,
+    /// which gives the same placement on every backend.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let select = fruit::Entity::find()
+    ///     .order_by_with_nulls(fruit::Column::CakeId, Order::Asc, NullOrdering::Last)
+    ///     .order_by_asc(fruit::Column::Id);
+    ///
+    /// assert_eq!(
+    ///     select.build(DbBackend::MySql).to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+    ///         "ORDER BY `fruit`.`cake_id` IS NULL ASC, `fruit`.`cake_id` ASC, `fruit`.`id` ASC",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Postgres).to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"ORDER BY "fruit"."cake_id" IS NULL ASC, "fruit"."cake_id" ASC, "fruit"."id" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .order_by_with_nulls(fruit::Column::CakeId, Order::Desc, NullOrdering::First)
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+    ///         "ORDER BY `fruit`.`cake_id` IS NULL DESC, `fruit`.`cake_id` DESC",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn order_by_with_nulls<C>(mut self, col: C, ord: Order, nulls: NullOrdering) -> Self
+    where
+        C: IntoSimpleExpr,
+    {
+        let expr = col.into_simple_expr();
+        let nulls_ord = match nulls {
+            NullOrdering::First => Order::Desc,
+            NullOrdering::Last => Order::Asc,
+        };
+        self.add_order_by(Expr::expr(expr.clone()).is_null(), nulls_ord);
+        self.add_order_by(expr, ord);
+        self
+    }
+
+    #[doc(hidden)]
+    fn add_order_by(&mut self, expr: SimpleExpr, ord: Order) {
+        self.query().order_by_expr(expr, ord);
+    }
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -266,11 +608,35 @@ pub trait QueryFilter: Sized {
     ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 4 OR `cake`.`id` = 5"
     /// );
     /// ```
+    ///
+    /// Clone a base query to derive variants of it; the conditions of a
+    /// [`Select`](crate::Select) can be cleared with [`reset_filter`](crate::Select::reset_filter).
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let base = cake::Entity::find().filter(cake::Column::Name.contains("chocolate"));
+    ///
+    /// assert_eq!(
+    ///     base.clone()
+    ///         .filter(cake::Column::Id.gt(4))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%chocolate%' AND `cake`.`id` > 4"
+    /// );
+    /// assert_eq!(
+    ///     base.reset_filter()
+    ///         .filter(cake::Column::Id.eq(4))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 4"
+    /// );
+    /// ```
     fn filter<F>(mut self, filter: F) -> Self
     where
         F: IntoCondition,
     {
-        self.query().cond_where(filter.into_condition());
+        // Wrapped, so that a top level `Condition::any` is not extended by later filters
+        self.add_condition(Condition::all().add(filter.into_condition()));
         self
     }
 
@@ -285,14 +651,33 @@ pub trait QueryFilter: Sized {
         }
         self
     }
+
+    #[doc(hidden)]
+    fn add_condition(&mut self, condition: Condition) {
+        self.query().cond_where(condition);
+    }
+}
+
+fn prepare_postgres_expr(expr: &SimpleExpr) -> String {
+    let mut sql = SqlWriter::new();
+    let mut values = Vec::new();
+    PostgresQueryBuilder.prepare_simple_expr(expr, &mut sql, &mut |value| values.push(value));
+    inject_parameters(&sql.result(), values, &PostgresQueryBuilder)
 }
 
 fn join_condition(rel: RelationDef) -> SimpleExpr {
     let from_tbl = unpack_table_ref(&rel.from_tbl);
     let to_tbl = unpack_table_ref(&rel.to_tbl);
-    let owner_keys = rel.from_col;
-    let foreign_keys = rel.to_col;
 
+    join_tbl_on_condition(from_tbl, to_tbl, rel.from_col, rel.to_col)
+}
+
+fn join_tbl_on_condition(
+    from_tbl: DynIden,
+    to_tbl: DynIden,
+    owner_keys: Identity,
+    foreign_keys: Identity,
+) -> SimpleExpr {
     match (owner_keys, foreign_keys) {
         (Identity::Unary(o1), Identity::Unary(f1)) => {
             Expr::tbl(SeaRc::clone(&from_tbl), o1).equals(SeaRc::clone(&to_tbl), f1)
@@ -321,3 +706,286 @@ pub(crate) fn unpack_table_ref(table_ref: &TableRef) -> DynIden {
         TableRef::SubQuery(_, tbl) => SeaRc::clone(tbl),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    };
+    use sea_query::{Alias, Condition, Expr, MysqlQueryBuilder};
+
+    fn fruits_per_cake() -> crate::Select<fruit::Entity> {
+        fruit::Entity::find()
+            .select_only()
+            .column(fruit::Column::CakeId)
+            .column_as(fruit::Column::Id.count(), "num_fruits")
+            .filter(fruit::Column::Name.like("%berry"))
+            .group_by(fruit::Column::CakeId)
+            .having(Expr::expr(fruit::Column::Id.count()).gt(1))
+            .order_by_desc(Expr::col(Alias::new("num_fruits")))
+    }
+
+    #[test]
+    fn group_by_having_1() {
+        assert_eq!(
+            fruits_per_cake().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `fruit`.`cake_id`, COUNT(`fruit`.`id`) AS `num_fruits` FROM `fruit`",
+                "WHERE `fruit`.`name` LIKE '%berry'",
+                "GROUP BY `fruit`.`cake_id`",
+                "HAVING COUNT(`fruit`.`id`) > 1",
+                "ORDER BY `num_fruits` DESC",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn group_by_having_2() {
+        assert_eq!(
+            fruits_per_cake().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_fruits" FROM "fruit""#,
+                r#"WHERE "fruit"."name" LIKE '%berry'"#,
+                r#"GROUP BY "fruit"."cake_id""#,
+                r#"HAVING COUNT("fruit"."id") > 1"#,
+                r#"ORDER BY "num_fruits" DESC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn group_by_having_3() {
+        assert_eq!(
+            fruits_per_cake().build(DbBackend::Sqlite).to_string(),
+            [
+                "SELECT `fruit`.`cake_id`, COUNT(`fruit`.`id`) AS `num_fruits` FROM `fruit`",
+                "WHERE `fruit`.`name` LIKE '%berry'",
+                "GROUP BY `fruit`.`cake_id`",
+                "HAVING COUNT(`fruit`.`id`) > 1",
+                "ORDER BY `num_fruits` DESC",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn group_by_alias() {
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column_as(Expr::col(fruit::Column::Name).if_null(""), "fruit_name")
+                .column_as(fruit::Column::Id.count(), "num_fruits")
+                .group_by(Expr::col(Alias::new("fruit_name")))
+                .having(Expr::col(Alias::new("num_fruits")).gte(2))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT COALESCE("name", '') AS "fruit_name", COUNT("fruit"."id") AS "num_fruits" FROM "fruit""#,
+                r#"GROUP BY "fruit_name""#,
+                r#"HAVING "num_fruits" >= 2"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn apply_if() {
+        let search = |name: Option<&str>, cake_id: Option<i32>, max_id: Option<i32>| {
+            fruit::Entity::find()
+                .apply_if(name, |query, name| {
+                    query.filter(fruit::Column::Name.contains(name))
+                })
+                .apply_if(cake_id, |query, cake_id| {
+                    query.filter(fruit::Column::CakeId.eq(cake_id))
+                })
+                .apply_if(max_id, |query, max_id| query.filter(fruit::Column::Id.lte(max_id)))
+                .build(DbBackend::MySql)
+                .to_string()
+        };
+
+        assert_eq!(
+            search(Some("Apple"), None, Some(10)),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`name` LIKE '%Apple%' AND `fruit`.`id` <= 10",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            search(None, Some(1), None),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "WHERE `fruit`.`cake_id` = 1",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            search(None, None, None),
+            "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`"
+        );
+    }
+
+    #[test]
+    fn limit_offset() {
+        let select = |backend| {
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .offset(5)
+                .limit(10)
+                .build(backend)
+                .to_string()
+        };
+        assert_eq!(
+            select(DbBackend::MySql),
+            "SELECT `fruit`.`id` FROM `fruit` LIMIT 10 OFFSET 5"
+        );
+        assert_eq!(
+            select(DbBackend::Postgres),
+            r#"SELECT "fruit"."id" FROM "fruit" LIMIT 10 OFFSET 5"#
+        );
+        assert_eq!(
+            select(DbBackend::Sqlite),
+            "SELECT `fruit`.`id` FROM `fruit` LIMIT 10 OFFSET 5"
+        );
+    }
+
+    #[test]
+    fn offset_without_limit() {
+        let select = |backend| {
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .offset(5)
+                .build(backend)
+                .to_string()
+        };
+        assert_eq!(
+            select(DbBackend::MySql),
+            "SELECT `fruit`.`id` FROM `fruit` LIMIT 9223372036854775807 OFFSET 5"
+        );
+        assert_eq!(
+            select(DbBackend::Postgres),
+            r#"SELECT "fruit"."id" FROM "fruit" LIMIT 9223372036854775807 OFFSET 5"#
+        );
+        assert_eq!(
+            select(DbBackend::Sqlite),
+            "SELECT `fruit`.`id` FROM `fruit` LIMIT 9223372036854775807 OFFSET 5"
+        );
+
+        // A limit set before the offset is kept
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::Id)
+                .limit(3)
+                .offset(5)
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `fruit`.`id` FROM `fruit` LIMIT 3 OFFSET 5"
+        );
+    }
+
+    #[test]
+    fn filter_accumulates_with_and() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(
+                    Condition::any()
+                        .add(cake::Column::Id.eq(4))
+                        .add(cake::Column::Id.eq(5))
+                )
+                .filter(cake::Column::Name.contains("chocolate"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (`cake`.`id` = 4 OR `cake`.`id` = 5) AND `cake`.`name` LIKE '%chocolate%'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn reset_filter_and_order() {
+        let base = cake::Entity::find()
+            .filter(cake::Column::Name.contains("chocolate"))
+            .filter(cake::Column::Id.gt(4))
+            .order_by_asc(cake::Column::Name)
+            .limit(10);
+
+        assert_eq!(
+            base.clone().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`name` LIKE '%chocolate%' AND `cake`.`id` > 4",
+                "ORDER BY `cake`.`name` ASC LIMIT 10",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            base.clone()
+                .reset_filter()
+                .filter(cake::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`id` = 1 ORDER BY `cake`.`name` ASC LIMIT 10",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            base.clone()
+                .reset_order()
+                .order_by_desc(cake::Column::Id)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`name` LIKE '%chocolate%' AND `cake`.`id` > 4",
+                "ORDER BY `cake`.`id` DESC LIMIT 10",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            base.reset_filter()
+                .reset_order()
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` LIMIT 10"
+        );
+    }
+
+    #[test]
+    fn reset_filter_keeps_statement_conditions() {
+        let mut select = cake::Entity::find().filter(cake::Column::Name.contains("chocolate"));
+        QueryTrait::query(&mut select).and_where(Expr::col(cake::Column::Id).lt(10));
+
+        assert_eq!(
+            select.clone().into_query().to_string(MysqlQueryBuilder),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `id` < 10 AND `cake`.`name` LIKE '%chocolate%'",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select
+                .reset_filter()
+                .find_also_related(fruit::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`",
+                "FROM `cake` LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+                "WHERE `id` < 10",
+            ]
+            .join(" ")
+        );
+    }
+}
@@ -1,6 +1,13 @@
-use crate::{ActiveModelTrait, EntityName, EntityTrait, IntoActiveModel, Iterable, QueryTrait};
+use super::{cte::WithClause, union::column_count};
+use crate::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbBackend, DbErr, EntityName, EntityTrait,
+    IdenStatic, IntoActiveModel, Iterable, PrimaryKeyToColumn, QueryTrait, Select, Statement,
+};
 use core::marker::PhantomData;
-use sea_query::InsertStatement;
+use sea_query::{
+    Expr, Iden, InsertStatement, SelectStatement, SimpleExpr, SqlWriter, Value, Values,
+};
+use std::fmt::Write;
 
 #[derive(Clone, Debug)]
 pub struct Insert<A>
@@ -9,9 +16,27 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
+    pub(crate) columns_overridden: bool,
+    pub(crate) defaults: Vec<bool>,
+    pub(crate) rows: Vec<Vec<ActiveValue<Value>>>,
+    pub(crate) schema: Option<String>,
+    pub(crate) on_conflict_do_nothing: bool,
+    pub(crate) primary_key: Option<Vec<Value>>,
+    pub(crate) sensitive: Vec<Value>,
     pub(crate) model: PhantomData<A>,
 }
 
+/// Insert the rows returned by a select, see [`Insert::from_select`]
+#[derive(Clone, Debug)]
+pub struct InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) columns: Vec<E::Column>,
+    pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
+}
+
 impl<A> Default for Insert<A>
 where
     A: ActiveModelTrait,
@@ -31,6 +56,13 @@ where
                 .into_table(A::Entity::default().table_ref())
                 .to_owned(),
             columns: Vec::new(),
+            columns_overridden: false,
+            defaults: Vec::new(),
+            rows: Vec::new(),
+            schema: None,
+            on_conflict_do_nothing: false,
+            primary_key: None,
+            sensitive: Vec::new(),
             model: PhantomData,
         }
     }
@@ -101,31 +133,108 @@ where
         Self::new().add_many(models)
     }
 
+    /// Insert the rows returned by `select` in one `INSERT INTO .. SELECT` statement.
+    /// The columns of the select fill `columns` by position, so select them in the same
+    /// order, e.g. with [`select_only`](crate::QuerySelect::select_only). The values bound
+    /// by the select are kept. Also available as [`EntityTrait::insert_from_select`].
+    /// Fails with [`DbErr::Query`] if the select does not return as many columns as are listed.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// # fn main() -> Result<(), sea_orm::DbErr> {
+    /// assert_eq!(
+    ///     Insert::<fruit::ActiveModel>::from_select(
+    ///         [fruit::Column::Name, fruit::Column::CakeId],
+    ///         cake::Entity::find()
+    ///             .select_only()
+    ///             .column(cake::Column::Name)
+    ///             .column(cake::Column::Id)
+    ///             .filter(cake::Column::Name.contains("chocolate"))
+    ///     )?
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "fruit" ("name", "cake_id")"#,
+    ///         r#"SELECT "cake"."name", "cake"."id" FROM "cake" WHERE "cake"."name" LIKE '%chocolate%'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_select<C, S>(
+        columns: C,
+        select: Select<S>,
+    ) -> Result<InsertFromSelect<A::Entity>, DbErr>
+    where
+        C: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+        S: EntityTrait,
+    {
+        InsertFromSelect::new(columns, select)
+    }
+
+    /// Add a Model or ActiveModel to the insert. Columns left `Unset` are omitted
+    /// from the statement entirely, so the database default (e.g. an auto-increment
+    /// primary key or `DEFAULT now()`) applies instead of `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the model does not set the same columns as the models added before it.
     #[allow(clippy::should_implement_trait)]
     pub fn add<M>(mut self, m: M) -> Self
     where
         M: IntoActiveModel<A>,
     {
         let mut am: A = m.into_active_model();
+        self.primary_key = primary_key_of(&am);
+        let row: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+            .map(|col| am.take(col))
+            .collect();
+        if !self.columns_overridden {
+            let columns_empty = self.columns.is_empty();
+            for (idx, av) in row.iter().enumerate() {
+                let av_has_val = av.is_set() || av.is_unchanged() || self.is_default(idx);
+                if columns_empty {
+                    self.columns.push(av_has_val);
+                } else if self.columns[idx] != av_has_val {
+                    panic!("columns mismatch");
+                }
+            }
+        }
+        self.push_row(&row);
+        self.rows.push(row);
+        self
+    }
+
+    /// Write the values of the selected columns, with a `NULL` for a selected column the model leaves unset
+    fn push_row(&mut self, row: &[ActiveValue<Value>]) {
         let mut columns = Vec::new();
         let mut values = Vec::new();
-        let columns_empty = self.columns.is_empty();
-        for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
-            let av = am.take(col);
-            let av_has_val = av.is_set() || av.is_unchanged();
-            if columns_empty {
-                self.columns.push(av_has_val);
-            } else if self.columns[idx] != av_has_val {
-                panic!("columns mismatch");
-            }
-            if av_has_val {
-                columns.push(col);
-                values.push(av.into_value());
+        for (idx, ((col, av), selected)) in <A::Entity as EntityTrait>::Column::iter()
+            .zip(row)
+            .zip(self.columns.iter())
+            .enumerate()
+        {
+            if !selected {
+                continue;
             }
+            let value: SimpleExpr = if self.is_default(idx) {
+                Expr::cust("DEFAULT")
+            } else if av.is_set() || av.is_unchanged() {
+                let value = av.clone().into_value();
+                if col.def().sensitive {
+                    self.sensitive.push(value.clone());
+                }
+                col.save_as(value)
+            } else {
+                Expr::cust("NULL")
+            };
+            columns.push(col);
+            values.push(value);
         }
         self.query.columns(columns);
-        self.query.values_panic(values);
-        self
+        self.query.exprs_panic(values);
     }
 
     pub fn add_many<M, I>(mut self, models: I) -> Self
@@ -138,6 +247,261 @@ where
         }
         self
     }
+
+    /// Skip the insert instead of failing when it would violate a unique constraint,
+    /// as `ON CONFLICT DO NOTHING` on Postgres and SQLite and `INSERT IGNORE` on MySQL.
+    /// Use [`exec_without_returning`](Self::exec_without_returning) to find out
+    /// whether the rows were inserted.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let insert = Insert::one(cake::Model {
+    ///     id: 1,
+    ///     name: "Apple Pie".to_owned(),
+    /// })
+    /// .on_conflict_do_nothing();
+    ///
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Postgres).to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie') ON CONFLICT DO NOTHING"#,
+    /// );
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Sqlite).to_string(),
+    ///     "INSERT INTO `cake` (`id`, `name`) VALUES (1, 'Apple Pie') ON CONFLICT DO NOTHING",
+    /// );
+    /// assert_eq!(
+    ///     insert.build(DbBackend::MySql).to_string(),
+    ///     "INSERT IGNORE INTO `cake` (`id`, `name`) VALUES (1, 'Apple Pie')",
+    /// );
+    /// ```
+    pub fn on_conflict_do_nothing(mut self) -> Self {
+        self.on_conflict_do_nothing = true;
+        self
+    }
+
+    /// Insert into the entity's table in the given schema instead of its default one
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Insert::one(cake::ActiveModel {
+    ///         name: Set("Apple Pie".to_owned()),
+    ///         ..Default::default()
+    ///     })
+    ///     .into_schema("tenant_42")
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "tenant_42"."cake" ("name") VALUES ('Apple Pie')"#,
+    /// );
+    /// ```
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query
+            .into_table(A::Entity::default().table_ref_in_schema(schema));
+        self.schema = Some(schema.to_owned());
+        self
+    }
+
+    /// Insert exactly the given columns instead of those the models set. A listed column
+    /// the model leaves unset is inserted as `NULL` rather than the database default,
+    /// and a set column that is not listed is left out. Rewrites the statement, so call
+    /// it before modifying the underlying query.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Insert::one(cake::ActiveModel {
+    ///         id: Set(1),
+    ///         ..Default::default()
+    ///     })
+    ///     .columns([cake::Column::Name])
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake" ("name") VALUES (NULL)"#,
+    /// );
+    /// ```
+    pub fn columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+    {
+        let columns: Vec<String> = columns
+            .into_iter()
+            .map(|col| col.as_str().to_owned())
+            .collect();
+        self.columns = <A::Entity as EntityTrait>::Column::iter()
+            .map(|col| columns.iter().any(|name| name == col.as_str()))
+            .collect();
+        self.columns_overridden = true;
+        // Without every key column the inserted row is found by the last insert id instead
+        if <A::Entity as EntityTrait>::PrimaryKey::iter().any(|key| {
+            !columns
+                .iter()
+                .any(|name| name == key.into_column().as_str())
+        }) {
+            self.primary_key = None;
+        }
+        self.rebuild();
+        self
+    }
+
+    /// Insert `DEFAULT` for the column in every row, so that the database computes it from
+    /// the default of the column, such as `now()` or `gen_random_uuid()`, instead of taking
+    /// the value of the models. SQLite has no `DEFAULT` in `VALUES`, so there the column is
+    /// left out of the insert, to the same effect. Rewrites the statement, so call it before
+    /// modifying the underlying query.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let insert = Insert::many(vec![
+    ///     cake::Model {
+    ///         id: 1,
+    ///         name: "Apple Pie".to_owned(),
+    ///     },
+    ///     cake::Model {
+    ///         id: 2,
+    ///         name: "Orange Scone".to_owned(),
+    ///     },
+    /// ])
+    /// .default_column(cake::Column::Id);
+    ///
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Postgres).to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (DEFAULT, 'Apple Pie'), (DEFAULT, 'Orange Scone')"#,
+    /// );
+    /// assert_eq!(
+    ///     insert.build(DbBackend::Sqlite).to_string(),
+    ///     "INSERT INTO `cake` (`name`) VALUES ('Apple Pie'), ('Orange Scone')",
+    /// );
+    /// ```
+    pub fn default_column(mut self, column: <A::Entity as EntityTrait>::Column) -> Self {
+        let num_columns = <A::Entity as EntityTrait>::Column::iter().count();
+        let idx = <A::Entity as EntityTrait>::Column::iter()
+            .position(|col| col.as_str() == column.as_str())
+            .expect("column of the entity");
+        self.defaults.resize(num_columns, false);
+        self.defaults[idx] = true;
+        if !self.columns.is_empty() {
+            self.columns[idx] = true;
+        }
+        // The value the database picks for a key column is not known up front
+        if <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .any(|key| key.into_column().as_str() == column.as_str())
+        {
+            self.primary_key = None;
+        }
+        self.rebuild();
+        self
+    }
+
+    fn is_default(&self, idx: usize) -> bool {
+        self.defaults.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Leave the columns inserted as `DEFAULT` out on SQLite, which does not support it
+    pub(crate) fn for_backend(mut self, db_backend: DbBackend) -> Self {
+        if db_backend != DbBackend::Sqlite || !self.defaults.contains(&true) {
+            return self;
+        }
+        for (selected, default) in self.columns.iter_mut().zip(&self.defaults) {
+            if *default {
+                *selected = false;
+            }
+        }
+        self.defaults.clear();
+        self.rebuild();
+        self
+    }
+
+    /// Write the statement anew from the rows, for the columns selected now
+    fn rebuild(&mut self) {
+        let table = match &self.schema {
+            Some(schema) => A::Entity::default().table_ref_in_schema(schema),
+            None => A::Entity::default().table_ref(),
+        };
+        self.query = InsertStatement::new().into_table(table).to_owned();
+        self.sensitive.clear();
+        for row in std::mem::take(&mut self.rows) {
+            self.push_row(&row);
+            self.rows.push(row);
+        }
+    }
+
+    /// The primary key of every row to insert, if each of them inserts all the key columns
+    pub(crate) fn primary_keys(&self) -> Option<Vec<Vec<Value>>> {
+        if self.rows.is_empty() {
+            return Some(Vec::new());
+        }
+        let key_idx: Vec<usize> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| {
+                <A::Entity as EntityTrait>::Column::iter()
+                    .position(|col| col.as_str() == key.into_column().as_str())
+                    .expect("primary key column")
+            })
+            .collect();
+        if !key_idx
+            .iter()
+            .all(|idx| self.columns[*idx] && !self.is_default(*idx))
+        {
+            return None;
+        }
+        self.rows
+            .iter()
+            .map(|row| {
+                key_idx
+                    .iter()
+                    .map(|idx| {
+                        let av = &row[*idx];
+                        (av.is_set() || av.is_unchanged()).then(|| av.clone().into_value())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Rewrite an insert without any column into the form each backend accepts
+/// for a row made of database defaults only
+pub(crate) fn insert_default_values(mut stmt: Statement) -> Statement {
+    match stmt.db_backend {
+        DbBackend::MySql => {}
+        DbBackend::Postgres | DbBackend::Sqlite => {
+            stmt.sql = stmt.sql.replacen(" () VALUES ()", " DEFAULT VALUES", 1);
+        }
+    }
+    stmt
+}
+
+/// The primary key of the model, if every key column has a value
+fn primary_key_of<A>(am: &A) -> Option<Vec<Value>>
+where
+    A: ActiveModelTrait,
+{
+    let mut values = Vec::new();
+    for key in <A::Entity as EntityTrait>::PrimaryKey::iter() {
+        let av = am.get(key.into_column());
+        if !(av.is_set() || av.is_unchanged()) {
+            return None;
+        }
+        values.push(av.into_value());
+    }
+    Some(values)
+}
+
+/// Add the clause to skip conflicting rows to a built insert statement
+pub(crate) fn insert_on_conflict_do_nothing(mut stmt: Statement) -> Statement {
+    match stmt.db_backend {
+        DbBackend::MySql => {
+            stmt.sql = stmt.sql.replacen("INSERT ", "INSERT IGNORE ", 1);
+        }
+        DbBackend::Postgres | DbBackend::Sqlite => match stmt.sql.rfind(" RETURNING ") {
+            Some(idx) => stmt.sql.insert_str(idx, " ON CONFLICT DO NOTHING"),
+            None => stmt.sql.push_str(" ON CONFLICT DO NOTHING"),
+        },
+    }
+    stmt
 }
 
 impl<A> QueryTrait for Insert<A>
@@ -157,12 +521,78 @@ where
     fn into_query(self) -> InsertStatement {
         self.query
     }
+
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        if db_backend == DbBackend::Sqlite && self.defaults.contains(&true) {
+            return self.clone().for_backend(db_backend).build(db_backend);
+        }
+        let query_builder = db_backend.get_query_builder();
+        let stmt = insert_default_values(Statement::from_string_values_tuple(
+            db_backend,
+            self.query.build_any(query_builder.as_ref()),
+        ));
+        let stmt = stmt.with_sensitive_values(self.sensitive.clone());
+        if self.on_conflict_do_nothing {
+            insert_on_conflict_do_nothing(stmt)
+        } else {
+            stmt
+        }
+    }
+}
+
+impl<E> InsertFromSelect<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) fn new<C, S>(columns: C, select: Select<S>) -> Result<Self, DbErr>
+    where
+        C: IntoIterator<Item = E::Column>,
+        S: EntityTrait,
+    {
+        let columns: Vec<_> = columns.into_iter().collect();
+        let (mut query, with) = select.into_parts();
+        let selected = column_count(&mut query);
+        if columns.len() != selected {
+            return Err(DbErr::Query(format!(
+                "cannot insert a select of {} columns into {} columns",
+                selected,
+                columns.len()
+            )));
+        }
+        Ok(Self {
+            columns,
+            query,
+            with,
+        })
+    }
+
+    /// Build the statement, with the values of the select bound in the order they appear
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        let mut sql = SqlWriter::new();
+        let mut values = Vec::new();
+        let mut collector = |value| values.push(value);
+        write!(sql, "INSERT INTO ").unwrap();
+        query_builder.prepare_table_ref(&E::default().table_ref(), &mut sql, &mut collector);
+        write!(sql, " (").unwrap();
+        for (i, col) in self.columns.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(&mut sql, query_builder.quote());
+        }
+        write!(sql, ") ").unwrap();
+        self.with
+            .prepare(query_builder.as_ref(), &mut sql, &mut collector);
+        query_builder.prepare_select_statement(&self.query, &mut sql, &mut collector);
+        Statement::from_string_values_tuple(db_backend, (sql.result(), Values(values)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::cake;
-    use crate::{ActiveValue, DbBackend, Insert, QueryTrait};
+    use crate::{ActiveValue, DbBackend, DbErr, Insert, QueryTrait};
 
     #[test]
     fn insert_1() {
@@ -245,4 +675,439 @@ mod tests {
             r#"INSERT INTO "cake" ("id", "name") VALUES (NULL, 'Apple'), (2, 'Orange')"#,
         );
     }
+
+    #[test]
+    fn insert_6() {
+        let insert = Insert::one(cake::ActiveModel {
+            ..Default::default()
+        });
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "cake" DEFAULT VALUES"#,
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            "INSERT INTO `cake` DEFAULT VALUES",
+        );
+        assert_eq!(
+            insert.build(DbBackend::MySql).to_string(),
+            "INSERT INTO `cake` () VALUES ()",
+        );
+    }
+
+    #[test]
+    fn insert_columns_override() {
+        use crate::tests_cfg::fruit;
+        use crate::Statement;
+
+        let apple = fruit::ActiveModel {
+            id: ActiveValue::set(1),
+            name: ActiveValue::set("Apple".to_owned()),
+            ..Default::default()
+        };
+        let pear = fruit::ActiveModel {
+            id: ActiveValue::set(2),
+            name: ActiveValue::set("Pear".to_owned()),
+            ..Default::default()
+        };
+        let insert = Insert::many(vec![apple, pear])
+            .into_schema("tenant_42")
+            .columns(vec![fruit::Column::Name, fruit::Column::CakeId]);
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "tenant_42"."fruit" ("name", "cake_id") VALUES ('Apple', NULL), ('Pear', NULL)"#,
+        );
+        // The unset column is sent as a literal `NULL`, not a bound value
+        assert_eq!(
+            insert.build(DbBackend::MySql),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                "INSERT INTO `tenant_42`.`fruit` (`name`, `cake_id`) VALUES (?, NULL), (?, NULL)",
+                vec!["Apple".into(), "Pear".into()]
+            ),
+        );
+        assert!(insert.primary_key.is_none());
+    }
+
+    #[test]
+    fn insert_default_column() {
+        use crate::tests_cfg::fruit;
+        use crate::Statement;
+
+        let apple = fruit::ActiveModel {
+            name: ActiveValue::set("Apple".to_owned()),
+            cake_id: ActiveValue::set(Some(1)),
+            ..Default::default()
+        };
+        let pear = fruit::ActiveModel {
+            name: ActiveValue::set("Pear".to_owned()),
+            ..Default::default()
+        };
+        // Rows added later take the default as well
+        let insert = Insert::one(apple)
+            .default_column(fruit::Column::CakeId)
+            .add(pear);
+        assert_eq!(
+            insert.build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, DEFAULT), ($2, DEFAULT)"#,
+                vec!["Apple".into(), "Pear".into()]
+            ),
+        );
+        assert_eq!(
+            insert.build(DbBackend::MySql).to_string(),
+            "INSERT INTO `fruit` (`name`, `cake_id`) VALUES ('Apple', DEFAULT), ('Pear', DEFAULT)",
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            "INSERT INTO `fruit` (`name`) VALUES ('Apple'), ('Pear')",
+        );
+
+        // A key computed by the database is not known up front
+        let insert = Insert::one(fruit::ActiveModel {
+            id: ActiveValue::set(1),
+            name: ActiveValue::set("Apple".to_owned()),
+            ..Default::default()
+        });
+        assert!(insert.primary_key.is_some());
+        let insert = insert.default_column(fruit::Column::Id);
+        assert!(insert.primary_key.is_none());
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "fruit" ("id", "name") VALUES (DEFAULT, 'Apple')"#,
+        );
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn insert_default_values_returning() -> Result<(), crate::DbErr> {
+        use crate::{MockDatabase, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Apple Pie".to_owned(),
+            }]])
+            .into_connection();
+
+        let res = Insert::one(cake::ActiveModel {
+            ..Default::default()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.last_insert_id, 1);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" DEFAULT VALUES RETURNING "id""#,
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn insert_on_conflict_do_nothing_returning() -> Result<(), crate::DbErr> {
+        use crate::{MockDatabase, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+
+        let res = Insert::one(cake::ActiveModel {
+            id: ActiveValue::set(1),
+            name: ActiveValue::set("Apple Pie".to_owned()),
+        })
+        .on_conflict_do_nothing()
+        .exec(&db)
+        .await?;
+        assert_eq!(res.last_insert_id, 0);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2)"#,
+                    r#"ON CONFLICT DO NOTHING RETURNING "id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), "Apple Pie".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn insert_exec_with_returning() -> Result<(), crate::DbErr> {
+        use crate::{MockDatabase, MockExecResult, Transaction};
+
+        let apple_pie = cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        };
+        let insert = || {
+            Insert::one(cake::ActiveModel {
+                name: ActiveValue::set("Apple Pie".to_owned()),
+                ..Default::default()
+            })
+        };
+
+        // Postgres returns the whole row
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![apple_pie.clone()]])
+            .into_connection();
+        assert_eq!(insert().exec_with_returning(&db).await?, apple_pie);
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                vec!["Apple Pie".into()]
+            )]
+        );
+
+        // MySQL selects it again by the last insert id
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            // Exec and query results share one counter, so the select takes the second
+            .append_query_results(vec![vec![], vec![apple_pie.clone()]])
+            .into_connection();
+        assert_eq!(insert().exec_with_returning(&db).await?, apple_pie);
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake` (`name`) VALUES (?)"#,
+                    vec!["Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?"#,
+                    vec![1i32.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        // A primary key set on the model is used instead of the last insert id
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![
+                vec![],
+                vec![cake::Model {
+                    id: 7,
+                    name: "Apple Pie".to_owned(),
+                }],
+            ])
+            .into_connection();
+        let model = Insert::one(cake::ActiveModel {
+            id: ActiveValue::set(7),
+            name: ActiveValue::set("Apple Pie".to_owned()),
+        })
+        .exec_with_returning(&db)
+        .await?;
+        assert_eq!(model.id, 7);
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?"#,
+                vec![7i32.into(), 1u64.into()]
+            )
+        );
+
+        Ok(())
+    }
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn insert_many_exec_with_returning() -> Result<(), crate::DbErr> {
+        use crate::{DbErr, MockDatabase, MockExecResult, Transaction};
+
+        let cakes = |ids: [i32; 3]| -> Vec<cake::Model> {
+            ids.iter()
+                .zip(["Apple Pie", "Orange Scone", "Lemon Tart"])
+                .map(|(id, name)| cake::Model {
+                    id: *id,
+                    name: name.to_owned(),
+                })
+                .collect()
+        };
+        let insert = || {
+            Insert::many(cakes([0, 0, 0]).into_iter().map(|cake| cake::ActiveModel {
+                name: ActiveValue::set(cake.name),
+                ..Default::default()
+            }))
+        };
+        let exec_result = |last_insert_id| MockExecResult {
+            last_insert_id,
+            rows_affected: 3,
+        };
+
+        // Postgres returns the rows, or only their keys
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![cakes([4, 5, 6]), cakes([7, 8, 9])])
+            .into_connection();
+        assert_eq!(
+            insert().exec_with_returning_many(&db).await?,
+            cakes([4, 5, 6])
+        );
+        assert_eq!(insert().exec_with_returning_keys(&db).await?, vec![7, 8, 9]);
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log[0],
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2), ($3) RETURNING "id", "name""#,
+                vec![
+                    "Apple Pie".into(),
+                    "Orange Scone".into(),
+                    "Lemon Tart".into()
+                ]
+            )
+        );
+        assert_eq!(
+            log[1],
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2), ($3) RETURNING "id""#,
+                vec![
+                    "Apple Pie".into(),
+                    "Orange Scone".into(),
+                    "Lemon Tart".into()
+                ]
+            )
+        );
+
+        // MySQL reports the first id of the batch, SQLite the last one
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![exec_result(4)])
+            .into_connection();
+        assert_eq!(insert().exec_with_returning_keys(&db).await?, vec![4, 5, 6]);
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![exec_result(6)])
+            .into_connection();
+        assert_eq!(insert().exec_with_returning_keys(&db).await?, vec![4, 5, 6]);
+
+        // The rows are selected again by their keys, and put in the order they were inserted
+        let mut selected = cakes([4, 5, 6]);
+        selected.rotate_left(1);
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![exec_result(4)])
+            .append_query_results(vec![vec![], selected])
+            .into_connection();
+        assert_eq!(
+            insert().exec_with_returning_many(&db).await?,
+            cakes([4, 5, 6])
+        );
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                    "WHERE `cake`.`id` = ? OR `cake`.`id` = ? OR `cake`.`id` = ?",
+                ]
+                .join(" ")
+                .as_str(),
+                vec![4i32.into(), 5i32.into(), 6i32.into()]
+            )
+        );
+
+        // Keys set on the models are taken as they are
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![exec_result(0)])
+            .into_connection();
+        let keys = Insert::many(cakes([3, 1, 2]))
+            .exec_with_returning_keys(&db)
+            .await?;
+        assert_eq!(keys, vec![3, 1, 2]);
+
+        // Skipped rows leave the keys of the inserted ones unknown
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 4,
+                rows_affected: 2,
+            }])
+            .into_connection();
+        assert!(matches!(
+            insert()
+                .on_conflict_do_nothing()
+                .exec_with_returning_keys(&db)
+                .await,
+            Err(DbErr::Exec(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_from_select() -> Result<(), DbErr> {
+        use crate::tests_cfg::fruit;
+        use crate::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, Values};
+
+        let stmt = fruit::Entity::insert_from_select(
+            [fruit::Column::Name, fruit::Column::CakeId],
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Name)
+                .column(cake::Column::Id)
+                .filter(cake::Column::Name.contains("chocolate"))
+                .filter(cake::Column::Id.lt(10)),
+        )?
+        .build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"INSERT INTO "fruit" ("name", "cake_id")"#,
+                r#"SELECT "cake"."name", "cake"."id" FROM "cake""#,
+                r#"WHERE "cake"."name" LIKE $1 AND "cake"."id" < $2"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec!["%chocolate%".into(), 10i32.into()]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_from_select_arity() {
+        use crate::tests_cfg::fruit;
+        use crate::{EntityTrait, QuerySelect};
+
+        assert_eq!(
+            fruit::Entity::insert_from_select([fruit::Column::Name], cake::Entity::find())
+                .map(|_| ()),
+            Err(DbErr::Query(
+                "cannot insert a select of 2 columns into 1 columns".to_owned()
+            ))
+        );
+        assert_eq!(
+            fruit::Entity::insert_from_select(
+                [fruit::Column::Name],
+                cake::Entity::find().select_only()
+            )
+            .map(|_| ()),
+            Err(DbErr::Query(
+                "cannot insert a select of 0 columns into 1 columns".to_owned()
+            ))
+        );
+    }
 }
@@ -41,6 +41,8 @@ where
     }
 
     /// Left Join with a Related Entity and select both Entity.
+    /// The related model is `None` where the join finds no match; columns are
+    /// selected with a prefix per Entity, so names shared by both do not collide.
     pub fn find_also_related<R>(self, r: R) -> SelectTwo<E, R>
     where
         R: EntityTrait,
@@ -75,7 +77,11 @@ where
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, cake_filling, cake_filling_price, filling, fruit};
-    use crate::{ColumnTrait, DbBackend, EntityTrait, ModelTrait, QueryFilter, QueryTrait};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, ModelTrait, QueryFilter, QuerySelect, QueryTrait,
+        RelationTrait,
+    };
+    use sea_query::{Alias, Expr, JoinType};
 
     #[test]
     fn join_1() {
@@ -274,4 +280,113 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn join_12() {
+        assert_eq!(
+            cake::Entity::find()
+                .join(JoinType::InnerJoin, cake::Relation::Fruit.def())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "INNER JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_13() {
+        assert_eq!(
+            cake::Entity::find()
+                .join(JoinType::LeftJoin, cake::Relation::Fruit.def())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_14() {
+        assert_eq!(
+            cake::Entity::find()
+                .join(JoinType::RightJoin, cake::Relation::Fruit.def())
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "RIGHT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_15() {
+        assert_eq!(
+            cake::Entity::find()
+                .join_rev(JoinType::InnerJoin, fruit::Relation::Cake.def())
+                .filter(fruit::Column::Name.contains("cherry"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "INNER JOIN `fruit` ON `fruit`.`cake_id` = `cake`.`id`",
+                "WHERE `fruit`.`name` LIKE '%cherry%'",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_16() {
+        assert_eq!(
+            cake::Entity::find()
+                .join_as(
+                    JoinType::LeftJoin,
+                    cake::Relation::Fruit.def(),
+                    Alias::new("fruit_1")
+                )
+                .join_as(
+                    JoinType::LeftJoin,
+                    cake::Relation::Fruit.def(),
+                    Alias::new("fruit_2")
+                )
+                .filter(Expr::tbl(Alias::new("fruit_1"), fruit::Column::Name).eq("apple"))
+                .filter(Expr::tbl(Alias::new("fruit_2"), fruit::Column::Name).eq("pear"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"LEFT JOIN "fruit" AS "fruit_1" ON "cake"."id" = "fruit_1"."cake_id""#,
+                r#"LEFT JOIN "fruit" AS "fruit_2" ON "cake"."id" = "fruit_2"."cake_id""#,
+                r#"WHERE "fruit_1"."name" = 'apple' AND "fruit_2"."name" = 'pear'"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_17() {
+        assert_eq!(
+            cake::Entity::find()
+                .join_as_rev(
+                    JoinType::InnerJoin,
+                    fruit::Relation::Cake.def(),
+                    Alias::new("cake_fruit")
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "INNER JOIN `fruit` AS `cake_fruit` ON `cake_fruit`.`cake_id` = `cake`.`id`",
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -1,4 +1,6 @@
 pub(crate) mod combine;
+mod condition;
+pub(crate) mod cte;
 mod delete;
 mod helper;
 mod insert;
@@ -7,17 +9,20 @@ mod join;
 mod json;
 mod select;
 mod traits;
+mod union;
 mod update;
+mod window;
 
 pub use combine::{SelectA, SelectB};
+pub use condition::*;
 pub use delete::*;
 pub use helper::*;
 pub use insert::*;
-pub use join::*;
 #[cfg(feature = "with-json")]
 pub use json::*;
 pub use select::*;
 pub use traits::*;
 pub use update::*;
+pub use window::*;
 
 pub use crate::{InsertResult, Statement, UpdateResult, Value, Values, ConnectionTrait};
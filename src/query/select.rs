@@ -1,8 +1,7 @@
-use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use crate::{query::cte::WithClause, ColumnTrait, DbBackend, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Statement};
 use core::fmt::Debug;
 use core::marker::PhantomData;
-pub use sea_query::JoinType;
-use sea_query::{DynIden, IntoColumnRef, SeaRc, SelectStatement, SimpleExpr};
+use sea_query::{Condition, DynIden, Expr, IntoColumnRef, Order, SeaRc, SelectStatement, SimpleExpr};
 
 #[derive(Clone, Debug)]
 pub struct Select<E>
@@ -10,6 +9,10 @@ where
     E: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
+    /// Kept apart from the query until it is built, so that they can be reset
+    pub(crate) filters: Vec<Condition>,
+    pub(crate) orders: Vec<(SimpleExpr, Order)>,
     pub(crate) entity: PhantomData<E>,
 }
 
@@ -20,6 +23,7 @@ where
     F: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
@@ -30,6 +34,7 @@ where
     F: EntityTrait,
 {
     pub(crate) query: SelectStatement,
+    pub(crate) with: WithClause,
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
@@ -50,35 +55,58 @@ macro_rules! impl_trait {
             }
         }
 
-        impl<E, F> $trait for SelectTwo<E, F>
-        where
-            E: EntityTrait,
-            F: EntityTrait,
-        {
-            type QueryStatement = SelectStatement;
+        impl_trait!($trait, SelectTwo, SelectTwoMany);
+    };
+    ( $trait: ident, $( $selector: ident ),* ) => {
+        $(
+            impl<E, F> $trait for $selector<E, F>
+            where
+                E: EntityTrait,
+                F: EntityTrait,
+            {
+                type QueryStatement = SelectStatement;
 
-            fn query(&mut self) -> &mut SelectStatement {
-                &mut self.query
+                fn query(&mut self) -> &mut SelectStatement {
+                    &mut self.query
+                }
             }
-        }
-
-        impl<E, F> $trait for SelectTwoMany<E, F>
-        where
-            E: EntityTrait,
-            F: EntityTrait,
-        {
-            type QueryStatement = SelectStatement;
-
-            fn query(&mut self) -> &mut SelectStatement {
-                &mut self.query
-            }
-        }
+        )*
     };
 }
 
 impl_trait!(QuerySelect);
-impl_trait!(QueryFilter);
-impl_trait!(QueryOrder);
+impl_trait!(QueryFilter, SelectTwo, SelectTwoMany);
+impl_trait!(QueryOrder, SelectTwo, SelectTwoMany);
+
+impl<E> QueryFilter for Select<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+
+    fn add_condition(&mut self, condition: Condition) {
+        self.filters.push(condition);
+    }
+}
+
+impl<E> QueryOrder for Select<E>
+where
+    E: EntityTrait,
+{
+    type QueryStatement = SelectStatement;
+
+    fn query(&mut self) -> &mut SelectStatement {
+        &mut self.query
+    }
+
+    fn add_order_by(&mut self, expr: SimpleExpr, ord: Order) {
+        self.orders.push((expr, ord));
+    }
+}
 
 impl<C> IntoSimpleExpr for C
 where
@@ -95,6 +123,12 @@ impl IntoSimpleExpr for SimpleExpr {
     }
 }
 
+impl IntoSimpleExpr for Expr {
+    fn into_simple_expr(self) -> SimpleExpr {
+        self.into_simple_expr()
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -102,6 +136,9 @@ where
     pub(crate) fn new() -> Self {
         Self {
             query: SelectStatement::new(),
+            with: WithClause::default(),
+            filters: Vec::new(),
+            orders: Vec::new(),
             entity: PhantomData,
         }
         .prepare_select()
@@ -122,6 +159,85 @@ where
         self.query.from(E::default().table_ref());
         self
     }
+
+    /// Select from the entity's table in the given schema instead of its default one,
+    /// e.g. to route a query to a tenant's schema picked at runtime.
+    /// Joined tables keep their own schema.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .into_schema("tenant_42")
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "tenant_42"."cake""#
+    /// );
+    /// ```
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query.from(E::default().table_ref_in_schema(schema));
+        self
+    }
+
+    /// Remove the conditions added with [`filter`](QueryFilter::filter), including the
+    /// ones of [`find_by_id`](EntityTrait::find_by_id) and of related finds, e.g. to derive
+    /// variants of a cached base query.
+    /// Conditions added directly to the statement through [`QueryTrait::query`] are kept.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let base = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("chocolate"))
+    ///     .order_by_asc(cake::Column::Name);
+    ///
+    /// assert_eq!(
+    ///     base.reset_filter()
+    ///         .filter(cake::Column::Id.gt(4))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` > 4 ORDER BY `cake`.`name` ASC"
+    /// );
+    /// ```
+    pub fn reset_filter(mut self) -> Self {
+        self.filters.clear();
+        self
+    }
+
+    /// Remove the orderings added with [`QueryOrder`], keeping the filters.
+    /// Orderings added directly to the statement through [`QueryTrait::query`] are kept.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let base = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("chocolate"))
+    ///     .order_by_asc(cake::Column::Name);
+    ///
+    /// assert_eq!(
+    ///     base.reset_order()
+    ///         .order_by_desc(cake::Column::Id)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%chocolate%' ORDER BY `cake`.`id` DESC"
+    /// );
+    /// ```
+    pub fn reset_order(mut self) -> Self {
+        self.orders.clear();
+        self
+    }
+
+    /// The statement with the filters and orders added to it, and the WITH clause
+    pub(crate) fn into_parts(mut self) -> (SelectStatement, WithClause) {
+        for condition in self.filters {
+            self.query.cond_where(condition);
+        }
+        for (expr, ord) in self.orders {
+            self.query.order_by_expr(expr, ord);
+        }
+        (self.query, self.with)
+    }
 }
 
 impl<E> QueryTrait for Select<E>
@@ -132,11 +248,26 @@ where
     fn query(&mut self) -> &mut SelectStatement {
         &mut self.query
     }
+    /// The statement without the filters and orders, which are only added when the
+    /// select is built or turned into its statement
     fn as_query(&self) -> &SelectStatement {
         &self.query
     }
     fn into_query(self) -> SelectStatement {
-        self.query
+        self.into_parts().0
+    }
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        let (query, with) = self.clone().into_parts();
+        with.build(db_backend, &query)
+    }
+}
+
+impl<E> From<Select<E>> for SelectStatement
+where
+    E: EntityTrait,
+{
+    fn from(select: Select<E>) -> Self {
+        select.into_query()
     }
 }
 
@@ -157,6 +288,9 @@ macro_rules! select_two {
             fn into_query(self) -> SelectStatement {
                 self.query
             }
+            fn build(&self, db_backend: DbBackend) -> Statement {
+                self.with.build(db_backend, &self.query)
+            }
         }
     };
 }
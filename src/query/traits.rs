@@ -1,6 +1,28 @@
 use crate::{DbBackend, Statement};
 use sea_query::QueryStatementBuilder;
 
+/// The statement behind a query builder of [`Select`](crate::Select), [`Insert`](crate::Insert),
+/// [`UpdateOne`](crate::UpdateOne), [`DeleteMany`](crate::DeleteMany) and the like, to see
+/// the SQL it would run without running it
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, Values};
+///
+/// let select = cake::Entity::find().filter(cake::Column::Name.eq("Cheese"));
+///
+/// let stmt = select.build(DbBackend::Postgres);
+/// assert_eq!(
+///     stmt.sql,
+///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" = $1"#
+/// );
+/// assert_eq!(stmt.values, Some(Values(vec!["Cheese".into()])));
+///
+/// // Placeholders follow the backend
+/// assert_eq!(
+///     select.build(DbBackend::MySql).sql,
+///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` = ?"
+/// );
+/// ```
 pub trait QueryTrait {
     type QueryStatement: QueryStatementBuilder;
 
@@ -21,4 +43,126 @@ pub trait QueryTrait {
             self.as_query().build_any(query_builder.as_ref()),
         )
     }
+
+    /// Apply `if_some` to the query only when `val` is `Some`, for optional filters
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let name: Option<&str> = Some("Cheese");
+    /// let id: Option<i32> = None;
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .apply_if(name, |query, name| query.filter(cake::Column::Name.contains(name)))
+    ///         .apply_if(id, |query, id| query.filter(cake::Column::Id.eq(id)))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%Cheese%'"#
+    /// );
+    /// ```
+    fn apply_if<T, F>(self, val: Option<T>, if_some: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(Self, T) -> Self,
+    {
+        match val {
+            Some(val) => if_some(self, val),
+            None => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::cake;
+    use crate::{
+        ActiveValue, ColumnTrait, DbBackend, EntityTrait, Insert, QueryFilter, QueryTrait,
+        Statement, Update, Values,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::Expr;
+
+    fn assert_sql<Q: QueryTrait>(query: Q, sql: [&str; 3], values: Vec<sea_query::Value>) {
+        for (backend, sql) in [DbBackend::Postgres, DbBackend::MySql, DbBackend::Sqlite]
+            .iter()
+            .zip(sql)
+        {
+            let stmt = query.build(*backend);
+            assert_eq!(
+                stmt,
+                Statement::from_sql_and_values(*backend, sql, values.clone())
+            );
+            assert_eq!(stmt.values, Some(Values(values.clone())));
+        }
+    }
+
+    #[test]
+    fn build_each_statement_kind() {
+        assert_sql(
+            cake::Entity::find().filter(cake::Column::Id.gt(2)),
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > $1"#,
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` > ?",
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` > ?",
+            ],
+            vec![2i32.into()],
+        );
+        assert_sql(
+            Insert::one(cake::ActiveModel {
+                name: ActiveValue::set("Apple Pie".to_owned()),
+                ..Default::default()
+            }),
+            [
+                r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+                "INSERT INTO `cake` (`name`) VALUES (?)",
+                "INSERT INTO `cake` (`name`) VALUES (?)",
+            ],
+            vec!["Apple Pie".into()],
+        );
+        assert_sql(
+            Update::one(cake::ActiveModel {
+                id: ActiveValue::set(1),
+                name: ActiveValue::set("Orange Scone".to_owned()),
+            }),
+            [
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2"#,
+                "UPDATE `cake` SET `name` = ? WHERE `cake`.`id` = ?",
+                "UPDATE `cake` SET `name` = ? WHERE `cake`.`id` = ?",
+            ],
+            vec!["Orange Scone".into(), 1i32.into()],
+        );
+        assert_sql(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Lemon Tart"))
+                .filter(cake::Column::Name.contains("Lemon")),
+            [
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."name" LIKE $2"#,
+                "UPDATE `cake` SET `name` = ? WHERE `cake`.`name` LIKE ?",
+                "UPDATE `cake` SET `name` = ? WHERE `cake`.`name` LIKE ?",
+            ],
+            vec!["Lemon Tart".into(), "%Lemon%".into()],
+        );
+        assert_sql(
+            cake::Entity::delete(cake::ActiveModel {
+                id: ActiveValue::set(3),
+                ..Default::default()
+            }),
+            [
+                r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                "DELETE FROM `cake` WHERE `cake`.`id` = ?",
+                "DELETE FROM `cake` WHERE `cake`.`id` = ?",
+            ],
+            vec![3i32.into()],
+        );
+        assert_sql(
+            cake::Entity::delete_many().filter(cake::Column::Id.is_in(vec![4, 5])),
+            [
+                r#"DELETE FROM "cake" WHERE "cake"."id" IN ($1, $2)"#,
+                "DELETE FROM `cake` WHERE `cake`.`id` IN (?, ?)",
+                "DELETE FROM `cake` WHERE `cake`.`id` IN (?, ?)",
+            ],
+            vec![4i32.into(), 5i32.into()],
+        );
+    }
 }
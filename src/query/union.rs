@@ -0,0 +1,165 @@
+use crate::{DbErr, EntityTrait, Select};
+use sea_query::{SelectStatement, UnionType};
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Combine the rows of `other` with the rows of this select, dropping duplicates.
+    /// See [`union_all`](Self::union_all).
+    pub fn union(self, other: Select<E>) -> Result<Self, DbErr> {
+        self.union_with(UnionType::Distinct, other)
+    }
+
+    /// Combine the rows of `other` with the rows of this select, keeping duplicates.
+    /// Both selects have to return the same number of columns, or this fails with
+    /// [`DbErr::Query`].
+    ///
+    /// The values of this select are bound first, then those of `other`. The order by,
+    /// limit and offset of this select apply to the combined rows, which no longer come
+    /// from a table: order them by column name, with `Expr::col`, rather than by the
+    /// column of the entity. `other` should have no order by, limit or offset of its own.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    /// use sea_query::Expr;
+    ///
+    /// # fn main() -> Result<(), sea_orm::DbErr> {
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("chocolate"))
+    ///         .union_all(cake::Entity::find().filter(cake::Column::Id.lt(3)))?
+    ///         .order_by_asc(Expr::col(cake::Column::Name))
+    ///         .limit(10)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE '%chocolate%'"#,
+    ///         r#"UNION ALL SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" < 3"#,
+    ///         r#"ORDER BY "name" ASC LIMIT 10"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union_all(self, other: Select<E>) -> Result<Self, DbErr> {
+        self.union_with(UnionType::All, other)
+    }
+
+    fn union_with(mut self, union_type: UnionType, other: Select<E>) -> Result<Self, DbErr> {
+        let (mut other_query, other_with) = other.into_parts();
+        let columns = column_count(&mut self.query);
+        let other_columns = column_count(&mut other_query);
+        if columns != other_columns {
+            return Err(DbErr::Query(format!(
+                "cannot combine a select of {} columns with a select of {} columns",
+                columns, other_columns
+            )));
+        }
+        self.with.append(other_with);
+        self.query.union(union_type, other_query);
+        Ok(self)
+    }
+}
+
+/// The number of expressions selected by the query
+pub(crate) fn column_count(query: &mut SelectStatement) -> usize {
+    let mut count = 0;
+    query.exprs_mut_for_each(|_| count += 1);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::cake;
+    use crate::{
+        ColumnTrait, DbBackend, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+        QueryTrait, Values,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, Query};
+
+    #[test]
+    fn union_all() -> Result<(), DbErr> {
+        let stmt = cake::Entity::find()
+            .filter(cake::Column::Name.eq("Cheese"))
+            .union_all(cake::Entity::find().filter(cake::Column::Id.gt(5)))?
+            .union(cake::Entity::find().filter(cake::Column::Id.eq(1)))?
+            .order_by_desc(Expr::col(cake::Column::Id))
+            .limit(2)
+            .build(DbBackend::MySql);
+
+        assert_eq!(
+            stmt.sql,
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` = ?",
+                "UNION ALL SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` > ?",
+                "UNION SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ?",
+                "ORDER BY `id` DESC LIMIT ?",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec![
+                "Cheese".into(),
+                5i32.into(),
+                1i32.into(),
+                2u64.into()
+            ]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_cte() -> Result<(), DbErr> {
+        let sweet = Query::select()
+            .columns(vec![cake::Column::Id, cake::Column::Name])
+            .from(cake::Entity)
+            .to_owned();
+
+        assert_eq!(
+            cake::Entity::find()
+                .union_all(cake::Entity::find().with("sweet", sweet).from_cte("sweet"))?
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"WITH "sweet" AS (SELECT "id", "name" FROM "cake")"#,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"UNION ALL SELECT "cake"."id", "cake"."name" FROM "sweet" AS "cake""#,
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_column_count_mismatch() {
+        assert_eq!(
+            cake::Entity::find()
+                .union(
+                    cake::Entity::find()
+                        .select_only()
+                        .column_as(Expr::cust("COALESCE(\"name\", 'a, b')"), "name"),
+                )
+                .map(|_| ()),
+            Err(DbErr::Query(
+                "cannot combine a select of 2 columns with a select of 1 columns".to_owned()
+            ))
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .select_only()
+                .order_by_asc(cake::Column::Id)
+                .order_by_asc(cake::Column::Name)
+                .union(cake::Entity::find())
+                .map(|_| ()),
+            Err(DbErr::Query(
+                "cannot combine a select of 0 columns with a select of 2 columns".to_owned()
+            ))
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn, QueryFilter,
-    QueryTrait,
+    ActiveModelTrait, ColumnTrait, DbBackend, EntityName, EntityTrait, IdenStatic, Iterable,
+    PrimaryKeyToColumn, QueryFilter, QueryTrait, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::{IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    Expr, Iden, IntoIden, SimpleExpr, SqlWriter, TableRef, UpdateStatement, Value, Values,
+};
+use std::fmt::Write;
 
 #[derive(Clone, Debug)]
 pub struct Update;
@@ -15,6 +18,7 @@ where
 {
     pub(crate) query: UpdateStatement,
     pub(crate) model: A,
+    pub(crate) sensitive: Vec<Value>,
 }
 
 #[derive(Clone, Debug)]
@@ -26,6 +30,21 @@ where
     pub(crate) entity: PhantomData<E>,
 }
 
+/// Update many rows in one statement, each to the values of its own ActiveModel,
+/// see [`Update::many_with_values`]
+#[derive(Clone, Debug)]
+pub struct UpdateManyWithValues<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) table: TableRef,
+    /// The primary key of each row, with the values set on it
+    pub(crate) rows: Vec<(Vec<Value>, ColumnValues<E>)>,
+}
+
+/// The columns set on a row, with their values
+pub(crate) type ColumnValues<E> = Vec<(<E as EntityTrait>::Column, Value)>;
+
 impl Update {
     /// Update one ActiveModel
     ///
@@ -52,6 +71,7 @@ impl Update {
                 .table(A::Entity::default().table_ref())
                 .to_owned(),
             model,
+            sensitive: Vec::new(),
         }
         .prepare_filters()
         .prepare_values()
@@ -80,6 +100,164 @@ impl Update {
             entity: PhantomData,
         }
     }
+
+    /// Update many rows, each to the values set on its own ActiveModel, with a single
+    /// statement instead of one per row. Every column becomes a `CASE` over the
+    /// primary keys of the rows setting it, so rows leaving a column unset keep their value.
+    /// If two models share a primary key, the first one wins.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Update::many_with_values(vec![
+    ///         fruit::ActiveModel {
+    ///             id: ActiveValue::set(1),
+    ///             name: ActiveValue::set("Orange".to_owned()),
+    ///             ..Default::default()
+    ///         },
+    ///         fruit::ActiveModel {
+    ///             id: ActiveValue::set(2),
+    ///             name: ActiveValue::set("Lemon".to_owned()),
+    ///             cake_id: ActiveValue::set(Some(3)),
+    ///         },
+    ///     ])
+    ///     .build(DbBackend::Postgres)
+    ///     .unwrap()
+    ///     .to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET"#,
+    ///         r#""name" = CASE WHEN "fruit"."id" = 1 THEN 'Orange' WHEN "fruit"."id" = 2 THEN 'Lemon' ELSE "fruit"."name" END,"#,
+    ///         r#""cake_id" = CASE WHEN "fruit"."id" = 2 THEN 3 ELSE "fruit"."cake_id" END"#,
+    ///         r#"WHERE "fruit"."id" IN (1, 2)"#,
+    ///     ]
+    ///     .join(" "),
+    /// );
+    /// ```
+    pub fn many_with_values<E, A, I>(models: I) -> UpdateManyWithValues<E>
+    where
+        E: EntityTrait,
+        A: ActiveModelTrait<Entity = E>,
+        I: IntoIterator<Item = A>,
+    {
+        let mut rows = Vec::new();
+        for model in models {
+            let mut key = Vec::new();
+            let mut values = Vec::new();
+            for col in E::Column::iter() {
+                let av = model.get(col);
+                if E::PrimaryKey::from_column(col).is_some() {
+                    if av.is_set() || av.is_unchanged() {
+                        key.push(av.unwrap());
+                    } else {
+                        panic!("PrimaryKey is not set");
+                    }
+                } else if av.is_set() {
+                    values.push((col, av.unwrap()));
+                }
+            }
+            // A row without values to set is left out, so it does not count as affected
+            if values.is_empty() {
+                continue;
+            }
+            rows.push((key, values));
+        }
+        UpdateManyWithValues {
+            table: E::default().table_ref(),
+            rows,
+        }
+    }
+}
+
+impl<E> UpdateManyWithValues<E>
+where
+    E: EntityTrait,
+{
+    /// Build the statement for `db_backend`, or `None` if no model sets a value
+    pub fn build(&self, db_backend: DbBackend) -> Option<Statement> {
+        if self.rows.is_empty() {
+            return None;
+        }
+        let query_builder = db_backend.get_query_builder();
+        let mut sql = SqlWriter::new();
+        let mut values = Vec::new();
+        let mut collector = |value| values.push(value);
+        write!(sql, "UPDATE ").unwrap();
+        query_builder.prepare_table_ref(&self.table, &mut sql, &mut collector);
+        write!(sql, " SET ").unwrap();
+        let mut first = true;
+        for col in E::Column::iter() {
+            let mut branches = self.rows.iter().filter_map(|(key, values)| {
+                let value = values.iter().find(|(c, _)| c.as_str() == col.as_str())?;
+                Some((key, &value.1))
+            });
+            let (key, value) = match branches.next() {
+                Some(branch) => branch,
+                None => continue,
+            };
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            first = false;
+            col.prepare(&mut sql, query_builder.quote());
+            write!(sql, " = CASE").unwrap();
+            for (key, value) in std::iter::once((key, value)).chain(branches) {
+                write!(sql, " WHEN ").unwrap();
+                query_builder.prepare_simple_expr(&Self::key_eq(key), &mut sql, &mut collector);
+                write!(sql, " THEN ").unwrap();
+                // the branches of a `CASE` take one collation on MySQL, which a value cast to
+                // text by `save_as` might not share with the column
+                if db_backend == DbBackend::Postgres {
+                    query_builder.prepare_simple_expr(
+                        &col.save_as(value.clone()),
+                        &mut sql,
+                        &mut collector,
+                    );
+                } else {
+                    query_builder.prepare_value(value, &mut sql, &mut collector);
+                }
+            }
+            write!(sql, " ELSE ").unwrap();
+            query_builder.prepare_simple_expr(
+                &Expr::tbl(E::default(), col).into(),
+                &mut sql,
+                &mut collector,
+            );
+            write!(sql, " END").unwrap();
+        }
+        write!(sql, " WHERE ").unwrap();
+        let filter = self.filter();
+        query_builder.prepare_simple_expr(&filter, &mut sql, &mut collector);
+        Some(Statement::from_string_values_tuple(
+            db_backend,
+            (sql.result(), Values(values)),
+        ))
+    }
+
+    /// Match the row with the primary key `key`
+    fn key_eq(key: &[Value]) -> SimpleExpr {
+        E::PrimaryKey::iter()
+            .zip(key)
+            .map(|(col, value)| Expr::tbl(E::default(), col.into_column()).eq(value.clone()))
+            .reduce(SimpleExpr::and)
+            .unwrap()
+    }
+
+    /// The rows to update: `key IN (...)` for a single primary key, or else
+    /// the conditions matching each row joined by `OR`
+    fn filter(&self) -> SimpleExpr {
+        let mut keys = E::PrimaryKey::iter();
+        match (keys.next(), keys.next()) {
+            (Some(key), None) => Expr::tbl(E::default(), key.into_column())
+                .is_in(self.rows.iter().map(|(key, _)| key[0].clone())),
+            _ => self
+                .rows
+                .iter()
+                .map(|(key, _)| Self::key_eq(key))
+                .reduce(SimpleExpr::or)
+                .unwrap(),
+        }
+    }
 }
 
 impl<A> UpdateOne<A>
@@ -99,6 +277,13 @@ where
         self
     }
 
+    /// Update the entity's table in the given schema instead of its default one
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query
+            .table(A::Entity::default().table_ref_in_schema(schema));
+        self
+    }
+
     fn prepare_values(mut self) -> Self {
         for col in <A::Entity as EntityTrait>::Column::iter() {
             if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
@@ -106,7 +291,11 @@ where
             }
             let av = self.model.get(col);
             if av.is_set() {
-                self.query.value(col, av.unwrap());
+                let value = av.unwrap();
+                if col.def().sensitive {
+                    self.sensitive.push(value.clone());
+                }
+                self.query.value_expr(col, col.save_as(value));
             }
         }
         self
@@ -124,6 +313,17 @@ where
     }
 }
 
+impl<E> UpdateMany<E>
+where
+    E: EntityTrait,
+{
+    /// Update the entity's table in the given schema instead of its default one
+    pub fn into_schema(mut self, schema: &str) -> Self {
+        self.query.table(E::default().table_ref_in_schema(schema));
+        self
+    }
+}
+
 impl<E> QueryFilter for UpdateMany<E>
 where
     E: EntityTrait,
@@ -152,6 +352,12 @@ where
     fn into_query(self) -> UpdateStatement {
         self.query
     }
+
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        db_backend
+            .build(&self.query)
+            .with_sensitive_values(self.sensitive.clone())
+    }
 }
 
 impl<E> QueryTrait for UpdateMany<E>
@@ -184,7 +390,7 @@ where
         for col in E::Column::iter() {
             let av = model.get(col);
             if av.is_set() {
-                self.query.value(col, av.unwrap());
+                self.query.value_expr(col, col.save_as(av.unwrap()));
             }
         }
         self
@@ -203,7 +409,7 @@ where
 mod tests {
     use crate::tests_cfg::{cake, fruit};
     use crate::{entity::*, query::*, DbBackend};
-    use sea_query::{Expr, Value};
+    use sea_query::{Expr, Value, Values};
 
     #[test]
     fn update_1() {
@@ -218,6 +424,47 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn update_nullable_patch() {
+        use crate as sea_orm;
+        use crate::DeriveIntoActiveModel;
+
+        #[derive(DeriveIntoActiveModel)]
+        #[sea_orm(active_model = "fruit::ActiveModel")]
+        struct PatchFruit {
+            id: i32,
+            name: Option<String>,
+            cake_id: Option<Option<i32>>,
+        }
+
+        let update = |cake_id| {
+            let am: fruit::ActiveModel = PatchFruit {
+                id: 1,
+                name: Some("Orange".to_owned()),
+                cake_id,
+            }
+            .into_active_model();
+            Update::one(am).build(DbBackend::Postgres).to_string()
+        };
+
+        // Left alone
+        assert_eq!(
+            update(None),
+            r#"UPDATE "fruit" SET "name" = 'Orange' WHERE "fruit"."id" = 1"#,
+        );
+        // Set to NULL
+        assert_eq!(
+            update(Some(None)),
+            r#"UPDATE "fruit" SET "name" = 'Orange', "cake_id" = NULL WHERE "fruit"."id" = 1"#,
+        );
+        // Set to a value
+        assert_eq!(
+            update(Some(Some(3))),
+            r#"UPDATE "fruit" SET "name" = 'Orange', "cake_id" = 3 WHERE "fruit"."id" = 1"#,
+        );
+    }
+
     #[test]
     fn update_2() {
         assert_eq!(
@@ -288,4 +535,124 @@ mod tests {
             r#"UPDATE "fruit" SET "id" = 3 WHERE "fruit"."id" = 2"#,
         );
     }
+
+    #[smol_potat::test]
+    async fn update_exec_with_returning() -> Result<(), crate::DbErr> {
+        use crate::{MockDatabase, MockExecResult, Transaction};
+
+        let apple_pie = cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        };
+        let update = || {
+            Update::one(cake::ActiveModel {
+                id: ActiveValue::set(1),
+                name: ActiveValue::set("Apple Pie".to_owned()),
+            })
+        };
+
+        // Postgres returns the whole row
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![apple_pie.clone()]])
+            .into_connection();
+        assert_eq!(update().exec_with_returning(&db).await?, apple_pie);
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2 RETURNING "id", "name""#,
+                vec!["Apple Pie".into(), 1i32.into()]
+            )]
+        );
+
+        // MySQL selects it again by the primary key
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![vec![], vec![apple_pie.clone()]])
+            .into_connection();
+        assert_eq!(update().exec_with_returning(&db).await?, apple_pie);
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"UPDATE `cake` SET `name` = ? WHERE `cake`.`id` = ?"#,
+                    vec!["Apple Pie".into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?"#,
+                    vec![1i32.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_many_with_values() {
+        use crate::tests_cfg::cake_filling_price;
+        use rust_decimal_macros::dec;
+
+        let price = |cake_id: i32, filling_id: i32, price| cake_filling_price::ActiveModel {
+            cake_id: ActiveValue::set(cake_id),
+            filling_id: ActiveValue::set(filling_id),
+            price: ActiveValue::set(price),
+        };
+        let stmt = cake_filling_price::Entity::update_many_with_values(vec![
+            price(1, 2, dec!(2.5)),
+            price(1, 3, dec!(4.0)),
+        ])
+        .build(DbBackend::MySql)
+        .unwrap();
+
+        assert_eq!(
+            stmt.sql,
+            [
+                "UPDATE `public`.`cake_filling_price` SET `price` = CASE",
+                "WHEN (`cake_filling_price`.`cake_id` = ?) AND (`cake_filling_price`.`filling_id` = ?) THEN ?",
+                "WHEN (`cake_filling_price`.`cake_id` = ?) AND (`cake_filling_price`.`filling_id` = ?) THEN ?",
+                "ELSE `cake_filling_price`.`price` END",
+                "WHERE ((`cake_filling_price`.`cake_id` = ?) AND (`cake_filling_price`.`filling_id` = ?))",
+                "OR ((`cake_filling_price`.`cake_id` = ?) AND (`cake_filling_price`.`filling_id` = ?))",
+            ]
+            .join(" ")
+        );
+        // Every value follows the key of its own row
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec![
+                1i32.into(),
+                2i32.into(),
+                dec!(2.5).into(),
+                1i32.into(),
+                3i32.into(),
+                dec!(4.0).into(),
+                1i32.into(),
+                2i32.into(),
+                1i32.into(),
+                3i32.into(),
+            ]))
+        );
+
+        // Nothing to update
+        assert!(
+            Update::many_with_values(Vec::<cake_filling_price::ActiveModel>::new())
+                .build(DbBackend::MySql)
+                .is_none()
+        );
+        assert!(
+            Update::many_with_values(vec![cake_filling_price::ActiveModel {
+                cake_id: ActiveValue::set(1),
+                filling_id: ActiveValue::set(2),
+                ..Default::default()
+            }])
+            .build(DbBackend::MySql)
+            .is_none()
+        );
+    }
 }
@@ -1,19 +1,68 @@
 use crate::{
-    unpack_table_ref, ColumnTrait, EntityTrait, Identity, Iterable, PrimaryKeyToColumn,
-    PrimaryKeyTrait, RelationTrait, Schema,
+    unpack_table_ref, ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Identity,
+    Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema, Statement,
+};
+use sea_query::{
+    extension::postgres::Type, Alias, ColumnDef, ForeignKeyCreateStatement, Iden, Index,
+    IndexCreateStatement, PostgresQueryBuilder, SeaRc, TableCreateStatement,
 };
-use sea_query::{ColumnDef, ForeignKeyCreateStatement, Iden, Index, TableCreateStatement};
 
 impl Schema {
     pub fn create_table_from_entity<E>(entity: E) -> TableCreateStatement
     where
         E: EntityTrait,
     {
-        create_table_from_entity(entity)
+        create_table_from_entity(entity, None)
+    }
+
+    /// Like [`Schema::create_table_from_entity`], with the column types of `db_backend`,
+    /// which differ for native enums, see [`ColumnType::Enum`]
+    pub fn create_table_from_entity_for_backend<E>(
+        entity: E,
+        db_backend: DbBackend,
+    ) -> TableCreateStatement
+    where
+        E: EntityTrait,
+    {
+        create_table_from_entity(entity, Some(db_backend))
+    }
+
+    /// Create the Postgres type of a native enum, see [`ColumnType::Enum`], to be executed
+    /// before the tables using it. The other backends list the values in the column,
+    /// so have nothing to create, as have enums stored in other column types
+    pub fn create_enum_from_active_enum<A>(db_backend: DbBackend) -> Option<Statement>
+    where
+        A: ActiveEnum,
+    {
+        let (name, values) = match A::db_type().col_type {
+            ColumnType::Enum(name, values) if db_backend == DbBackend::Postgres => (name, values),
+            _ => return None,
+        };
+        let stmt = Type::create()
+            .as_enum(Alias::new(&name))
+            .values(values.iter().map(|value| Alias::new(value)))
+            .to_owned();
+        // a type is created without parameters, so the values are written into the statement
+        Some(Statement::from_string(
+            db_backend,
+            stmt.to_string(PostgresQueryBuilder),
+        ))
+    }
+
+    /// Create an index for every column of the entity defined as `indexed`,
+    /// to be executed after the table of [`Schema::create_table_from_entity`]
+    pub fn create_index_from_entity<E>(entity: E) -> Vec<IndexCreateStatement>
+    where
+        E: EntityTrait,
+    {
+        create_index_from_entity(entity)
     }
 }
 
-pub(crate) fn create_table_from_entity<E>(entity: E) -> TableCreateStatement
+pub(crate) fn create_table_from_entity<E>(
+    entity: E,
+    db_backend: Option<DbBackend>,
+) -> TableCreateStatement
 where
     E: EntityTrait,
 {
@@ -21,7 +70,7 @@ where
 
     for column in E::Column::iter() {
         let orm_column_def = column.def();
-        let types = orm_column_def.col_type.into();
+        let types = column_type(orm_column_def.col_type, db_backend);
         let mut column_def = ColumnDef::new_with_type(column, types);
         if !orm_column_def.null {
             column_def.not_null();
@@ -29,6 +78,12 @@ where
         if orm_column_def.unique {
             column_def.unique_key();
         }
+        if let Some(value) = orm_column_def.default_value {
+            column_def.default(value);
+        }
+        if let Some(expr) = orm_column_def.default_expr {
+            column_def.extra(format!("DEFAULT {}", expr));
+        }
         for primary_key in E::PrimaryKey::iter() {
             if column.to_string() == primary_key.into_column().to_string() {
                 if E::PrimaryKey::auto_increment() {
@@ -39,18 +94,6 @@ where
                 }
             }
         }
-        if orm_column_def.indexed {
-            stmt.index(
-                Index::create()
-                    .name(&format!(
-                        "idx-{}-{}",
-                        entity.to_string(),
-                        column.to_string()
-                    ))
-                    .table(entity)
-                    .col(column),
-            );
-        }
         stmt.col(&mut column_def);
     }
 
@@ -119,11 +162,139 @@ where
     stmt.table(entity).take()
 }
 
+pub(crate) fn create_index_from_entity<E>(entity: E) -> Vec<IndexCreateStatement>
+where
+    E: EntityTrait,
+{
+    E::Column::iter()
+        .filter(|column| column.def().indexed)
+        .map(|column| {
+            Index::create()
+                .name(&format!(
+                    "idx-{}-{}",
+                    entity.to_string(),
+                    column.to_string()
+                ))
+                .table(entity)
+                .col(column)
+                .to_owned()
+        })
+        .collect()
+}
+
+/// The column type written for `db_backend`: a native enum is an `ENUM(..)` on MySQL
+/// and text on SQLite. Without a backend it is the named type of Postgres
+fn column_type(col_type: ColumnType, db_backend: Option<DbBackend>) -> sea_query::ColumnType {
+    match (col_type, db_backend) {
+        (ColumnType::Enum(_, values), Some(DbBackend::MySql)) => {
+            let values: Vec<String> = values
+                .iter()
+                .map(|value| format!("'{}'", value.replace('\'', "''")))
+                .collect();
+            sea_query::ColumnType::Custom(SeaRc::new(Alias::new(&format!(
+                "ENUM({})",
+                values.join(", ")
+            ))))
+        }
+        (ColumnType::Enum(_, _), Some(DbBackend::Sqlite)) => sea_query::ColumnType::Text,
+        (col_type, _) => col_type.into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{sea_query::*, tests_cfg::*, Schema};
+    use crate::{sea_query::*, tests_cfg::*, DbBackend, Schema};
     use pretty_assertions::assert_eq;
 
+    mod column_types {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "column_types")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub small: i16,
+            pub big: i64,
+            pub float: f32,
+            pub double: f64,
+            pub is_active: bool,
+            #[sea_orm(column_type = "Text")]
+            pub text: String,
+            #[sea_orm(column_type = "Char(Some(4))")]
+            pub code: String,
+            pub binary: Vec<u8>,
+            pub time: Time,
+            pub date: Date,
+            pub date_time: DateTime,
+            #[sea_orm(column_type = "Timestamp")]
+            pub timestamp: DateTime,
+            pub timestamp_tz: DateTimeWithTimeZone,
+            pub uuid: Uuid,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod column_modifiers {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "column_modifiers")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            #[sea_orm(unique)]
+            pub code: String,
+            #[sea_orm(indexed)]
+            pub name: String,
+            #[sea_orm(default_value = 0)]
+            pub quantity: i32,
+            #[sea_orm(default_value = "pending")]
+            pub status: String,
+            #[sea_orm(default_expr = "CURRENT_TIMESTAMP")]
+            pub created_at: DateTime,
+            pub note: Option<String>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod column_enum {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tea")]
+        pub enum Tea {
+            #[sea_orm(string_value = "EverydayTea")]
+            EverydayTea,
+            #[sea_orm(string_value = "Builder's Tea")]
+            BuildersTea,
+        }
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "column_enum")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub tea: Option<Tea>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
     #[test]
     fn test_create_table_from_entity() {
         assert_eq!(
@@ -165,4 +336,176 @@ mod tests {
                 .to_string(MysqlQueryBuilder)
         );
     }
+
+    #[test]
+    fn test_column_types_to_sql() {
+        let stmt = Schema::create_table_from_entity(column_types::Entity);
+
+        assert_eq!(
+            DbBackend::MySql.build(&stmt).to_string(),
+            [
+                "CREATE TABLE `column_types` (",
+                "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+                "`small` smallint NOT NULL,",
+                "`big` bigint NOT NULL,",
+                "`float` float NOT NULL,",
+                "`double` double NOT NULL,",
+                "`is_active` bool NOT NULL,",
+                "`text` text NOT NULL,",
+                "`code` char(4) NOT NULL,",
+                "`binary` blob NOT NULL,",
+                "`time` time NOT NULL,",
+                "`date` date NOT NULL,",
+                "`date_time` datetime NOT NULL,",
+                "`timestamp` timestamp NOT NULL,",
+                "`timestamp_tz` timestamp NOT NULL,",
+                "`uuid` binary(16) NOT NULL",
+                ")",
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            DbBackend::Postgres.build(&stmt).to_string(),
+            [
+                r#"CREATE TABLE "column_types" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""small" smallint NOT NULL,"#,
+                r#""big" bigint NOT NULL,"#,
+                r#""float" real NOT NULL,"#,
+                r#""double" double precision NOT NULL,"#,
+                r#""is_active" bool NOT NULL,"#,
+                r#""text" text NOT NULL,"#,
+                r#""code" char(4) NOT NULL,"#,
+                r#""binary" bytea NOT NULL,"#,
+                r#""time" time NOT NULL,"#,
+                r#""date" date NOT NULL,"#,
+                r#""date_time" timestamp without time zone NOT NULL,"#,
+                r#""timestamp" timestamp NOT NULL,"#,
+                r#""timestamp_tz" timestamp with time zone NOT NULL,"#,
+                r#""uuid" uuid NOT NULL"#,
+                ")",
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            DbBackend::Sqlite.build(&stmt).to_string(),
+            [
+                "CREATE TABLE `column_types` (",
+                "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
+                "`small` integer NOT NULL,",
+                "`big` integer NOT NULL,",
+                "`float` real NOT NULL,",
+                "`double` real NOT NULL,",
+                "`is_active` integer NOT NULL,",
+                "`text` text NOT NULL,",
+                "`code` text(4) NOT NULL,",
+                "`binary` binary NOT NULL,",
+                "`time` text NOT NULL,",
+                "`date` text NOT NULL,",
+                "`date_time` text NOT NULL,",
+                "`timestamp` text NOT NULL,",
+                "`timestamp_tz` text NOT NULL,",
+                "`uuid` text(36) NOT NULL",
+                ")",
+            ]
+            .join(" ")
+        );
+    }
+    #[test]
+    fn test_column_modifiers_to_sql() {
+        let stmt = Schema::create_table_from_entity(column_modifiers::Entity);
+
+        assert_eq!(
+            DbBackend::MySql.build(&stmt).to_string(),
+            [
+                "CREATE TABLE `column_modifiers` (",
+                "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+                "`code` varchar(255) NOT NULL UNIQUE,",
+                "`name` varchar(255) NOT NULL,",
+                "`quantity` int NOT NULL DEFAULT 0,",
+                "`status` varchar(255) NOT NULL DEFAULT 'pending',",
+                "`created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,",
+                "`note` varchar(255)",
+                ")",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_column_enum_to_sql() {
+        let create_table = |db_backend: DbBackend| {
+            db_backend
+                .build(&Schema::create_table_from_entity_for_backend(
+                    column_enum::Entity,
+                    db_backend,
+                ))
+                .to_string()
+        };
+        let create_enum = |db_backend: DbBackend| {
+            Schema::create_enum_from_active_enum::<column_enum::Tea>(db_backend)
+                .map(|stmt| stmt.to_string())
+        };
+
+        assert_eq!(
+            create_table(DbBackend::MySql),
+            [
+                "CREATE TABLE `column_enum` (",
+                "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+                "`tea` ENUM('EverydayTea', 'Builder''s Tea')",
+                ")",
+            ]
+            .join(" ")
+        );
+        assert_eq!(create_enum(DbBackend::MySql), None);
+
+        assert_eq!(
+            create_table(DbBackend::Postgres),
+            [
+                r#"CREATE TABLE "column_enum" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""tea" "tea""#,
+                ")",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            create_enum(DbBackend::Postgres),
+            Some(r#"CREATE TYPE "tea" AS ENUM ('EverydayTea', E'Builder\'s Tea')"#.to_owned())
+        );
+
+        assert_eq!(
+            create_table(DbBackend::Sqlite),
+            [
+                "CREATE TABLE `column_enum` (",
+                "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
+                "`tea` text",
+                ")",
+            ]
+            .join(" ")
+        );
+        assert_eq!(create_enum(DbBackend::Sqlite), None);
+
+        // without a backend, a native enum is written as the type of Postgres
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&Schema::create_table_from_entity(column_enum::Entity))
+                .to_string(),
+            create_table(DbBackend::Postgres)
+        );
+    }
+
+    #[test]
+    fn test_create_index_from_entity() {
+        assert_eq!(
+            Schema::create_index_from_entity(column_modifiers::Entity)
+                .iter()
+                .map(|stmt| DbBackend::Postgres.build(stmt).to_string())
+                .collect::<Vec<_>>(),
+            vec![r#"CREATE INDEX "idx-column_modifiers-name" ON "column_modifiers" ("name")"#]
+        );
+        assert!(Schema::create_index_from_entity(CakeFillingPrice).is_empty());
+    }
 }
@@ -31,20 +31,13 @@ impl Related<super::filling::Entity> for Entity {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, DeriveLinked)]
+#[sea_orm(
+    from = "Entity",
+    to = "super::filling::Entity",
+    via_from = "super::cake_filling::Relation::Cake",
+    via_to = "super::cake_filling::Relation::Filling"
+)]
 pub struct CakeToFilling;
 
-impl Linked for CakeToFilling {
-    type FromEntity = Entity;
-
-    type ToEntity = super::filling::Entity;
-
-    fn link(&self) -> Vec<RelationDef> {
-        vec![
-            super::cake_filling::Relation::Cake.def().rev(),
-            super::cake_filling::Relation::Filling.def(),
-        ]
-    }
-}
-
 impl ActiveModelBehavior for ActiveModel {}
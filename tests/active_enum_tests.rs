@@ -0,0 +1,122 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{entity::*, query::*, sea_query::Query, DatabaseConnection, DbErr};
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test active_enum_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("active_enum_tests").await;
+    insert_active_enum(&ctx.db).await?;
+    unknown_active_enum_value(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_active_enum(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use active_enum::*;
+
+    let model = ActiveModel {
+        category: Set(None),
+        color: Set(None),
+        tea: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    assert_eq!(Entity::find().one(db).await?.unwrap(), model);
+    assert_eq!(
+        model,
+        Model {
+            id: 1,
+            category: None,
+            color: None,
+            tea: None,
+        }
+    );
+
+    ActiveModel {
+        category: Set(Some(Category::Big)),
+        color: Set(Some(Color::Black)),
+        tea: Set(Some(Tea::EverydayTea)),
+        ..model.into_active_model()
+    }
+    .save(db)
+    .await?;
+
+    let model = Entity::find().one(db).await?.unwrap();
+    assert_eq!(
+        model,
+        Model {
+            id: 1,
+            category: Some(Category::Big),
+            color: Some(Color::Black),
+            tea: Some(Tea::EverydayTea),
+        }
+    );
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Category.eq(Category::Big))
+            .filter(Column::Color.eq(Color::Black))
+            .filter(Column::Tea.eq(Tea::EverydayTea))
+            .one(db)
+            .await?,
+        Some(model.clone())
+    );
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Tea.is_in(vec![Tea::BreakfastTea]))
+            .one(db)
+            .await?,
+        None
+    );
+
+    let model = ActiveModel {
+        tea: Set(Some(Tea::BreakfastTea)),
+        ..model.into_active_model()
+    }
+    .update(db)
+    .await?;
+    assert_eq!(model.tea, Some(Tea::BreakfastTea));
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Tea.is_in(vec![Tea::BreakfastTea]))
+            .one(db)
+            .await?,
+        Some(model)
+    );
+
+    Ok(())
+}
+
+pub async fn unknown_active_enum_value(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use active_enum::*;
+
+    let builder = db.get_database_backend();
+    db.execute(
+        builder.build(
+            Query::insert()
+                .into_table(Entity)
+                .columns(vec![Column::Id, Column::Category])
+                .values_panic(vec![2.into(), "X".into()]),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        Entity::find_by_id(2).one(db).await,
+        Err(DbErr::Query(
+            "unexpected value for Category enum: \"X\"".to_owned()
+        ))
+    );
+
+    Ok(())
+}
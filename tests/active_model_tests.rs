@@ -0,0 +1,186 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{entity::prelude::*, sea_query, ConnectionTrait, DatabaseConnection, Set};
+
+mod todo {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "todo")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub status: String,
+        pub priority: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test active_model_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("active_model_tests").await;
+    create_todo_table(&ctx.db).await?;
+    insert_returning_model(&ctx.db).await?;
+    update_returning_model(&ctx.db).await?;
+    save_insert_or_update(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_todo_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use sea_query::{ColumnDef, Table};
+
+    let stmt = Table::create()
+        .table(todo::Entity)
+        .col(
+            ColumnDef::new(todo::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(todo::Column::Title).string().not_null())
+        .col(
+            ColumnDef::new(todo::Column::Status)
+                .string()
+                .not_null()
+                .default("pending"),
+        )
+        .col(
+            ColumnDef::new(todo::Column::Priority)
+                .integer()
+                .not_null()
+                .default(3),
+        )
+        .to_owned();
+    let builder = db.get_database_backend();
+    db.execute(builder.build(&stmt)).await?;
+
+    Ok(())
+}
+
+pub async fn insert_returning_model(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // The primary key and the defaulted columns are filled by the database
+    let todo = todo::ActiveModel {
+        title: Set("Bake".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(
+        todo,
+        todo::Model {
+            id: 1,
+            title: "Bake".to_owned(),
+            status: "pending".to_owned(),
+            priority: 3,
+        }
+    );
+    assert_eq!(todo::Entity::find_by_id(1).one(db).await?, Some(todo));
+
+    // A primary key set on the model is returned as is
+    let todo = todo::ActiveModel {
+        id: Set(10),
+        title: Set("Deliver".to_owned()),
+        priority: Set(1),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(
+        todo,
+        todo::Model {
+            id: 10,
+            title: "Deliver".to_owned(),
+            status: "pending".to_owned(),
+            priority: 1,
+        }
+    );
+
+    Ok(())
+}
+
+pub async fn update_returning_model(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Columns not set keep their stored values
+    let todo = todo::ActiveModel {
+        id: Set(1),
+        status: Set("done".to_owned()),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+    assert_eq!(
+        todo,
+        todo::Model {
+            id: 1,
+            title: "Bake".to_owned(),
+            status: "done".to_owned(),
+            priority: 3,
+        }
+    );
+
+    // Nothing to update, the stored row is returned
+    let todo = todo::ActiveModel {
+        id: Set(10),
+        ..Default::default()
+    }
+    .update(db)
+    .await?;
+    assert_eq!(todo.title, "Deliver");
+
+    let res = todo::ActiveModel {
+        id: Set(99),
+        status: Set("done".to_owned()),
+        ..Default::default()
+    }
+    .update(db)
+    .await;
+    assert_eq!(
+        res,
+        Err(DbErr::RecordNotFound(
+            "Failed to find updated item".to_owned()
+        ))
+    );
+
+    Ok(())
+}
+
+pub async fn save_insert_or_update(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Primary key unset: inserted
+    let saved = todo::ActiveModel {
+        title: Set("Sell".to_owned()),
+        ..Default::default()
+    }
+    .save(db)
+    .await?;
+    let id = saved.id.clone().unwrap();
+    assert!(id > 10);
+    assert_eq!(saved.status.clone().unwrap(), "pending");
+
+    // Primary key loaded from the database: updated
+    let mut todo = saved;
+    todo.priority = Set(5);
+    let saved = todo.save(db).await?;
+    assert_eq!(saved.priority.clone().unwrap(), 5);
+    assert_eq!(todo::Entity::find().count(db).await?, 3);
+    assert_eq!(
+        todo::Entity::find_by_id(id).one(db).await?.map(|m| m.priority),
+        Some(5)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{entity::prelude::*, ConnectionTrait, DatabaseConnection, Schema, Set};
+
+mod attachment {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "attachment")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub content: Vec<u8>,
+        pub checksum: Option<Vec<u8>>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test binary_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    assert_eq!(attachment::Column::Content.def(), ColumnType::Binary.def());
+    assert_eq!(
+        attachment::Column::Checksum.def(),
+        ColumnType::Binary.def().null()
+    );
+
+    let ctx = TestContext::new("binary_tests").await;
+    create_attachment_table(&ctx.db).await?;
+    insert_binary(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_attachment_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    db.execute(backend.build(&Schema::create_table_from_entity(attachment::Entity)))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn insert_binary(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Not valid UTF-8, so it only survives as bytes
+    let content = vec![0, 159, 146, 150, 255, b'\n', 0];
+    assert!(String::from_utf8(content.clone()).is_err());
+
+    let file = attachment::ActiveModel {
+        content: Set(content.clone()),
+        checksum: Set(Some(vec![0xde, 0xad, 0xbe, 0xef])),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(file.content, content);
+
+    let empty = attachment::ActiveModel {
+        content: Set(Vec::new()),
+        checksum: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(empty.content, Vec::<u8>::new());
+    assert_eq!(empty.checksum, None);
+
+    assert_eq!(
+        attachment::Entity::find_by_id(file.id).one(db).await?,
+        Some(file.clone())
+    );
+    assert_eq!(
+        attachment::Entity::find_by_id(empty.id).one(db).await?,
+        Some(empty.clone())
+    );
+    assert_eq!(
+        attachment::Entity::find()
+            .filter(attachment::Column::Content.eq(content))
+            .all(db)
+            .await?,
+        vec![file]
+    );
+    assert_eq!(
+        attachment::Entity::find()
+            .filter(attachment::Column::Checksum.is_null())
+            .all(db)
+            .await?,
+        vec![empty]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{entity::prelude::*, ConnectionTrait, DatabaseConnection, QueryOrder, Schema, Set};
+
+// The columns in the order of the fields of the model
+mod declared {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "scoreboard")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub score: i32,
+        pub rank: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// The same table, with the variants of the column enum in another order than the fields
+mod reordered {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+    pub struct Entity;
+
+    impl EntityName for Entity {
+        fn table_name(&self) -> &str {
+            "scoreboard"
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, DeriveModel, DeriveActiveModel)]
+    pub struct Model {
+        pub id: i32,
+        pub name: String,
+        pub score: i32,
+        pub rank: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+    pub enum Column {
+        Rank,
+        Name,
+        Id,
+        Score,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+    pub enum PrimaryKey {
+        Id,
+    }
+
+    impl PrimaryKeyTrait for PrimaryKey {
+        type ValueType = i32;
+
+        fn auto_increment() -> bool {
+            true
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter)]
+    pub enum Relation {}
+
+    impl ColumnTrait for Column {
+        type EntityName = Entity;
+
+        fn def(&self) -> ColumnDef {
+            match self {
+                Self::Id => ColumnType::Integer.def(),
+                Self::Name => ColumnType::String(None).def(),
+                Self::Score => ColumnType::Integer.def(),
+                Self::Rank => ColumnType::Integer.def(),
+            }
+        }
+    }
+
+    impl RelationTrait for Relation {
+        fn def(&self) -> RelationDef {
+            panic!("No RelationDef")
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test column_order_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    // Columns are iterated in the order the variants are declared
+    assert_eq!(
+        reordered::Column::iter()
+            .map(|col| col.as_str().to_owned())
+            .collect::<Vec<_>>(),
+        vec!["rank", "name", "id", "score"]
+    );
+    assert_eq!(
+        declared::Column::iter()
+            .map(|col| col.as_str().to_owned())
+            .collect::<Vec<_>>(),
+        vec!["id", "name", "score", "rank"]
+    );
+
+    let ctx = TestContext::new("column_order_tests").await;
+    create_scoreboard_table(&ctx.db).await?;
+    insert_in_column_order(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_scoreboard_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    db.execute(backend.build(&Schema::create_table_from_entity(declared::Entity)))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn insert_in_column_order(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // Score and rank are both integers, so binding them to each other's column would go unnoticed
+    let alice = reordered::ActiveModel {
+        name: Set("Alice".to_owned()),
+        score: Set(980),
+        rank: Set(1),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!((alice.score, alice.rank), (980, 1));
+
+    reordered::Entity::insert_many(vec![
+        reordered::ActiveModel {
+            name: Set("Bob".to_owned()),
+            score: Set(720),
+            rank: Set(2),
+            ..Default::default()
+        },
+        reordered::ActiveModel {
+            name: Set("Carol".to_owned()),
+            score: Set(510),
+            rank: Set(3),
+            ..Default::default()
+        },
+    ])
+    .exec(db)
+    .await?;
+
+    declared::ActiveModel {
+        name: Set("Dave".to_owned()),
+        score: Set(300),
+        rank: Set(4),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    let expected = vec![
+        ("Alice", 980, 1),
+        ("Bob", 720, 2),
+        ("Carol", 510, 3),
+        ("Dave", 300, 4),
+    ];
+    assert_eq!(
+        declared::Entity::find()
+            .order_by_asc(declared::Column::Rank)
+            .all(db)
+            .await?
+            .iter()
+            .map(|row| (row.name.as_str(), row.score, row.rank))
+            .collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        reordered::Entity::find()
+            .order_by_asc(reordered::Column::Rank)
+            .all(db)
+            .await?
+            .iter()
+            .map(|row| (row.name.as_str(), row.score, row.rank))
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    Ok(())
+}
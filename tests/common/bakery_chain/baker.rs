@@ -38,6 +38,15 @@ impl Related<super::cake::Entity> for Entity {
     }
 }
 
+#[derive(Debug, DeriveLinked)]
+#[sea_orm(
+    from = "Entity",
+    to = "super::cake::Entity",
+    via_from = "super::cakes_bakers::Relation::Baker",
+    via_to = "super::cakes_bakers::Relation::Cake"
+)]
+pub struct BakerToCake;
+
 pub struct BakedForCustomer;
 
 impl Linked for BakedForCustomer {
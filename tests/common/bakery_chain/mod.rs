@@ -1,3 +1,4 @@
+pub mod active_enum;
 pub mod applog;
 pub mod baker;
 pub mod bakery;
@@ -1,4 +1,4 @@
-use sea_orm::{Database, DatabaseBackend, DatabaseConnection, ConnectionTrait, Statement};
+use sea_orm::{ConnectionTrait, Database, DatabaseBackend, DatabaseConnection, Statement};
 pub mod schema;
 pub use schema::*;
 
@@ -54,6 +54,7 @@ pub async fn setup(base_url: &str, db_name: &str) -> DatabaseConnection {
     schema::create_lineitem_table(&db).await.unwrap();
     schema::create_metadata_table(&db).await.unwrap();
     schema::create_log_table(&db).await.unwrap();
+    schema::create_active_enum_table(&db).await.unwrap();
     db
 }
 
@@ -76,6 +77,5 @@ pub async fn tear_down(base_url: &str, db_name: &str) {
                 format!("DROP DATABASE IF EXISTS \"{}\";", db_name),
             ))
             .await;
-    } else {
-    };
+    }
 }
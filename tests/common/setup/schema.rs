@@ -1,8 +1,12 @@
 pub use super::super::bakery_chain::*;
 use pretty_assertions::assert_eq;
-use sea_orm::{error::*, sea_query, ConnectionTrait, DbBackend, DbConn, EntityTrait, ExecResult, Schema};
+use sea_orm::{
+    error::*, sea_query, ConnectionTrait, DbBackend, DbConn, EntityTrait, ExecResult, Schema,
+    Statement,
+};
 use sea_query::{
-    Alias, ColumnDef, ForeignKey, ForeignKeyAction, Index, Table, TableCreateStatement,
+    extension::postgres::Type, Alias, ColumnDef, ForeignKey, ForeignKeyAction, Index,
+    PostgresQueryBuilder, Table, TableCreateStatement,
 };
 
 async fn create_table<E>(
@@ -26,7 +30,9 @@ where
 
     let stmt = builder.build(create);
     assert_eq!(
-        builder.build(&Schema::create_table_from_entity(entity)),
+        builder.build(&Schema::create_table_from_entity_for_backend(
+            entity, builder
+        )),
         stmt
     );
     db.execute(stmt).await
@@ -311,3 +317,41 @@ pub async fn create_log_table(db: &DbConn) -> Result<ExecResult, DbErr> {
 
     create_table(db, &stmt, Applog).await
 }
+
+pub async fn create_active_enum_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let builder = db.get_database_backend();
+    let mut tea = ColumnDef::new(active_enum::Column::Tea);
+    match builder {
+        DbBackend::MySql => tea.custom(Alias::new("ENUM('EverydayTea', 'BreakfastTea')")),
+        DbBackend::Postgres => tea.custom(Alias::new("\"tea\"")),
+        DbBackend::Sqlite => tea.text(),
+    };
+    if builder == DbBackend::Postgres {
+        let drop_type = Type::drop()
+            .name(Alias::new("tea"))
+            .if_exists()
+            .cascade()
+            .to_string(PostgresQueryBuilder);
+        db.execute(Statement::from_string(builder, drop_type))
+            .await?;
+    }
+    if let Some(stmt) = Schema::create_enum_from_active_enum::<active_enum::Tea>(builder) {
+        db.execute(stmt).await?;
+    }
+
+    let stmt = sea_query::Table::create()
+        .table(active_enum::Entity)
+        .col(
+            ColumnDef::new(active_enum::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(active_enum::Column::Category).string_len(1))
+        .col(ColumnDef::new(active_enum::Column::Color).integer())
+        .col(&mut tea)
+        .to_owned();
+
+    create_table(db, &stmt, active_enum::Entity).await
+}
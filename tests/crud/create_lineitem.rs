@@ -1,6 +1,7 @@
 pub use super::*;
 use chrono::offset::Utc;
 use rust_decimal_macros::dec;
+use sea_orm::{ConnectionTrait, QueryFilter, QueryTrait};
 use uuid::Uuid;
 
 pub async fn test_create_lineitem(db: &DbConn) {
@@ -96,10 +97,11 @@ pub async fn test_create_lineitem(db: &DbConn) {
         quantity: Set(1),
         ..Default::default()
     };
-    let lineitem_insert_res = Lineitem::insert(lineitem_1)
-        .exec(db)
-        .await
-        .expect("could not insert lineitem");
+    let insert = Lineitem::insert(lineitem_1);
+    let stmt = insert.build(db.get_database_backend());
+    assert!(!stmt.sql.contains(r#""id""#) && !stmt.sql.contains("`id`"));
+    assert_eq!(stmt.values.map(|values| values.0.len()), Some(4));
+    let lineitem_insert_res = insert.exec(db).await.expect("could not insert lineitem");
 
     let lineitem: Option<lineitem::Model> =
         Lineitem::find_by_id(lineitem_insert_res.last_insert_id)
@@ -111,6 +113,8 @@ pub async fn test_create_lineitem(db: &DbConn) {
     let lineitem_model = lineitem.unwrap();
 
     assert_eq!(lineitem_model.price, dec!(7.55));
+    assert_eq!(lineitem_model.id, lineitem_insert_res.last_insert_id);
+    assert_ne!(lineitem_model.id, 0);
 
     let cake: Option<cake::Model> = Cake::find_by_id(lineitem_model.cake_id)
         .one(db)
@@ -130,4 +134,65 @@ pub async fn test_create_lineitem(db: &DbConn) {
         order_model.customer_id,
         customer_insert_res.last_insert_id as i32
     );
+
+    let lineitem_2 = lineitem::ActiveModel {
+        cake_id: Set(cake_insert_res.last_insert_id as i32),
+        order_id: Set(order_insert_res.last_insert_id as i32),
+        price: Set(dec!(15.10)),
+        quantity: Set(2),
+        ..Default::default()
+    };
+    let lineitem_2_insert_res = Lineitem::insert(lineitem_2)
+        .exec(db)
+        .await
+        .expect("could not insert lineitem");
+
+    let mut lineitem_ids = Lineitem::find()
+        .filter(lineitem::Column::OrderId.eq(order_insert_res.last_insert_id as i32))
+        .into_primary_key_values(db)
+        .await
+        .expect("could not find lineitem ids");
+    lineitem_ids.sort_unstable();
+    assert_eq!(
+        lineitem_ids,
+        vec![
+            lineitem_insert_res.last_insert_id,
+            lineitem_2_insert_res.last_insert_id
+        ]
+    );
+
+    let lineitems_by_id: std::collections::HashMap<i32, lineitem::Model> = Lineitem::find()
+        .filter(lineitem::Column::OrderId.eq(order_insert_res.last_insert_id as i32))
+        .all(db)
+        .await
+        .expect("could not find lineitems")
+        .into_iter()
+        .map(|model| (model.get_primary_key_value(), model))
+        .collect();
+    assert_eq!(lineitems_by_id.len(), 2);
+    assert_eq!(
+        lineitems_by_id[&lineitem_2_insert_res.last_insert_id].price,
+        dec!(15.10)
+    );
+
+    let lineitem_ids = Lineitem::find()
+        .filter(lineitem::Column::OrderId.eq(order_insert_res.last_insert_id as i32))
+        .filter(lineitem::Column::Quantity.gt(1))
+        .into_primary_key_values(db)
+        .await
+        .expect("could not find lineitem ids");
+    assert_eq!(lineitem_ids, vec![lineitem_2_insert_res.last_insert_id]);
+
+    let cake_baker_ids = CakesBakers::find()
+        .filter(cakes_bakers::Column::CakeId.eq(cake_insert_res.last_insert_id as i32))
+        .into_primary_key_values(db)
+        .await
+        .expect("could not find cake_baker ids");
+    assert_eq!(
+        cake_baker_ids,
+        vec![(
+            cake_insert_res.last_insert_id as i32,
+            baker_insert_res.last_insert_id as i32
+        )]
+    );
 }
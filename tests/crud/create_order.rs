@@ -1,6 +1,7 @@
 pub use super::*;
 use chrono::offset::Utc;
 use rust_decimal_macros::dec;
+use sea_orm::QueryFilter;
 use uuid::Uuid;
 
 pub async fn test_create_order(db: &DbConn) {
@@ -134,4 +135,19 @@ pub async fn test_create_order(db: &DbConn) {
     assert_eq!(related_lineitems.len(), 1);
     assert_eq!(related_lineitems[0].price, dec!(7.55));
     assert_eq!(related_lineitems[0].quantity, 2);
+
+    let filtered_lineitems: Vec<lineitem::Model> = Lineitem::find()
+        .filter(lineitem::Column::OrderId.eq(order_model.id))
+        .all(db)
+        .await
+        .expect("could not find lineitems");
+    assert_eq!(related_lineitems, filtered_lineitems);
+
+    // And the other way round, from the order to the customer it belongs to
+    let related_customer: Option<customer::Model> = order_model
+        .find_related(Customer)
+        .one(db)
+        .await
+        .expect("could not find related customer");
+    assert_eq!(related_customer, Some(customer_model));
 }
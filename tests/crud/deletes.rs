@@ -59,3 +59,43 @@ pub async fn test_delete_bakery(db: &DbConn) {
         initial_bakeries
     );
 }
+
+pub async fn test_delete_lineitem(db: &DbConn) {
+    let initial_lineitems = Lineitem::find().count(db).await.unwrap();
+
+    let lineitem = Lineitem::find()
+        .one(db)
+        .await
+        .expect("could not find lineitem")
+        .expect("no lineitem to delete");
+    let lineitem_id = lineitem.id;
+
+    let result = lineitem
+        .delete(db)
+        .await
+        .expect("failed to delete lineitem");
+    assert_eq!(result.rows_affected, 1);
+    assert_eq!(
+        Lineitem::find().count(db).await.unwrap(),
+        initial_lineitems - 1
+    );
+    assert_eq!(
+        Lineitem::find_by_id(lineitem_id).one(db).await.unwrap(),
+        None
+    );
+
+    let result = lineitem::ActiveModel {
+        price: Set(dec!(1.00)),
+        ..Default::default()
+    }
+    .delete(db)
+    .await;
+    assert_eq!(
+        result.unwrap_err(),
+        DbErr::Exec("Primary key `id` is not set".to_owned())
+    );
+    assert_eq!(
+        Lineitem::find().count(db).await.unwrap(),
+        initial_lineitems - 1
+    );
+}
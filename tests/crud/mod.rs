@@ -13,7 +13,7 @@ pub use deletes::*;
 pub use updates::*;
 
 pub use super::common::bakery_chain::*;
-use sea_orm::{entity::*, DbConn};
+use sea_orm::{entity::*, ConnectionTrait, DbBackend, DbConn, DbErr, QueryFilter, SqlErr};
 
 pub async fn test_create_bakery(db: &DbConn) {
     let seaside_bakery = bakery::ActiveModel {
@@ -58,3 +58,152 @@ pub async fn test_create_customer(db: &DbConn) {
     assert_eq!(customer_model.name, "Kate");
     assert_eq!(customer_model.notes, Some("Loves cheese cake".to_owned()));
 }
+
+pub async fn test_customer_exists(db: &DbConn) {
+    let exists = |name: &'static str| {
+        Customer::find()
+            .filter(customer::Column::Name.eq(name))
+            .exists(db)
+    };
+    assert!(exists("Kate").await.expect("could not check customer"));
+    assert!(!exists("Nobody").await.expect("could not check customer"));
+}
+
+pub async fn test_insert_on_conflict_do_nothing(db: &DbConn) {
+    let customer_jim = customer::ActiveModel {
+        id: Set(100),
+        name: Set("Jim".to_owned()),
+        notes: Set(None),
+    };
+
+    let rows_affected = Customer::insert(customer_jim.clone())
+        .on_conflict_do_nothing()
+        .exec_without_returning(db)
+        .await
+        .expect("could not insert customer");
+    assert_eq!(rows_affected, 1);
+
+    let rows_affected = Customer::insert(customer::ActiveModel {
+        name: Set("Jim 2".to_owned()),
+        ..customer_jim
+    })
+    .on_conflict_do_nothing()
+    .exec_without_returning(db)
+    .await
+    .expect("could not skip duplicated customer");
+    assert_eq!(rows_affected, 0);
+
+    let customer: Option<customer::Model> = Customer::find_by_id(100)
+        .one(db)
+        .await
+        .expect("could not find customer");
+    assert_eq!(customer.unwrap().name, "Jim");
+}
+
+pub async fn test_insert_unique_violation(db: &DbConn) {
+    let customer_amy = customer::ActiveModel {
+        id: Set(101),
+        name: Set("Amy".to_owned()),
+        notes: Set(None),
+    };
+
+    Customer::insert(customer_amy.clone())
+        .exec(db)
+        .await
+        .expect("could not insert customer");
+
+    let err = Customer::insert(customer_amy)
+        .exec(db)
+        .await
+        .expect_err("inserted a duplicated customer");
+    assert!(matches!(err, DbErr::Database(_)));
+    assert_eq!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+pub async fn test_insert_many_with_returning(db: &DbConn) {
+    let new_customer = |name: &str| customer::ActiveModel {
+        name: Set(name.to_owned()),
+        notes: Set(None),
+        ..Default::default()
+    };
+
+    let names = ["Ann", "Ben", "Cid"];
+    let ids = Customer::insert_many(names.iter().map(|name| new_customer(name)))
+        .exec_with_returning_keys(db)
+        .await
+        .expect("could not insert customers");
+    assert_eq!(ids.len(), names.len());
+    for (id, name) in ids.iter().zip(names) {
+        let customer = Customer::find_by_id(*id)
+            .one(db)
+            .await
+            .expect("could not find customer")
+            .expect("no customer of the returned id");
+        assert_eq!(customer.name, name);
+    }
+
+    let customers = Customer::insert_many(vec![new_customer("Dee"), new_customer("Eve")])
+        .exec_with_returning_many(db)
+        .await
+        .expect("could not insert customers");
+    assert_eq!(
+        customers
+            .iter()
+            .map(|customer| customer.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Dee", "Eve"]
+    );
+    assert_eq!(customers[1].id, customers[0].id + 1);
+    assert!(customers[0].id > ids[2]);
+    for customer in customers {
+        assert_eq!(
+            Customer::find_by_id(customer.id)
+                .one(db)
+                .await
+                .expect("could not find customer"),
+            Some(customer)
+        );
+    }
+}
+
+pub async fn test_insert_foreign_key_violation(db: &DbConn) {
+    let order = Order::find()
+        .one(db)
+        .await
+        .expect("could not find order")
+        .expect("no order inserted");
+
+    let err = Lineitem::insert(lineitem::ActiveModel {
+        cake_id: Set(9999),
+        order_id: Set(order.id),
+        price: Set(rust_decimal_macros::dec!(1.00)),
+        quantity: Set(1),
+        ..Default::default()
+    })
+    .exec(db)
+    .await
+    .expect_err("inserted a lineitem of a missing cake");
+    assert_eq!(err.sql_err(), Some(SqlErr::ForeignKeyConstraintViolation));
+
+    let constraint = match &err {
+        DbErr::Database(e) => e.constraint(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        constraint,
+        match db.get_database_backend() {
+            DbBackend::Sqlite => None,
+            _ => Some("fk-lineitem-cake"),
+        }
+    );
+
+    assert_eq!(
+        Lineitem::find()
+            .filter(lineitem::Column::CakeId.eq(9999))
+            .count(db)
+            .await
+            .expect("could not count lineitems"),
+        0
+    );
+}
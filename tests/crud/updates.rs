@@ -42,8 +42,7 @@ pub async fn test_update_cake(db: &DbConn) {
     cake_am.name = Set("Extra chocolate mud cake".to_owned());
     cake_am.price = Set(dec!(20.00));
 
-    let _cake_update_res: cake::ActiveModel =
-        cake_am.update(db).await.expect("could not update cake");
+    let _cake_update_res: cake::Model = cake_am.update(db).await.expect("could not update cake");
 
     let cake: Option<cake::Model> = Cake::find_by_id(cake_insert_res.last_insert_id)
         .one(db)
@@ -79,7 +78,7 @@ pub async fn test_update_bakery(db: &DbConn) {
     bakery_am.name = Set("SeaBreeze Bakery".to_owned());
     bakery_am.profit_margin = Set(12.00);
 
-    let _bakery_update_res: bakery::ActiveModel =
+    let _bakery_update_res: bakery::Model =
         bakery_am.update(db).await.expect("could not update bakery");
 
     let bakery: Option<bakery::Model> = Bakery::find_by_id(bakery_insert_res.last_insert_id)
@@ -119,10 +118,13 @@ pub async fn test_update_deleted_customer(db: &DbConn) {
         ..Default::default()
     };
 
-    let _customer_update_res: customer::ActiveModel = customer
-        .update(db)
-        .await
-        .expect("could not update customer");
+    let customer_update_res = customer.update(db).await;
+    assert_eq!(
+        customer_update_res,
+        Err(DbErr::RecordNotFound(
+            "Failed to find updated item".to_owned()
+        ))
+    );
 
     assert_eq!(Customer::find().count(db).await.unwrap(), init_n_customers);
 
@@ -133,3 +135,102 @@ pub async fn test_update_deleted_customer(db: &DbConn) {
 
     assert_eq!(customer, None);
 }
+
+pub async fn test_update_many_rows_affected(db: &DbConn) {
+    use sea_orm::{sea_query::Query, ConnectionTrait};
+
+    for _ in 0..3 {
+        bakery::ActiveModel {
+            name: Set("Rows Bakery".to_owned()),
+            profit_margin: Set(1.0),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let update = |name: &str| {
+        Query::update()
+            .table(bakery::Entity)
+            .values(vec![(bakery::Column::ProfitMargin, 2.5.into())])
+            .and_where(bakery::Column::Name.eq(name))
+            .to_owned()
+    };
+    let builder = db.get_database_backend();
+
+    let res = db
+        .execute(builder.build(&update("Rows Bakery")))
+        .await
+        .expect("could not update bakeries");
+    assert_eq!(res.rows_affected(), 3);
+
+    let res = db
+        .execute(builder.build(&update("No Such Bakery")))
+        .await
+        .expect("could not update bakeries");
+    assert_eq!(res.rows_affected(), 0);
+}
+
+pub async fn test_update_many_with_values(db: &DbConn) {
+    use sea_orm::QueryOrder;
+
+    let mut bakeries = Vec::new();
+    for _ in 0..3 {
+        let bakery = bakery::ActiveModel {
+            name: Set("Totals Bakery".to_owned()),
+            profit_margin: Set(1.0),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .expect("could not insert bakery");
+        bakeries.push(bakery);
+    }
+
+    let res = Bakery::update_many_with_values(vec![
+        bakery::ActiveModel {
+            id: Set(bakeries[0].id),
+            profit_margin: Set(3.5),
+            ..Default::default()
+        },
+        bakery::ActiveModel {
+            id: Set(bakeries[1].id),
+            name: Set("Renamed Bakery".to_owned()),
+            profit_margin: Set(4.5),
+        },
+        // Nothing to set
+        bakery::ActiveModel {
+            id: Set(bakeries[2].id),
+            ..Default::default()
+        },
+    ])
+    .exec(db)
+    .await
+    .expect("could not update bakeries");
+    assert_eq!(res.rows_affected, 2);
+
+    let updated = Bakery::find()
+        .filter(bakery::Column::Id.is_in(bakeries.iter().map(|bakery| bakery.id)))
+        .order_by_asc(bakery::Column::Id)
+        .all(db)
+        .await
+        .expect("could not find bakeries")
+        .into_iter()
+        .map(|bakery| (bakery.name, bakery.profit_margin))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        updated,
+        vec![
+            ("Totals Bakery".to_owned(), 3.5),
+            ("Renamed Bakery".to_owned(), 4.5),
+            ("Totals Bakery".to_owned(), 1.0),
+        ]
+    );
+
+    let res = Bakery::update_many_with_values(Vec::<bakery::ActiveModel>::new())
+        .exec(db)
+        .await
+        .expect("could not update no bakery");
+    assert_eq!(res.rows_affected, 0);
+}
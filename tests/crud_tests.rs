@@ -25,14 +25,22 @@ pub async fn create_entities(db: &DatabaseConnection) {
     test_create_bakery(db).await;
     test_create_baker(db).await;
     test_create_customer(db).await;
+    test_customer_exists(db).await;
+    test_insert_on_conflict_do_nothing(db).await;
+    test_insert_unique_violation(db).await;
+    test_insert_many_with_returning(db).await;
     test_create_cake(db).await;
     test_create_lineitem(db).await;
+    test_insert_foreign_key_violation(db).await;
     test_create_order(db).await;
 
     test_update_cake(db).await;
     test_update_bakery(db).await;
     test_update_deleted_customer(db).await;
+    test_update_many_rows_affected(db).await;
+    test_update_many_with_values(db).await;
 
     test_delete_cake(db).await;
     test_delete_bakery(db).await;
+    test_delete_lineitem(db).await;
 }
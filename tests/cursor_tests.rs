@@ -0,0 +1,167 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+pub use sea_orm::{entity::*, DatabaseConnection, DbErr};
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test cursor_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn cursor_lineitem() -> Result<(), DbErr> {
+    let ctx = TestContext::new("cursor_lineitem").await;
+    let lineitems = seed_lineitems(&ctx.db, 10).await?;
+    let ids: Vec<i32> = lineitems.iter().map(|(id, _)| *id).collect();
+
+    // walk forward a page at a time
+    let mut forward = Vec::new();
+    let mut cursor: Option<i32> = None;
+    loop {
+        let mut page = Lineitem::find().cursor_by(lineitem::Column::Id);
+        if let Some(id) = cursor {
+            page = page.after(id);
+        }
+        let page = page.first(3).all(&ctx.db).await?;
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 3);
+        cursor = page.last().map(|lineitem| lineitem.id);
+        forward.extend(page.into_iter().map(|lineitem| lineitem.id));
+    }
+    assert_eq!(forward, ids);
+
+    // and backward from the end
+    let mut backward = Vec::new();
+    let mut cursor: Option<i32> = None;
+    loop {
+        let mut page = Lineitem::find().cursor_by(lineitem::Column::Id);
+        if let Some(id) = cursor {
+            page = page.before(id);
+        }
+        let page = page.last(3).all(&ctx.db).await?;
+        if page.is_empty() {
+            break;
+        }
+        cursor = page.first().map(|lineitem| lineitem.id);
+        let mut page: Vec<i32> = page.into_iter().map(|lineitem| lineitem.id).collect();
+        page.append(&mut backward);
+        backward = page;
+    }
+    assert_eq!(backward, ids);
+
+    // a window between two cursors
+    let window = Lineitem::find()
+        .cursor_by(lineitem::Column::Id)
+        .after(ids[2])
+        .before(ids[6])
+        .all(&ctx.db)
+        .await?;
+    assert_eq!(
+        window.into_iter().map(|lineitem| lineitem.id).collect::<Vec<_>>(),
+        ids[3..6].to_vec()
+    );
+
+    // composite cursor over (quantity, id); quantities repeat across rows
+    let mut composite = Vec::new();
+    let mut cursor: Option<(i32, i32)> = None;
+    loop {
+        let mut page =
+            Lineitem::find().cursor_by((lineitem::Column::Quantity, lineitem::Column::Id));
+        if let Some(values) = cursor {
+            page = page.after(values);
+        }
+        let page = page.first(4).all(&ctx.db).await?;
+        if page.is_empty() {
+            break;
+        }
+        cursor = page.last().map(|lineitem| (lineitem.quantity, lineitem.id));
+        composite.extend(page.into_iter().map(|lineitem| (lineitem.quantity, lineitem.id)));
+    }
+    let mut expected: Vec<(i32, i32)> = lineitems
+        .iter()
+        .map(|(id, quantity)| (*quantity, *id))
+        .collect();
+    expected.sort_unstable();
+    assert_eq!(composite, expected);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn seed_lineitems(
+    db: &DatabaseConnection,
+    num_lineitems: i32,
+) -> Result<Vec<(i32, i32)>, DbErr> {
+    use chrono::offset::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    let bakery_id = Bakery::insert(bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?
+    .last_insert_id;
+
+    let customer_id = Customer::insert(customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        notes: Set(None),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?
+    .last_insert_id;
+
+    let cake_id = Cake::insert(cake::ActiveModel {
+        name: Set("Mud Cake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery_id)),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?
+    .last_insert_id;
+
+    let order_id = Order::insert(order::ActiveModel {
+        bakery_id: Set(bakery_id),
+        customer_id: Set(customer_id),
+        total: Set(dec!(10.25)),
+        placed_at: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?
+    .last_insert_id;
+
+    let mut lineitems = Vec::new();
+    for i in 0..num_lineitems {
+        // repeat quantities to give the composite cursor duplicate leading values
+        let quantity = i % 3;
+        let id = Lineitem::insert(lineitem::ActiveModel {
+            cake_id: Set(cake_id),
+            order_id: Set(order_id),
+            price: Set(dec!(10.25)),
+            quantity: Set(quantity),
+            ..Default::default()
+        })
+        .exec(db)
+        .await?
+        .last_insert_id;
+        lineitems.push((id, quantity));
+    }
+    Ok(lineitems)
+}
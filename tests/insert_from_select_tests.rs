@@ -0,0 +1,126 @@
+pub mod common;
+
+pub use common::TestContext;
+use sea_orm::{
+    entity::prelude::*, ConnectionTrait, DatabaseConnection, QueryOrder, QuerySelect, Schema, Set,
+};
+
+mod sale {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "sale")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub customer: String,
+        pub total: i32,
+        pub placed_at: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod archived_sale {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "archived_sale")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub sale_id: i32,
+        pub total: i32,
+        pub customer: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test insert_from_select_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_from_select_tests").await;
+    create_tables(&ctx.db).await?;
+    archive_old_sales(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    db.execute(backend.build(&Schema::create_table_from_entity(sale::Entity)))
+        .await?;
+    db.execute(backend.build(&Schema::create_table_from_entity(archived_sale::Entity)))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn archive_old_sales(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let sale = |customer: &str, total, placed_at| sale::ActiveModel {
+        customer: Set(customer.to_owned()),
+        total: Set(total),
+        placed_at: Set(placed_at),
+        ..Default::default()
+    };
+    sale::Entity::insert_many(vec![
+        sale("Alice", 120, 2019),
+        sale("Bob", 80, 2020),
+        sale("Carol", 45, 2021),
+        sale("Dave", 300, 2022),
+    ])
+    .exec(db)
+    .await?;
+
+    // The columns are listed in another order than the ones of the table
+    let archived = archived_sale::Entity::insert_from_select(
+        [
+            archived_sale::Column::SaleId,
+            archived_sale::Column::Customer,
+            archived_sale::Column::Total,
+        ],
+        sale::Entity::find()
+            .select_only()
+            .column(sale::Column::Id)
+            .column(sale::Column::Customer)
+            .column(sale::Column::Total)
+            .filter(sale::Column::PlacedAt.lt(2021)),
+    )?
+    .exec(db)
+    .await?;
+    assert_eq!(archived, 2);
+
+    assert_eq!(
+        archived_sale::Entity::find()
+            .order_by_asc(archived_sale::Column::SaleId)
+            .all(db)
+            .await?,
+        vec![
+            archived_sale::Model {
+                sale_id: 1,
+                total: 120,
+                customer: "Alice".to_owned(),
+            },
+            archived_sale::Model {
+                sale_id: 2,
+                total: 80,
+                customer: "Bob".to_owned(),
+            },
+        ]
+    );
+
+    Ok(())
+}
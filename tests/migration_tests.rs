@@ -0,0 +1,208 @@
+pub mod common;
+
+pub use common::TestContext;
+use sea_orm::{
+    sea_query::{Alias, Expr, Order, Query},
+    ConnectionTrait, DatabaseConnection, DbErr, MigrationName, MigrationTrait, MigratorTrait,
+};
+
+mod m20220101_000001_create_recipe {
+    use sea_orm::{
+        sea_query::{Alias, ColumnDef, Table},
+        DbErr, DeriveMigrationName, MigrationTrait, SchemaManager,
+    };
+
+    #[derive(DeriveMigrationName)]
+    pub struct Migration;
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("recipe"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .auto_increment()
+                                .primary_key(),
+                        )
+                        .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new("recipe")).to_owned())
+                .await
+        }
+    }
+}
+
+mod m20220101_000002_create_ingredient {
+    use sea_orm::{
+        sea_query::{Alias, ColumnDef, Table},
+        DbErr, DeriveMigrationName, MigrationTrait, SchemaManager,
+    };
+
+    #[derive(DeriveMigrationName)]
+    pub struct Migration;
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("ingredient"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .auto_increment()
+                                .primary_key(),
+                        )
+                        .col(ColumnDef::new(Alias::new("recipe_id")).integer().not_null())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new("ingredient")).to_owned())
+                .await
+        }
+    }
+}
+
+pub struct Migrator;
+
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20220101_000001_create_recipe::Migration),
+            Box::new(m20220101_000002_create_ingredient::Migration),
+        ]
+    }
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test migration_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("migration_tests").await;
+    migration_name();
+    apply_and_roll_back(&ctx.db).await?;
+    unknown_migration(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub fn migration_name() {
+    assert_eq!(
+        m20220101_000001_create_recipe::Migration.name(),
+        "m20220101_000001_create_recipe"
+    );
+}
+
+async fn applied_versions(db: &DatabaseConnection) -> Result<Vec<String>, DbErr> {
+    let stmt = Query::select()
+        .column(Alias::new("version"))
+        .from(Alias::new("seaql_migrations"))
+        .order_by(Alias::new("version"), Order::Asc)
+        .to_owned();
+    let builder = db.get_database_backend();
+    db.query_all(builder.build(&stmt))
+        .await?
+        .iter()
+        .map(|row| row.try_get("", "version"))
+        .collect()
+}
+
+async fn has_table(db: &DatabaseConnection, table: &str) -> bool {
+    let stmt = Query::select()
+        .expr(Expr::val(1))
+        .from(Alias::new(table))
+        .to_owned();
+    let builder = db.get_database_backend();
+    db.query_all(builder.build(&stmt)).await.is_ok()
+}
+
+pub async fn apply_and_roll_back(db: &DatabaseConnection) -> Result<(), DbErr> {
+    Migrator::up(db, None).await?;
+    assert_eq!(
+        applied_versions(db).await?,
+        vec![
+            "m20220101_000001_create_recipe".to_owned(),
+            "m20220101_000002_create_ingredient".to_owned(),
+        ]
+    );
+    assert!(has_table(db, "recipe").await);
+    assert!(has_table(db, "ingredient").await);
+
+    // Applying again runs nothing, the tables are not created twice
+    Migrator::up(db, None).await?;
+    assert_eq!(applied_versions(db).await?.len(), 2);
+    assert!(Migrator::get_pending_migrations(db).await?.is_empty());
+
+    Migrator::down(db, Some(1)).await?;
+    assert_eq!(
+        applied_versions(db).await?,
+        vec!["m20220101_000001_create_recipe".to_owned()]
+    );
+    assert!(has_table(db, "recipe").await);
+    assert!(!has_table(db, "ingredient").await);
+    assert_eq!(
+        Migrator::get_pending_migrations(db)
+            .await?
+            .iter()
+            .map(|m| m.name().to_owned())
+            .collect::<Vec<_>>(),
+        vec!["m20220101_000002_create_ingredient".to_owned()]
+    );
+
+    Ok(())
+}
+
+pub struct EmptyMigrator;
+
+impl MigratorTrait for EmptyMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        Vec::new()
+    }
+}
+
+pub async fn unknown_migration(db: &DatabaseConnection) -> Result<(), DbErr> {
+    assert_eq!(
+        EmptyMigrator::up(db, None).await,
+        Err(DbErr::Migration(
+            "migration 'm20220101_000001_create_recipe' is applied but is not one of the migrations of the migrator".to_owned()
+        ))
+    );
+
+    // Nothing is rolled back when the applied migrations do not match
+    assert_eq!(
+        EmptyMigrator::down(db, None).await,
+        Err(DbErr::Migration(
+            "migration 'm20220101_000001_create_recipe' is applied but is not one of the migrations of the migrator".to_owned()
+        ))
+    );
+    assert_eq!(
+        applied_versions(db).await?,
+        vec!["m20220101_000001_create_recipe".to_owned()]
+    );
+    assert!(has_table(db, "recipe").await);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ pub mod common;
 
 pub use common::{bakery_chain::*, setup::*, TestContext};
 pub use sea_orm::entity::*;
-pub use sea_orm::{QueryFilter, ConnectionTrait};
+pub use sea_orm::{NullOrdering, Order, QueryFilter, QueryOrder, QuerySelect, ConnectionTrait};
 
 // Run the test locally:
 // DATABASE_URL="mysql://root:@localhost" cargo test --features sqlx-mysql,runtime-async-std --test query_tests
@@ -214,3 +214,622 @@ pub async fn find_all_filter_with_results() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_into_tuple() {
+    let ctx = TestContext::new("find_into_tuple").await;
+
+    let select = Bakery::find()
+        .select_only()
+        .column_as(bakery::Column::Id.count(), "num_bakeries")
+        .column_as(bakery::Column::Name.max(), "max_name");
+
+    let result = select
+        .clone()
+        .into_tuple::<(i64, Option<String>)>()
+        .one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(result, Some((0, None)));
+
+    for name in ["SeaSide Bakery", "Top Bakery"] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let result = select
+        .into_tuple::<(i64, Option<String>)>()
+        .one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(result, Some((2, Some("Top Bakery".to_owned()))));
+
+    let names: Vec<String> = Bakery::find()
+        .select_only()
+        .column(bakery::Column::Name)
+        .order_by_asc(bakery::Column::Name)
+        .into_tuple()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(
+        names,
+        vec!["SeaSide Bakery".to_owned(), "Top Bakery".to_owned()]
+    );
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_order_by_with_nulls() {
+    let ctx = TestContext::new("find_order_by_with_nulls").await;
+
+    for (name, notes) in [
+        ("Kate", Some("Loves cheese cake")),
+        ("Jim", None),
+        ("Amy", Some("Loves all cakes")),
+    ] {
+        customer::ActiveModel {
+            name: Set(name.to_owned()),
+            notes: Set(notes.map(ToOwned::to_owned)),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await
+        .expect("could not insert customer");
+    }
+
+    let names = |customers: Vec<customer::Model>| -> Vec<String> {
+        customers.into_iter().map(|customer| customer.name).collect()
+    };
+
+    let customers = Customer::find()
+        .order_by_with_nulls(customer::Column::Notes, Order::Asc, NullOrdering::Last)
+        .order_by_asc(customer::Column::Name)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(names(customers), vec!["Amy", "Kate", "Jim"]);
+
+    let customers = Customer::find()
+        .order_by_with_nulls(customer::Column::Notes, Order::Desc, NullOrdering::First)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(names(customers), vec!["Jim", "Kate", "Amy"]);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_contains_literal_wildcards() {
+    let ctx = TestContext::new("find_contains_literal_wildcards").await;
+
+    for name in ["50% Off", "500 Off", "Snake_Bakery", "SnakeyBakery", r"C:\Bakery"] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let names = |filter| async {
+        Bakery::find()
+            .filter(filter)
+            .all(&ctx.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(bakery::Column::Name.contains("50%")).await, vec!["50% Off"]);
+    assert_eq!(
+        names(bakery::Column::Name.starts_with("Snake_")).await,
+        vec!["Snake_Bakery"]
+    );
+    assert_eq!(
+        names(bakery::Column::Name.ends_with(r":\Bakery")).await,
+        vec![r"C:\Bakery"]
+    );
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_by_raw_statement() {
+    use sea_orm::{DbErr, Statement};
+
+    let ctx = TestContext::new("find_by_raw_statement").await;
+
+    let bakeries = [
+        ("SeaSide Bakery", 10.4),
+        ("Top Bakery", 15.0),
+        ("Low Bakery", 2.5),
+    ];
+    for (name, profit_margin) in bakeries {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let builder = ctx.db.get_database_backend();
+    let bakeries = Bakery::find_by_statement(Statement::from_sql_and_values(
+        builder,
+        &[
+            "WITH profitable AS (SELECT * FROM bakery WHERE profit_margin > ",
+            if builder == sea_orm::DbBackend::Postgres { "$1" } else { "?" },
+            ") SELECT id, name, profit_margin FROM profitable ORDER BY profit_margin DESC",
+        ]
+        .join(""),
+        vec![5.0.into()],
+    ))
+    .all(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(
+        bakeries.into_iter().map(|bakery| bakery.name).collect::<Vec<_>>(),
+        vec!["Top Bakery", "SeaSide Bakery"]
+    );
+
+    // A column of the model is missing from the statement
+    let res = Bakery::find_by_statement(Statement::from_string(
+        builder,
+        "SELECT id, name FROM bakery".to_owned(),
+    ))
+    .one(&ctx.db)
+    .await;
+    let expected = "Failed to decode `query_tests::common::bakery_chain::bakery::Model` \
+                    from the columns [id, name] of the statement:";
+    match res {
+        Err(DbErr::Query(msg)) => assert!(msg.starts_with(expected), "{}", msg),
+        res => panic!("unexpected {:?}", res),
+    }
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_is_in_array() {
+    let ctx = TestContext::new("find_is_in_array").await;
+
+    let mut ids = Vec::new();
+    for name in ["SeaSide Bakery", "Top Bakery", "Low Bakery"] {
+        let bakery = bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+        ids.push(bakery.id);
+    }
+
+    // Bound as one array on Postgres, expanded to `IN` elsewhere
+    let builder = ctx.db.get_database_backend();
+    let names = |filter| async {
+        Bakery::find()
+            .filter(filter)
+            .order_by_asc(bakery::Column::Id)
+            .all(&ctx.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(bakery::Column::Id.is_in_array(builder, vec![ids[0], ids[2]])).await,
+        vec!["SeaSide Bakery", "Low Bakery"]
+    );
+    assert_eq!(
+        names(bakery::Column::Name.is_in_array(builder, vec!["Top Bakery", "No Bakery"])).await,
+        vec!["Top Bakery"]
+    );
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_on_any_connection() {
+    use sea_orm::MockDatabase;
+
+    let ctx = TestContext::new("find_on_any_connection").await;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    // The same query runs on a pool connection, a transaction and the mock
+    let select = Bakery::find().filter(bakery::Column::Name.contains("Bakery"));
+
+    assert_eq!(select.clone().one(&ctx.db).await.unwrap(), Some(bakery.clone()));
+    assert_eq!(select.clone().all(&ctx.db).await.unwrap(), vec![bakery.clone()]);
+
+    let txn = ctx.db.begin().await.unwrap();
+    assert_eq!(select.clone().one(&txn).await.unwrap(), Some(bakery.clone()));
+    assert_eq!(select.clone().all(&txn).await.unwrap(), vec![bakery.clone()]);
+    txn.commit().await.unwrap();
+
+    let mock = MockDatabase::new(ctx.db.get_database_backend())
+        .append_query_results(vec![vec![bakery.clone()], vec![bakery.clone()], vec![]])
+        .into_connection();
+    assert_eq!(select.clone().one(&mock).await.unwrap(), Some(bakery.clone()));
+    assert_eq!(select.clone().all(&mock).await.unwrap(), vec![bakery]);
+    assert_eq!(select.one(&mock).await.unwrap(), None);
+
+    ctx.delete().await;
+}
+
+#[test]
+pub fn find_related_both_directions() {
+    use sea_orm::{DbBackend, QueryTrait};
+
+    // `order` has many `lineitem`, so either side navigates to the other over `order_id`
+    assert_eq!(
+        <order::Entity as Related<lineitem::Entity>>::find_related()
+            .filter(order::Column::Id.eq(1))
+            .build(DbBackend::Postgres)
+            .to_string(),
+        [
+            r#"SELECT "lineitem"."id", "lineitem"."price", "lineitem"."quantity", "lineitem"."order_id", "lineitem"."cake_id""#,
+            r#"FROM "lineitem" INNER JOIN "order" ON "order"."id" = "lineitem"."order_id""#,
+            r#"WHERE "order"."id" = 1"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        <lineitem::Entity as Related<order::Entity>>::find_related()
+            .filter(lineitem::Column::Id.eq(1))
+            .build(DbBackend::Postgres)
+            .to_string(),
+        [
+            r#"SELECT "order"."id", "order"."total", "order"."bakery_id", "order"."customer_id", "order"."placed_at""#,
+            r#"FROM "order" INNER JOIN "lineitem" ON "lineitem"."order_id" = "order"."id""#,
+            r#"WHERE "lineitem"."id" = 1"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+pub fn iterate_columns() {
+    use sea_orm::IdenStatic;
+
+    // Listed in the order the fields are declared on the model
+    assert_eq!(
+        lineitem::Column::iter()
+            .map(|col| col.as_str().to_owned())
+            .collect::<Vec<_>>(),
+        vec!["id", "price", "quantity", "order_id", "cake_id"]
+    );
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn window_function_rank() -> Result<(), sea_orm::DbErr> {
+    use chrono::offset::Utc;
+    use rust_decimal_macros::dec;
+    use sea_orm::{FromQueryResult, WindowFunction};
+    use uuid::Uuid;
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct RankedLineitem {
+        id: i32,
+        order_id: i32,
+        // `ROW_NUMBER()` is unsigned on MySQL
+        rank: u64,
+    }
+
+    let ctx = TestContext::new("window_function_rank").await;
+    let db = &ctx.db;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    let customer = customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    let cake = cake::ActiveModel {
+        name: Set("Mud Cake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    let mut lineitems = Vec::new();
+    for prices in [vec![dec!(3.5), dec!(9.25)], vec![dec!(7.0)]] {
+        let order = order::ActiveModel {
+            total: Set(dec!(12.75)),
+            bakery_id: Set(bakery.id),
+            customer_id: Set(customer.id),
+            placed_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+        for price in prices {
+            let lineitem = lineitem::ActiveModel {
+                price: Set(price),
+                quantity: Set(1),
+                order_id: Set(order.id),
+                cake_id: Set(cake.id),
+                ..Default::default()
+            }
+            .insert(db)
+            .await?;
+            lineitems.push(lineitem);
+        }
+    }
+
+    let ranked = Lineitem::find()
+        .select_only()
+        .column(lineitem::Column::Id)
+        .column(lineitem::Column::OrderId)
+        .column_as(
+            WindowFunction::row_number()
+                .partition_by(lineitem::Column::OrderId)
+                .order_by(lineitem::Column::Price, Order::Desc),
+            "rank",
+        )
+        .order_by_asc(lineitem::Column::Id)
+        .into_model::<RankedLineitem>()
+        .all(db)
+        .await?;
+    assert_eq!(
+        ranked,
+        vec![
+            RankedLineitem {
+                id: lineitems[0].id,
+                order_id: lineitems[0].order_id,
+                rank: 2,
+            },
+            RankedLineitem {
+                id: lineitems[1].id,
+                order_id: lineitems[1].order_id,
+                rank: 1,
+            },
+            RankedLineitem {
+                id: lineitems[2].id,
+                order_id: lineitems[2].order_id,
+                rank: 1,
+            },
+        ]
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn recursive_cte() -> Result<(), sea_orm::DbErr> {
+    use sea_query::{Alias, Expr, Query, UnionType};
+
+    let ctx = TestContext::new("recursive_cte").await;
+    let db = &ctx.db;
+
+    for name in ["SeaSide Bakery", "Harbour Bakery", "Top Bakery"] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    // The ids 1 and 2, counted up from the first one
+    let ids = Query::select()
+        .expr_as(Expr::val(1), Alias::new("id"))
+        .union(
+            UnionType::All,
+            Query::select()
+                .expr(Expr::col(Alias::new("id")).add(1))
+                .from(Alias::new("ids"))
+                .and_where(Expr::col(Alias::new("id")).lt(2))
+                .to_owned(),
+        )
+        .to_owned();
+    let bakeries = Bakery::find()
+        .with_recursive("ids", ids)
+        .filter(
+            bakery::Column::Id.in_subquery(
+                Query::select()
+                    .column(Alias::new("id"))
+                    .from(Alias::new("ids"))
+                    .to_owned(),
+            ),
+        )
+        .order_by_asc(bakery::Column::Id);
+
+    assert_eq!(bakeries.clone().count(db).await?, 2);
+    assert_eq!(
+        bakeries
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect::<Vec<_>>(),
+        vec!["SeaSide Bakery".to_owned(), "Harbour Bakery".to_owned()]
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn decode_type_mismatch() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{DbErr, FromQueryResult};
+
+    #[derive(Debug, FromQueryResult)]
+    struct BakeryName {
+        #[allow(dead_code)]
+        name: i32,
+    }
+
+    let ctx = TestContext::new("decode_type_mismatch").await;
+    let db = &ctx.db;
+
+    bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    let res = Bakery::find()
+        .select_only()
+        .column(bakery::Column::Name)
+        .into_model::<BakeryName>()
+        .one(db)
+        .await;
+    match res {
+        Err(DbErr::Type(msg)) => assert!(msg.contains("name"), "{}", msg),
+        res => panic!("unexpected {:?}", res),
+    }
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn union_all() -> Result<(), sea_orm::DbErr> {
+    use sea_query::Expr;
+
+    let ctx = TestContext::new("union_all").await;
+    let db = &ctx.db;
+
+    for (name, profit_margin) in [
+        ("SeaSide Bakery", 10.4),
+        ("Harbour Bakery", 2.5),
+        ("Top Bakery", 15.0),
+    ] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    // The Top Bakery matches both selects, and is returned twice
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::ProfitMargin.gt(10.0))
+        .union_all(Bakery::find().filter(bakery::Column::Name.starts_with("Top")))?
+        .order_by_asc(Expr::col(bakery::Column::Name));
+
+    assert_eq!(bakeries.clone().count(db).await?, 3);
+    assert_eq!(
+        bakeries
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect::<Vec<_>>(),
+        vec![
+            "SeaSide Bakery".to_owned(),
+            "Top Bakery".to_owned(),
+            "Top Bakery".to_owned()
+        ]
+    );
+
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::ProfitMargin.gt(10.0))
+        .union(Bakery::find().filter(bakery::Column::Name.starts_with("Top")))?
+        .order_by_desc(Expr::col(bakery::Column::Name))
+        .limit(1)
+        .all(db)
+        .await?;
+    assert_eq!(bakeries.len(), 1);
+    assert_eq!(bakeries[0].name, "Top Bakery");
+
+    ctx.delete().await;
+
+    Ok(())
+}
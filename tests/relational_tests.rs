@@ -1,7 +1,7 @@
 pub mod common;
 
 pub use chrono::offset::Utc;
-pub use common::{bakery_chain::*, setup::*, TestContext};
+pub use common::{bakery_chain::*, bakery_chain::Order, setup::*, TestContext};
 pub use rust_decimal::prelude::*;
 pub use rust_decimal_macros::dec;
 pub use sea_orm::{entity::*, query::*, DbErr, FromQueryResult};
@@ -476,6 +476,205 @@ pub async fn having() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn having_count() -> Result<(), DbErr> {
+    // cakes appearing in more than one line item
+    use sea_orm::sea_query::Expr;
+
+    let ctx = TestContext::new("test_having_count").await;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let mud_cake = cake::ActiveModel {
+        name: Set("Mud Cake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery.id.clone().unwrap())),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let cheese_cake = cake::ActiveModel {
+        name: Set("Cheese Cake".to_owned()),
+        price: Set(dec!(20.5)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery.id.clone().unwrap())),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let customer_kate = customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let kate_order = order::ActiveModel {
+        bakery_id: Set(bakery.id.clone().unwrap()),
+        customer_id: Set(customer_kate.id.clone().unwrap()),
+        total: Set(dec!(51.0)),
+        placed_at: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    for (cake_id, price) in [
+        (mud_cake.id.clone().unwrap(), dec!(10.25)),
+        (mud_cake.id.clone().unwrap(), dec!(20.25)),
+        (cheese_cake.id.clone().unwrap(), dec!(20.5)),
+    ] {
+        lineitem::ActiveModel {
+            cake_id: Set(cake_id),
+            order_id: Set(kate_order.id.clone().unwrap()),
+            price: Set(price),
+            quantity: Set(1),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await?;
+    }
+
+    #[derive(Debug, FromQueryResult, PartialEq)]
+    struct SelectResult {
+        cake_id: i32,
+        num_lineitems: i64,
+    }
+
+    let results = lineitem::Entity::find()
+        .select_only()
+        .column(lineitem::Column::CakeId)
+        .column_as(lineitem::Column::Id.count(), "num_lineitems")
+        .group_by(lineitem::Column::CakeId)
+        .having(Expr::expr(lineitem::Column::Id.count()).gt(1))
+        .into_model::<SelectResult>()
+        .all(&ctx.db)
+        .await?;
+
+    assert_eq!(
+        results,
+        vec![SelectResult {
+            cake_id: mud_cake.id.clone().unwrap(),
+            num_lineitems: 2,
+        }]
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn exists_subquery() -> Result<(), DbErr> {
+    // orders that have at least one line item with quantity > 10
+    use sea_orm::sea_query::Expr;
+
+    let ctx = TestContext::new("test_exists_subquery").await;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let mud_cake = cake::ActiveModel {
+        name: Set("Mud Cake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery.id.clone().unwrap())),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let customer_kate = customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await?;
+
+    let mut order_ids = Vec::new();
+    for quantity in [12, 1] {
+        let order = order::ActiveModel {
+            bakery_id: Set(bakery.id.clone().unwrap()),
+            customer_id: Set(customer_kate.id.clone().unwrap()),
+            total: Set(dec!(10.25) * Decimal::from(quantity)),
+            placed_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await?;
+
+        lineitem::ActiveModel {
+            cake_id: Set(mud_cake.id.clone().unwrap()),
+            order_id: Set(order.id.clone().unwrap()),
+            price: Set(dec!(10.25)),
+            quantity: Set(quantity),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await?;
+
+        order_ids.push(order.id.unwrap());
+    }
+
+    let large_lineitems = || {
+        lineitem::Entity::find()
+            .filter(
+                Expr::tbl(lineitem::Entity, lineitem::Column::OrderId)
+                    .equals(order::Entity, order::Column::Id),
+            )
+            .filter(lineitem::Column::Quantity.gt(10))
+    };
+
+    let orders: Vec<i32> = order::Entity::find()
+        .filter(Condition::exists(large_lineitems()))
+        .all(&ctx.db)
+        .await?
+        .into_iter()
+        .map(|order| order.id)
+        .collect();
+    assert_eq!(orders, vec![order_ids[0]]);
+
+    let orders: Vec<i32> = order::Entity::find()
+        .filter(Condition::not_exists(large_lineitems()))
+        .all(&ctx.db)
+        .await?
+        .into_iter()
+        .map(|order| order.id)
+        .collect();
+    assert_eq!(orders, vec![order_ids[1]]);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(any(
     feature = "sqlx-mysql",
@@ -708,6 +907,131 @@ pub async fn linked() -> Result<(), DbErr> {
         ]
     );
 
+    // Cakes of a baker, joined through the `cakes_bakers` junction
+    let baker_bobby = Baker::find_by_id(baker_bobby_res.last_insert_id as i32)
+        .one(&ctx.db)
+        .await?
+        .unwrap();
+    let linked_cakes: Vec<cake::Model> = baker_bobby
+        .find_linked(baker::BakerToCake)
+        .order_by_asc(cake::Column::Id)
+        .all(&ctx.db)
+        .await?;
+    assert_eq!(
+        linked_cakes
+            .iter()
+            .map(|cake| cake.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Cheese Cake", "Chocolate Cake"]
+    );
+    assert_eq!(
+        baker_bobby
+            .find_related(Cake)
+            .order_by_asc(cake::Column::Id)
+            .all(&ctx.db)
+            .await?,
+        linked_cakes
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_also_related() -> Result<(), DbErr> {
+    use common::bakery_chain::Order;
+
+    let ctx = TestContext::new("test_find_also_related").await;
+
+    let bakery_res = Bakery::insert(bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    })
+    .exec(&ctx.db)
+    .await?;
+    let bakery_id = bakery_res.last_insert_id as i32;
+    let customer_res = Customer::insert(customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        notes: Set(None),
+        ..Default::default()
+    })
+    .exec(&ctx.db)
+    .await?;
+    let order_res = Order::insert(order::ActiveModel {
+        bakery_id: Set(bakery_id),
+        customer_id: Set(customer_res.last_insert_id as i32),
+        total: Set(dec!(25.5)),
+        placed_at: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    })
+    .exec(&ctx.db)
+    .await?;
+
+    let mut cakes = Vec::new();
+    for (name, bakery_id) in [("Mud Cake", Some(bakery_id)), ("Home Made Cake", None)] {
+        cakes.push(
+            cake::ActiveModel {
+                name: Set(name.to_owned()),
+                price: Set(dec!(10.25)),
+                gluten_free: Set(false),
+                serial: Set(Uuid::new_v4()),
+                bakery_id: Set(bakery_id),
+                ..Default::default()
+            }
+            .insert(&ctx.db)
+            .await?,
+        );
+    }
+    let mut lineitems = Vec::new();
+    for (cake, price) in [(&cakes[0], dec!(10.25)), (&cakes[1], dec!(15.25))] {
+        lineitems.push(
+            lineitem::ActiveModel {
+                cake_id: Set(cake.id),
+                order_id: Set(order_res.last_insert_id as i32),
+                price: Set(price),
+                quantity: Set(1),
+                ..Default::default()
+            }
+            .insert(&ctx.db)
+            .await?,
+        );
+    }
+
+    // `id` and `price` exist on both sides, each model still gets its own columns
+    let lineitem_cakes: Vec<(lineitem::Model, Option<cake::Model>)> = Lineitem::find()
+        .find_also_related(Cake)
+        .order_by_asc(lineitem::Column::Id)
+        .all(&ctx.db)
+        .await?;
+    assert_eq!(
+        lineitem_cakes,
+        vec![
+            (lineitems[0].clone(), Some(cakes[0].clone())),
+            (lineitems[1].clone(), Some(cakes[1].clone())),
+        ]
+    );
+
+    // The related model is `None` when nothing matches the left join
+    let cake_bakeries: Vec<(cake::Model, Option<bakery::Model>)> = Cake::find()
+        .find_also_related(Bakery)
+        .order_by_asc(cake::Column::Id)
+        .all(&ctx.db)
+        .await?;
+    assert_eq!(cake_bakeries.len(), 2);
+    assert_eq!(cake_bakeries[0].0, cakes[0]);
+    assert_eq!(
+        cake_bakeries[0].1.as_ref().map(|bakery| bakery.id),
+        Some(bakery_id)
+    );
+    assert_eq!(cake_bakeries[1], (cakes[1].clone(), None));
+
     ctx.delete().await;
 
     Ok(())
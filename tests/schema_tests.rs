@@ -0,0 +1,268 @@
+pub mod common;
+
+pub use common::bakery_chain::*;
+pub use sea_orm::entity::*;
+pub use sea_orm::{
+    sea_query, ConnectOptions, ConnectionTrait, Database, DbBackend, DbConn, DbErr, QueryFilter,
+    QueryOrder, Schema, Statement,
+};
+
+mod tag {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "tag")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        #[sea_orm(indexed)]
+        pub name: String,
+        #[sea_orm(default_value = 0)]
+        pub usage: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+pub fn column_nullability() {
+    assert_eq!(
+        lineitem::Entity::columns_with_defs()
+            .map(|(col, def)| (col.as_str().to_owned(), def.is_null()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("id".to_owned(), false),
+            ("price".to_owned(), false),
+            ("quantity".to_owned(), false),
+            ("order_id".to_owned(), false),
+            ("cake_id".to_owned(), false),
+        ]
+    );
+    assert_eq!(
+        customer::Entity::columns_with_defs()
+            .map(|(col, def)| (col.as_str().to_owned(), def.is_null()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("id".to_owned(), false),
+            ("name".to_owned(), false),
+            ("notes".to_owned(), true),
+        ]
+    );
+    for (col, def) in customer::Entity::columns_with_defs() {
+        assert_eq!(def, col.def());
+    }
+}
+
+// Run the test locally:
+// cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test schema_tests
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn switch_schema_at_runtime() -> Result<(), DbErr> {
+    // Attached databases are per connection, so keep the pool to a single one
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(1);
+    let db = Database::connect(opt).await?;
+
+    for tenant in ["tenant_a", "tenant_b"] {
+        for sql in [
+            format!("ATTACH DATABASE ':memory:' AS {}", tenant),
+            format!(
+                "CREATE TABLE {}.bakery (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL, profit_margin REAL NOT NULL)",
+                tenant
+            ),
+        ] {
+            db.execute(Statement::from_string(DbBackend::Sqlite, sql))
+                .await?;
+        }
+    }
+
+    insert_bakery(&db, "tenant_a", "SeaSide Bakery").await?;
+    insert_bakery(&db, "tenant_a", "Top Bakery").await?;
+    insert_bakery(&db, "tenant_b", "Corner Bakery").await?;
+
+    assert_eq!(bakery_names(&db, "tenant_a").await?, vec!["SeaSide Bakery", "Top Bakery"]);
+    assert_eq!(bakery_names(&db, "tenant_b").await?, vec!["Corner Bakery"]);
+
+    Bakery::update_many()
+        .col_expr(bakery::Column::ProfitMargin, sea_query::Expr::value(20.0))
+        .into_schema("tenant_b")
+        .exec(&db)
+        .await?;
+    let margins: Vec<f64> = Bakery::find()
+        .into_schema("tenant_a")
+        .all(&db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.profit_margin)
+        .collect();
+    assert_eq!(margins, vec![10.4, 10.4]);
+
+    Bakery::delete_many()
+        .filter(bakery::Column::Name.eq("Top Bakery"))
+        .into_schema("tenant_a")
+        .exec(&db)
+        .await?;
+    assert_eq!(bakery_names(&db, "tenant_a").await?, vec!["SeaSide Bakery"]);
+
+    let bakery = Bakery::find()
+        .into_schema("tenant_b")
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(bakery.profit_margin, 20.0);
+    Bakery::delete(bakery.into_active_model())
+        .into_schema("tenant_b")
+        .exec(&db)
+        .await?;
+    assert!(bakery_names(&db, "tenant_b").await?.is_empty());
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+async fn insert_bakery(db: &DbConn, schema: &str, name: &str) -> Result<(), DbErr> {
+    Bakery::insert(bakery::ActiveModel {
+        name: Set(name.to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    })
+    .into_schema(schema)
+    .exec(db)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+async fn bakery_names(db: &DbConn, schema: &str) -> Result<Vec<String>, DbErr> {
+    Ok(Bakery::find()
+        .into_schema(schema)
+        .order_by_asc(bakery::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect())
+}
+
+// Run the test locally:
+// cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test schema_tests
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn create_table_from_entity() -> Result<(), DbErr> {
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(1);
+    let db = Database::connect(opt).await?;
+    let builder = db.get_database_backend();
+
+    let stmt = builder.build(&Schema::create_table_from_entity(Lineitem));
+    assert!(stmt.sql.starts_with("CREATE TABLE `lineitem` ("));
+    db.execute(stmt).await?;
+    assert!(Lineitem::find().all(&db).await?.is_empty());
+
+    db.execute(builder.build(&Schema::create_table_from_entity(tag::Entity)))
+        .await?;
+    for stmt in Schema::create_index_from_entity(tag::Entity) {
+        db.execute(builder.build(&stmt)).await?;
+    }
+    let indexes = db
+        .query_all(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = 'tag'".to_owned(),
+        ))
+        .await?
+        .into_iter()
+        .map(|row| row.try_get::<String>("", "name"))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(indexes, vec!["idx-tag-name"]);
+
+    let tag = tag::ActiveModel {
+        name: Set("Chocolate".to_owned()),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+    assert_eq!(tag.usage, 0);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+pub async fn create_table_with_foreign_keys() -> Result<(), DbErr> {
+    use chrono::offset::Utc;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(1);
+    let db = Database::connect(opt).await?;
+    let builder = db.get_database_backend();
+
+    let stmt = builder.build(&Schema::create_table_from_entity(Lineitem));
+    for fk in [
+        "FOREIGN KEY (`order_id`) REFERENCES `order` (`id`) ON DELETE CASCADE ON UPDATE CASCADE",
+        "FOREIGN KEY (`cake_id`) REFERENCES `cake` (`id`) ON DELETE CASCADE ON UPDATE CASCADE",
+    ] {
+        assert!(stmt.sql.contains(fk), "{} is missing from {}", fk, stmt.sql);
+    }
+
+    db.execute(builder.build(&Schema::create_table_from_entity(Bakery)))
+        .await?;
+    db.execute(builder.build(&Schema::create_table_from_entity(Customer)))
+        .await?;
+    db.execute(builder.build(&Schema::create_table_from_entity(Order)))
+        .await?;
+    db.execute(builder.build(&Schema::create_table_from_entity(Cake)))
+        .await?;
+    db.execute(stmt).await?;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+    let customer = customer::ActiveModel {
+        name: Set("Kate".to_owned()),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+    let order = order::ActiveModel {
+        total: Set(dec!(7.55)),
+        bakery_id: Set(bakery.id),
+        customer_id: Set(customer.id),
+        placed_at: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+    let cake = cake::ActiveModel {
+        name: Set("Mud Cake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        serial: Set(Uuid::new_v4()),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&db)
+    .await?;
+
+    let lineitem = |cake_id| lineitem::ActiveModel {
+        price: Set(dec!(7.55)),
+        quantity: Set(1),
+        order_id: Set(order.id),
+        cake_id: Set(cake_id),
+        ..Default::default()
+    };
+    lineitem(cake.id).insert(&db).await?;
+    assert!(lineitem(cake.id + 100).insert(&db).await.is_err());
+    assert_eq!(Lineitem::find().count(&db).await?, 1);
+
+    Ok(())
+}
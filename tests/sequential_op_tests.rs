@@ -1,7 +1,7 @@
 pub mod common;
 
 pub use chrono::offset::Utc;
-pub use common::{bakery_chain::*, setup::*, TestContext};
+pub use common::{bakery_chain::*, bakery_chain::Order, setup::*, TestContext};
 pub use rust_decimal::prelude::*;
 pub use rust_decimal_macros::dec;
 pub use sea_orm::{entity::*, query::*, DatabaseConnection, FromQueryResult};
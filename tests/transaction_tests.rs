@@ -3,7 +3,7 @@ pub mod common;
 pub use common::{bakery_chain::*, setup::*, TestContext};
 use sea_orm::{DatabaseTransaction, DbErr};
 pub use sea_orm::entity::*;
-pub use sea_orm::{QueryFilter, ConnectionTrait};
+pub use sea_orm::{QueryFilter, ConnectionTrait, TransactionTrait};
 
 #[sea_orm_macros::test]
 #[cfg(any(
@@ -88,3 +88,307 @@ fn _transaction_with_reference<'a>(txn: &'a DatabaseTransaction, name1: &'a str,
         Ok(())
     })
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_with_generic_connection() {
+    let ctx = TestContext::new("transaction_with_generic_connection_test").await;
+
+    assert_eq!(count_bakeries(&ctx.db, "Direct").await.unwrap(), 0);
+    insert_bakery(&ctx.db, "Direct Bakery").await.unwrap();
+    assert_eq!(count_bakeries(&ctx.db, "Direct").await.unwrap(), 1);
+
+    let backend = ctx.db.get_database_backend();
+    ctx.db.transaction::<_, (), DbErr>(|txn| Box::pin(async move {
+        assert_eq!(txn.get_database_backend(), backend);
+
+        insert_bakery(txn, "Nested Bakery").await?;
+        assert_eq!(count_bakeries(txn, "Bakery").await?, 2);
+
+        Ok(())
+    })).await.unwrap();
+
+    assert_eq!(count_bakeries(&ctx.db, "Nested").await.unwrap(), 1);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_propagate_db_err() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_propagate_db_err_test").await;
+
+    // The callback returns `DbErr` as is, and `?` turns the `TransactionError` back into one
+    let count = ctx.db.transaction(|txn| Box::pin(async move {
+        insert_bakery(txn, "SeaSide Bakery").await?;
+        count_bakeries(txn, "SeaSide").await
+    })).await?;
+    assert_eq!(count, 1);
+
+    let res: Result<(), DbErr> = async {
+        ctx.db.transaction::<_, (), DbErr>(|txn| Box::pin(async move {
+            insert_bakery(txn, "Top Bakery").await?;
+            Err(DbErr::RecordNotFound("no flour left".to_owned()))
+        })).await?;
+        Ok(())
+    }.await;
+    assert_eq!(res, Err(DbErr::RecordNotFound("no flour left".to_owned())));
+    assert_eq!(count_bakeries(&ctx.db, "Top").await?, 0);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn insert_bakery(db: &impl ConnectionTrait, name: &str) -> Result<(), DbErr> {
+    bakery::ActiveModel {
+        name: Set(name.to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+        .insert(db)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn count_bakeries(db: &impl ConnectionTrait, search_name: &str) -> Result<usize, DbErr> {
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::Name.contains(search_name))
+        .all(db)
+        .await?;
+
+    Ok(bakeries.len())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_begin_commit() {
+    let ctx = TestContext::new("transaction_begin_commit_test").await;
+
+    let txn = ctx.db.begin().await.unwrap();
+    insert_bakery(&txn, "SeaSide Bakery").await.unwrap();
+    insert_bakery(&txn, "Top Bakery").await.unwrap();
+    assert_eq!(count_bakeries(&txn, "Bakery").await.unwrap(), 2);
+    txn.commit().await.unwrap();
+
+    assert_eq!(count_bakeries(&ctx.db, "Bakery").await.unwrap(), 2);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_begin_rollback() {
+    let ctx = TestContext::new("transaction_begin_rollback_test").await;
+
+    let txn = ctx.db.begin().await.unwrap();
+    insert_bakery(&txn, "SeaSide Bakery").await.unwrap();
+    assert_eq!(count_bakeries(&txn, "Bakery").await.unwrap(), 1);
+    txn.rollback().await.unwrap();
+
+    assert_eq!(count_bakeries(&ctx.db, "Bakery").await.unwrap(), 0);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_begin_drop() {
+    let ctx = TestContext::new("transaction_begin_drop_test").await;
+
+    {
+        let txn = ctx.db.begin().await.unwrap();
+        insert_bakery(&txn, "SeaSide Bakery").await.unwrap();
+        assert_eq!(count_bakeries(&txn, "Bakery").await.unwrap(), 1);
+    }
+
+    assert_eq!(count_bakeries(&ctx.db, "Bakery").await.unwrap(), 0);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_panic_rollback() {
+    use futures::FutureExt;
+    use std::panic::AssertUnwindSafe;
+
+    let ctx = TestContext::new("transaction_panic_rollback_test").await;
+
+    let res = AssertUnwindSafe(ctx.db.transaction::<_, (), DbErr>(|txn| Box::pin(async move {
+        insert_bakery(txn, "SeaSide Bakery").await?;
+        assert_eq!(count_bakeries(txn, "Bakery").await?, 1);
+
+        panic!("business rule violated");
+    }))).catch_unwind().await;
+    assert!(res.is_err());
+
+    assert_eq!(count_bakeries(&ctx.db, "Bakery").await.unwrap(), 0);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_depth() {
+    let ctx = TestContext::new("transaction_depth_test").await;
+
+    assert!(!ctx.db.is_in_transaction());
+
+    let txn = ctx.db.begin().await.unwrap();
+    assert!(txn.is_in_transaction());
+    assert_eq!(txn.depth(), 1);
+
+    let depths = txn.transaction::<_, _, DbErr>(|inner| Box::pin(async move {
+        let nested = inner.transaction::<_, _, DbErr>(|nested| Box::pin(async move {
+            Ok(nested.depth())
+        })).await.unwrap();
+        Ok((inner.is_in_transaction(), inner.depth(), nested))
+    })).await.unwrap();
+    assert_eq!(depths, (true, 2, 3));
+    txn.commit().await.unwrap();
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_rows_affected() {
+    let ctx = TestContext::new("transaction_rows_affected_test").await;
+
+    insert_bakery(&ctx.db, "SeaSide Bakery").await.unwrap();
+    insert_bakery(&ctx.db, "Top Bakery").await.unwrap();
+    insert_bakery(&ctx.db, "Top Cakes").await.unwrap();
+
+    let txn = ctx.db.begin().await.unwrap();
+    assert_eq!(txn.rows_affected(), 0);
+    let res = Bakery::delete_many()
+        .filter(bakery::Column::Name.contains("SeaSide"))
+        .exec(&txn)
+        .await
+        .unwrap();
+    assert_eq!(res.rows_affected, 1);
+    let res = Bakery::delete_many()
+        .filter(bakery::Column::Name.contains("Top"))
+        .exec(&txn)
+        .await
+        .unwrap();
+    assert_eq!(res.rows_affected, 2);
+    assert_eq!(txn.rows_affected(), 3);
+    txn.commit().await.unwrap();
+
+    // Every transaction counts from zero
+    let txn = ctx.db.begin().await.unwrap();
+    assert_eq!(txn.rows_affected(), 0);
+    txn.rollback().await.unwrap();
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_nested_savepoints() {
+    use futures::FutureExt;
+    use std::panic::AssertUnwindSafe;
+
+    let ctx = TestContext::new("transaction_nested_savepoints_test").await;
+
+    let txn = ctx.db.begin().await.unwrap();
+    insert_bakery(&txn, "Outer Bakery").await.unwrap();
+
+    // Released in order: the inner savepoint into the middle one, the middle one into the outer
+    txn.transaction::<_, _, DbErr>(|middle| Box::pin(async move {
+        insert_bakery(middle, "Kept Middle Bakery").await?;
+        middle.transaction::<_, _, DbErr>(|inner| Box::pin(async move {
+            insert_bakery(inner, "Kept Inner Bakery").await
+        })).await.unwrap();
+        Ok(())
+    })).await.unwrap();
+
+    // Rolling back the middle savepoint discards the inner one committed into it, and nothing else
+    let res = txn.transaction::<_, (), DbErr>(|middle| Box::pin(async move {
+        insert_bakery(middle, "Lost Middle Bakery").await?;
+        middle.transaction::<_, _, DbErr>(|inner| Box::pin(async move {
+            insert_bakery(inner, "Lost Inner Bakery").await
+        })).await.unwrap();
+        assert_eq!(count_bakeries(middle, "Lost").await?, 2);
+        Err(DbErr::RecordNotFound("no flour left".to_owned()))
+    })).await;
+    assert!(res.is_err());
+
+    // An inner savepoint given up on by a panic only takes its own changes with it
+    txn.transaction::<_, _, DbErr>(|middle| Box::pin(async move {
+        insert_bakery(middle, "Calm Middle Bakery").await?;
+        let res = AssertUnwindSafe(middle.transaction::<_, (), DbErr>(|inner| Box::pin(async move {
+            insert_bakery(inner, "Panicked Inner Bakery").await?;
+            panic!("business rule violated");
+        }))).catch_unwind().await;
+        assert!(res.is_err());
+        Ok(())
+    })).await.unwrap();
+
+    assert_eq!(count_bakeries(&txn, "Bakery").await.unwrap(), 4);
+    txn.commit().await.unwrap();
+
+    let mut names: Vec<String> = Bakery::find()
+        .all(&ctx.db)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec!["Calm Middle Bakery", "Kept Inner Bakery", "Kept Middle Bakery", "Outer Bakery"]
+    );
+
+    ctx.delete().await;
+}
@@ -0,0 +1,111 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use sea_orm::{
+    entity::prelude::*, ConnectionTrait, DatabaseConnection, DbBackend, Schema, Set, Statement,
+};
+
+mod meter {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "meter")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub reading: u32,
+        pub total: u64,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+// Run the test locally:
+// DATABASE_URL="sqlite::memory:" cargo test --features sqlx-sqlite,runtime-async-std-native-tls --test unsigned_tests
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    assert_eq!(meter::Column::Reading.def(), ColumnType::Unsigned.def());
+    assert_eq!(meter::Column::Total.def(), ColumnType::BigUnsigned.def());
+
+    let ctx = TestContext::new("unsigned_tests").await;
+    create_meter_table(&ctx.db).await?;
+    insert_unsigned(&ctx.db).await?;
+    insert_big_unsigned(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_meter_table(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let stmt = match db.get_database_backend() {
+        DbBackend::MySql => Statement::from_string(
+            DbBackend::MySql,
+            "CREATE TABLE `meter` (`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY, `reading` int unsigned NOT NULL, `total` bigint unsigned NOT NULL)".to_owned(),
+        ),
+        backend => backend.build(&Schema::create_table_from_entity(meter::Entity)),
+    };
+    db.execute(stmt).await?;
+
+    Ok(())
+}
+
+pub async fn insert_unsigned(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let meter = meter::ActiveModel {
+        reading: Set(u32::MAX),
+        total: Set(i64::MAX as u64),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(meter.reading, u32::MAX);
+    assert_eq!(meter.total, i64::MAX as u64);
+    assert_eq!(
+        meter::Entity::find()
+            .filter(meter::Column::Reading.gt(i32::MAX as u32))
+            .one(db)
+            .await?,
+        Some(meter)
+    );
+
+    Ok(())
+}
+
+pub async fn insert_big_unsigned(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let res = meter::ActiveModel {
+        reading: Set(1),
+        total: Set(u64::MAX),
+        ..Default::default()
+    }
+    .insert(db)
+    .await;
+
+    if db.get_database_backend() == DbBackend::MySql {
+        assert_eq!(res?.total, u64::MAX);
+        return Ok(());
+    }
+
+    // Stored as `i64` elsewhere, so the value is refused instead of wrapping around
+    assert!(matches!(res, Err(DbErr::Type(_))));
+    assert_eq!(meter::Entity::find().count(db).await?, 1);
+
+    // A negative number in the column cannot be read back as unsigned
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        "UPDATE meter SET total = -1".to_owned(),
+    ))
+    .await?;
+    assert!(matches!(
+        meter::Entity::find().one(db).await,
+        Err(DbErr::Type(_))
+    ));
+
+    Ok(())
+}